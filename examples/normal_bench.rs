@@ -0,0 +1,41 @@
+//! Compares the ziggurat samplers with the transform methods.
+//!
+//! `StandardNormal` is measured against Box-Muller (`Normal` with its cached spare value),
+//! and `Exp1` against the inversion `-ln(u)`.
+//!
+//! Run with `cargo run --release --example normal_bench`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use aporia::distributions::{Distribution, Exp1, Normal, StandardNormal};
+use aporia::{Rng, backend::Xoshiro256PlusPlus};
+
+const DRAWS: u32 = 50_000_000;
+
+/// Returns the time per draw of `sample`, in nanoseconds.
+fn time(mut sample: impl FnMut(&mut Rng<Xoshiro256PlusPlus>) -> f64) -> f64 {
+    let mut rng = Rng::new(Xoshiro256PlusPlus::new(1));
+    let start = Instant::now();
+    for _ in 0..DRAWS {
+        black_box(sample(&mut rng));
+    }
+    start.elapsed().as_nanos() as f64 / f64::from(DRAWS)
+}
+
+fn main() {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let box_muller = time(|rng| normal.sample(rng));
+    let zig_normal = time(|rng| rng.sample(StandardNormal));
+    println!(
+        "normal       box-muller {box_muller:>6.2} ns/draw   ziggurat {zig_normal:>6.2} ns/draw   speedup {:.2}x",
+        box_muller / zig_normal,
+    );
+
+    let inversion = time(|rng| -rng.next_f64_open_closed().ln());
+    let zig_exp = time(|rng| rng.sample(Exp1));
+    println!(
+        "exponential  inversion  {inversion:>6.2} ns/draw   ziggurat {zig_exp:>6.2} ns/draw   speedup {:.2}x",
+        inversion / zig_exp,
+    );
+}
@@ -0,0 +1,48 @@
+//! Compares `Rng::gen_range` (Lemire's method) with the zone-rejection method it replaced.
+//!
+//! Run with `cargo run --release --example range_bench`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use aporia::{Rng, backend::Xoshiro256PlusPlus};
+
+const DRAWS: u32 = 50_000_000;
+
+/// The previous `gen_range` algorithm: reject draws at or above the largest multiple of
+/// `range` below `2^64`, then reduce with `%`.
+fn zone_range(rng: &mut Rng<Xoshiro256PlusPlus>, min: u64, max: u64) -> u64 {
+    let range = max - min;
+    let zone = u64::MAX - (u64::MAX % range);
+    loop {
+        let v = rng.next_u64();
+        if v < zone {
+            return min + (v % range);
+        }
+    }
+}
+
+fn main() {
+    for max in [6u64, 1000, 1 << 40, (1 << 63) + 1] {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(1));
+        let start = Instant::now();
+        for _ in 0..DRAWS {
+            black_box(zone_range(&mut rng, 0, black_box(max)));
+        }
+        let zone = start.elapsed();
+
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(1));
+        let start = Instant::now();
+        for _ in 0..DRAWS {
+            black_box(rng.gen_range(0..black_box(max)).unwrap());
+        }
+        let lemire = start.elapsed();
+
+        println!(
+            "0..{max:<20} zone {:>7.2} ns/draw   lemire {:>7.2} ns/draw   speedup {:.2}x",
+            zone.as_nanos() as f64 / f64::from(DRAWS),
+            lemire.as_nanos() as f64 / f64::from(DRAWS),
+            zone.as_secs_f64() / lemire.as_secs_f64(),
+        );
+    }
+}
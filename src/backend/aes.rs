@@ -0,0 +1,180 @@
+//! Minimal AES-256 block encryption used by the CTR-DRBG and Fortuna backends.
+//!
+//! This is a byte-oriented FIPS 197 implementation, kept private to the crate. Only
+//! encryption is needed, since both backends run AES in counter mode. The S-box is computed
+//! at compile time from its algebraic definition. Table lookups are not constant-time, so
+//! this code is not hardened against cache-timing side channels.
+
+/// Number of rounds for a 256-bit key.
+const ROUNDS: usize = 14;
+
+/// AES block size in bytes.
+pub(crate) const BLOCK_LEN: usize = 16;
+
+/// AES-256 key size in bytes.
+pub(crate) const KEY_LEN: usize = 32;
+
+/// Multiplication by `x` in GF(2^8) modulo the AES polynomial.
+const fn xtime(a: u8) -> u8 {
+    (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 }
+}
+
+/// Multiplication in GF(2^8) modulo the AES polynomial.
+const fn gmul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            p ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    p
+}
+
+/// Builds the S-box: multiplicative inverse in GF(2^8) followed by the affine map.
+const fn build_sbox() -> [u8; 256] {
+    let mut sbox = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        // The inverse of x is x^254 (and 0 maps to 0).
+        let x = i as u8;
+        let mut inv = 1u8;
+        let mut n = 0;
+        while n < 254 {
+            inv = gmul(inv, x);
+            n += 1;
+        }
+        if x == 0 {
+            inv = 0;
+        }
+        sbox[i] = inv
+            ^ inv.rotate_left(1)
+            ^ inv.rotate_left(2)
+            ^ inv.rotate_left(3)
+            ^ inv.rotate_left(4)
+            ^ 0x63;
+        i += 1;
+    }
+    sbox
+}
+
+static SBOX: [u8; 256] = build_sbox();
+
+/// AES-256 with an expanded encryption key schedule.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Aes256 {
+    round_keys: [[u8; BLOCK_LEN]; ROUNDS + 1],
+}
+
+impl Aes256 {
+    /// Expands `key` into the round key schedule.
+    pub(crate) fn new(key: &[u8; KEY_LEN]) -> Self {
+        let mut w = [[0u8; 4]; 4 * (ROUNDS + 1)];
+        for (i, word) in w.iter_mut().take(8).enumerate() {
+            word.copy_from_slice(&key[4 * i..4 * i + 4]);
+        }
+        let mut rcon = 1u8;
+        for i in 8..w.len() {
+            let mut t = w[i - 1];
+            if i % 8 == 0 {
+                t = [
+                    SBOX[t[1] as usize] ^ rcon,
+                    SBOX[t[2] as usize],
+                    SBOX[t[3] as usize],
+                    SBOX[t[0] as usize],
+                ];
+                rcon = xtime(rcon);
+            } else if i % 8 == 4 {
+                t = t.map(|b| SBOX[b as usize]);
+            }
+            for j in 0..4 {
+                w[i][j] = w[i - 8][j] ^ t[j];
+            }
+        }
+
+        let mut round_keys = [[0u8; BLOCK_LEN]; ROUNDS + 1];
+        for (r, rk) in round_keys.iter_mut().enumerate() {
+            for c in 0..4 {
+                rk[4 * c..4 * c + 4].copy_from_slice(&w[4 * r + c]);
+            }
+        }
+        Self { round_keys }
+    }
+
+    /// Returns the cipher key, which forms the first two round keys of AES-256.
+    pub(crate) fn key(&self) -> [u8; KEY_LEN] {
+        let mut key = [0u8; KEY_LEN];
+        key[..BLOCK_LEN].copy_from_slice(&self.round_keys[0]);
+        key[BLOCK_LEN..].copy_from_slice(&self.round_keys[1]);
+        key
+    }
+
+    /// Encrypts one block in place.
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; BLOCK_LEN]) {
+        add_round_key(block, &self.round_keys[0]);
+        for round in 1..=ROUNDS {
+            for b in block.iter_mut() {
+                *b = SBOX[*b as usize];
+            }
+            shift_rows(block);
+            if round != ROUNDS {
+                mix_columns(block);
+            }
+            add_round_key(block, &self.round_keys[round]);
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Aes256 {
+    fn zeroize(&mut self) {
+        self.round_keys.zeroize();
+    }
+}
+
+fn add_round_key(block: &mut [u8; BLOCK_LEN], rk: &[u8; BLOCK_LEN]) {
+    for (b, k) in block.iter_mut().zip(rk) {
+        *b ^= k;
+    }
+}
+
+/// Rotates row `r` of the column-major state left by `r` positions.
+fn shift_rows(block: &mut [u8; BLOCK_LEN]) {
+    let s = *block;
+    for c in 0..4 {
+        for r in 0..4 {
+            block[4 * c + r] = s[4 * ((c + r) % 4) + r];
+        }
+    }
+}
+
+fn mix_columns(block: &mut [u8; BLOCK_LEN]) {
+    for col in block.chunks_exact_mut(4) {
+        let [a0, a1, a2, a3] = [col[0], col[1], col[2], col[3]];
+        col[0] = xtime(a0) ^ xtime(a1) ^ a1 ^ a2 ^ a3;
+        col[1] = a0 ^ xtime(a1) ^ xtime(a2) ^ a2 ^ a3;
+        col[2] = a0 ^ a1 ^ xtime(a2) ^ xtime(a3) ^ a3;
+        col[3] = xtime(a0) ^ a0 ^ a1 ^ a2 ^ xtime(a3);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aes256_fips197_appendix_c3() {
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let mut block: [u8; 16] = core::array::from_fn(|i| (i as u8) * 0x11);
+        Aes256::new(&key).encrypt_block(&mut block);
+        assert_eq!(
+            block,
+            [
+                0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b,
+                0x49, 0x60, 0x89
+            ]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Runtime-selected backend covering every deterministic built-in algorithm.
+//!
+//! [`AnyBackend`] is an enum with one variant per seedable backend. It lets applications
+//! pick the algorithm from a configuration string without generics or boxing, and it works
+//! in `no_std` builds.
+//!
+//! The entropy backends (`OsRandom`, `RdRand`, `RdSeed`) are not included, since they
+//! cannot be constructed from a seed.
+//!
+//! # Size
+//!
+//! The enum is as large as its largest variant ([`Cmwc`], about 16 KB). Box it, or use
+//! `Box<dyn RandomBackend>`, if it must be moved around frequently.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::AnyBackend};
+//!
+//! let backend = AnyBackend::from_name("xoshiro256**", 42).unwrap();
+//! assert_eq!(backend.name(), "xoshiro256**");
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//! ```
+
+use super::*;
+
+/// Declares [`AnyBackend`] and [`Algorithm`] together with their dispatching impls.
+macro_rules! any_backend {
+    ($($variant:ident),* $(,)?) => {
+        /// Names one of the deterministic built-in algorithms, without any state.
+        ///
+        /// Used to choose the backend of an [`AnyBackend`], for example through
+        /// [`crate::RngBuilder::algorithm`].
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Algorithm {
+            $(
+                #[doc = concat!("[`", stringify!($variant), "`]")]
+                $variant,
+            )*
+        }
+
+        impl Algorithm {
+            /// Every algorithm, in declaration order.
+            pub const ALL: &'static [Algorithm] = &[$(Algorithm::$variant),*];
+
+            /// Returns the algorithm's canonical name, as reported by [`BackendInfo::name`].
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Algorithm::$variant => <$variant as BackendInfo>::name(),)*
+                }
+            }
+
+            /// Looks up an algorithm by name, matched ASCII case-insensitively.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`crate::AporiaError::UnknownBackend`] if no algorithm has that name.
+            pub fn from_name(name: &str) -> core::result::Result<Self, crate::AporiaError> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|algorithm| name.eq_ignore_ascii_case(algorithm.name()))
+                    .ok_or(crate::AporiaError::UnknownBackend)
+            }
+        }
+
+        /// A backend chosen at runtime from the deterministic built-in algorithms.
+        ///
+        /// Each variant wraps the backend of the same name. See the
+        /// [module documentation](self) for details.
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum AnyBackend {
+            $($variant($variant),)*
+        }
+
+        impl AnyBackend {
+            /// Returns the canonical names accepted by [`AnyBackend::from_name`], as reported
+            /// by [`BackendInfo::name`].
+            pub fn names() -> impl Iterator<Item = &'static str> {
+                let names: &[fn() -> &'static str] = &[$(<$variant as BackendInfo>::name),*];
+                names.iter().map(|name| name())
+            }
+
+            /// Creates the backend with the given name, seeded with `seed`.
+            ///
+            /// Names are the ones reported by [`BackendInfo::name`] (for example
+            /// `"xoshiro256**"`, `"pcg64dxsm"` or `"chacha20"`) and are matched
+            /// ASCII case-insensitively. The backend is constructed with
+            /// [`SeedableBackend::from_seed_u64`].
+            ///
+            /// # Errors
+            ///
+            /// Returns [`crate::AporiaError::UnknownBackend`] if no backend has that name.
+            pub fn from_name(name: &str, seed: u64) -> core::result::Result<Self, crate::AporiaError> {
+                Algorithm::from_name(name).map(|algorithm| Self::from_seed_u64(algorithm, seed))
+            }
+
+            /// Creates the given algorithm with [`SeedableBackend::from_seed_u64`].
+            pub fn from_seed_u64(algorithm: Algorithm, seed: u64) -> Self {
+                match algorithm {
+                    $(Algorithm::$variant => AnyBackend::$variant(<$variant as SeedableBackend>::from_seed_u64(seed)),)*
+                }
+            }
+
+            /// Creates the given algorithm with its full byte seed read from the operating
+            /// system.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`crate::AporiaError::EntropyUnavailable`] if the OS source fails.
+            #[cfg(feature = "getrandom")]
+            pub fn from_os_seed(algorithm: Algorithm) -> core::result::Result<Self, crate::AporiaError> {
+                match algorithm {
+                    $(Algorithm::$variant => {
+                        let mut seed: <$variant as SeedableBackend>::Seed = core::array::from_fn(|_| 0);
+                        OsRandom::new().try_fill_bytes(seed.as_mut())?;
+                        Ok(AnyBackend::$variant(<$variant as SeedableBackend>::from_seed(seed)))
+                    })*
+                }
+            }
+
+            /// Returns the algorithm of the wrapped backend.
+            pub fn algorithm(&self) -> Algorithm {
+                match self {
+                    $(AnyBackend::$variant(_) => Algorithm::$variant,)*
+                }
+            }
+
+            /// Returns the canonical name of the wrapped backend.
+            pub fn name(&self) -> &'static str {
+                self.algorithm().name()
+            }
+        }
+
+        impl RandomBackend for AnyBackend {
+            fn next_u64(&mut self) -> u64 {
+                match self {
+                    $(AnyBackend::$variant(b) => b.next_u64(),)*
+                }
+            }
+
+            fn next_f64(&mut self) -> f64 {
+                match self {
+                    $(AnyBackend::$variant(b) => b.next_f64(),)*
+                }
+            }
+
+            fn next_u32(&mut self) -> u32 {
+                match self {
+                    $(AnyBackend::$variant(b) => b.next_u32(),)*
+                }
+            }
+
+            fn fill_bytes(&mut self, buf: &mut [u8]) {
+                match self {
+                    $(AnyBackend::$variant(b) => b.fill_bytes(buf),)*
+                }
+            }
+        }
+
+        impl core::fmt::Debug for AnyBackend {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(AnyBackend::$variant(b) => f.debug_tuple(stringify!($variant)).field(b).finish(),)*
+                }
+            }
+        }
+    };
+}
+
+any_backend!(
+    LCG,
+    PCG,
+    Pcg64Dxsm,
+    Pcg32,
+    Pcg32Fast,
+    XorShift,
+    XorShift128Plus,
+    XorWow,
+    MT19937_64,
+    MT19937,
+    Well19937a,
+    SplitMix64,
+    Xoshiro256StarStar,
+    Xoshiro256PlusPlus,
+    Xoshiro256Plus,
+    Xoshiro512StarStar,
+    RomuTrio,
+    RomuDuoJr,
+    Cmwc,
+    Isaac64,
+    ChaCha20,
+    ChaCha12,
+    ChaCha8,
+    Philox,
+    Squares,
+    HmacDrbg,
+    CtrDrbg,
+    Fortuna,
+);
+
+impl AnyBackend {
+    /// Switches a [`Streamable`] backend (PCG, Philox or ChaCha) to the given stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::StreamsUnsupported`] for any other backend.
+    pub fn set_stream(&mut self, stream: u64) -> core::result::Result<(), crate::AporiaError> {
+        match self {
+            AnyBackend::PCG(b) => b.set_stream(stream),
+            AnyBackend::Philox(b) => b.set_stream(stream),
+            AnyBackend::ChaCha20(b) => b.set_stream(stream),
+            AnyBackend::ChaCha12(b) => b.set_stream(stream),
+            AnyBackend::ChaCha8(b) => b.set_stream(stream),
+            _ => return Err(crate::AporiaError::StreamsUnsupported),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_backend_from_name_matches_concrete() {
+        let mut any = AnyBackend::from_name("Xoshiro256**", 42).unwrap();
+        let mut concrete = Xoshiro256StarStar::new(42);
+        assert_eq!(any.name(), "xoshiro256**");
+        assert_eq!(any.next_u64(), concrete.next_u64());
+        assert_eq!(
+            AnyBackend::from_name("not-a-backend", 0).unwrap_err(),
+            crate::AporiaError::UnknownBackend
+        );
+    }
+
+    #[test]
+    fn any_backend_names_round_trip() {
+        for name in AnyBackend::names() {
+            assert_eq!(AnyBackend::from_name(name, 1).unwrap().name(), name);
+        }
+        for &algorithm in Algorithm::ALL {
+            assert_eq!(AnyBackend::from_seed_u64(algorithm, 1).algorithm(), algorithm);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Lock-free shared generator for single-word backends.
+//!
+//! [`AtomicRng`] keeps the state word of an [`AtomicBackend`] in an `AtomicU64` and
+//! advances it with compare-exchange, so draws only need `&self`. It can live in a
+//! `static` and be used from many threads without a mutex. Each draw is atomic: no two
+//! callers ever receive the same step of the sequence, although which thread gets which
+//! step depends on scheduling.
+//!
+//! Under heavy contention a draw may retry, so for hot loops a per-thread generator (see
+//! [`Splittable`](super::Splittable)) is faster.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::{AtomicRng, XorShift}};
+//!
+//! static SHARED: AtomicRng<XorShift> = AtomicRng::new(XorShift::new(0x2545_f491));
+//!
+//! let a = SHARED.next_u64();
+//! let mut rng = Rng::new(&SHARED);
+//! let b = rng.next_u64();
+//! assert_ne!(a, b);
+//! ```
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use super::{AtomicBackend, RandomBackend};
+
+/// Generator shared through `&self`, backed by an atomic state word.
+pub struct AtomicRng<B: AtomicBackend> {
+    /// The backend as constructed; only its fixed parameters and initial word are used.
+    initial: B,
+    /// Current state word XOR the initial one, so a fresh generator stores zero and
+    /// [`AtomicRng::new`] can be a `const fn`.
+    delta: AtomicU64,
+}
+
+impl<B: AtomicBackend> AtomicRng<B> {
+    /// Wraps `backend`; the first draw continues from its current state.
+    pub const fn new(backend: B) -> Self {
+        Self {
+            initial: backend,
+            delta: AtomicU64::new(0),
+        }
+    }
+
+    /// Runs `f` on a copy of the current backend and publishes the advanced state,
+    /// retrying if another thread advanced it first.
+    fn draw<T>(&self, mut f: impl FnMut(&mut B) -> T) -> T {
+        let base = self.initial.state_word();
+        let mut delta = self.delta.load(Ordering::Relaxed);
+        loop {
+            let mut backend = self.initial.clone();
+            backend.set_state_word(base ^ delta);
+            let out = f(&mut backend);
+            let next = backend.state_word() ^ base;
+            match self.delta.compare_exchange_weak(delta, next, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => return out,
+                Err(actual) => delta = actual,
+            }
+        }
+    }
+
+    /// Generates the next 64-bit unsigned integer.
+    pub fn next_u64(&self) -> u64 {
+        self.draw(|b| b.next_u64())
+    }
+
+    /// Generates the next 32-bit unsigned integer.
+    pub fn next_u32(&self) -> u32 {
+        self.draw(|b| b.next_u32())
+    }
+
+    /// Generates the next floating-point number in the range [0, 1).
+    pub fn next_f64(&self) -> f64 {
+        self.draw(|b| b.next_f64())
+    }
+
+    /// Fills `buf` with random bytes as one atomic step.
+    pub fn fill_bytes(&self, buf: &mut [u8]) {
+        self.draw(|b| b.fill_bytes(buf))
+    }
+
+    /// Returns a copy of the backend in its current state.
+    pub fn snapshot(&self) -> B {
+        let mut backend = self.initial.clone();
+        let word = self.initial.state_word() ^ self.delta.load(Ordering::Relaxed);
+        backend.set_state_word(word);
+        backend
+    }
+}
+
+impl<B: AtomicBackend> RandomBackend for AtomicRng<B> {
+    fn next_u64(&mut self) -> u64 {
+        AtomicRng::next_u64(self)
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        AtomicRng::next_u32(self)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        AtomicRng::next_f64(self)
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        AtomicRng::fill_bytes(self, buf)
+    }
+}
+
+/// Lets a shared reference serve as a backend, e.g. `Rng::new(&STATIC_RNG)`.
+impl<B: AtomicBackend> RandomBackend for &AtomicRng<B> {
+    fn next_u64(&mut self) -> u64 {
+        AtomicRng::next_u64(self)
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        AtomicRng::next_u32(self)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        AtomicRng::next_f64(self)
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        AtomicRng::fill_bytes(self, buf)
+    }
+}
+
+impl<B: AtomicBackend + core::fmt::Debug> core::fmt::Debug for AtomicRng<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AtomicRng")
+            .field("backend", &self.snapshot())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::PCG;
+
+    #[test]
+    fn atomic_rng_follows_backend_sequence() {
+        let shared = AtomicRng::new(PCG::new(42, 54));
+        let mut plain = PCG::new(42, 54);
+        for _ in 0..100 {
+            assert_eq!(shared.next_u64(), plain.next_u64());
+        }
+        assert_eq!(shared.snapshot(), plain);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn concurrent_draws_never_repeat_a_step() {
+        use crate::backend::SplitMix64;
+
+        static SHARED: AtomicRng<SplitMix64> = AtomicRng::new(SplitMix64::new(7));
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| (0..1000).map(|_| SHARED.next_u64()).collect::<Vec<_>>()))
+            .collect();
+        let mut all: Vec<u64> = handles.into_iter().flat_map(|h| h.join().unwrap()).collect();
+
+        let mut expected = SplitMix64::new(7);
+        let mut sequential: Vec<u64> = (0..4000).map(|_| expected.next_u64()).collect();
+        all.sort_unstable();
+        sequential.sort_unstable();
+        assert_eq!(all, sequential);
+    }
+}
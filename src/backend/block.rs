@@ -0,0 +1,163 @@
+//! Buffering adapter for block-based backends.
+//!
+//! [`BlockRng`] wraps a [`BlockBackend`] and serves `next_u64` calls from a buffered block,
+//! calling [`BlockBackend::generate`] only once every `N` values. For generators whose
+//! natural unit of work is a block (the Mersenne Twister state, a ChaCha keystream block)
+//! this removes the per-value boundary checks of the backend's own `next_u64`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::{BlockRng, ChaCha8}};
+//!
+//! let mut rng = Rng::new(BlockRng::new(ChaCha8::new(7)));
+//! let random_number = rng.next_u64();
+//! ```
+
+use super::{BlockBackend, RandomBackend};
+
+/// Adapter that buffers one block of a [`BlockBackend`] and serves values from it.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockRng<B: BlockBackend<N>, const N: usize> {
+    core: B,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    buffer: [u64; N],
+    index: usize,
+}
+
+impl<B: BlockBackend<N>, const N: usize> BlockRng<B, N> {
+    /// Creates a new `BlockRng` around the given block backend.
+    ///
+    /// The first block is generated lazily on the first draw.
+    pub fn new(core: B) -> Self {
+        Self {
+            core,
+            buffer: [0; N],
+            index: N,
+        }
+    }
+
+    /// Returns a reference to the wrapped backend.
+    ///
+    /// The backend is positioned after the buffered block, not after the last value served.
+    pub fn core(&self) -> &B {
+        &self.core
+    }
+
+    /// Discards the buffered values, so the next draw starts a fresh block.
+    pub fn reset(&mut self) {
+        self.index = N;
+    }
+
+    /// Returns the wrapped backend, dropping any buffered values.
+    pub fn into_inner(self) -> B {
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.core
+        }
+        #[cfg(feature = "zeroize")]
+        {
+            let mut this = core::mem::ManuallyDrop::new(self);
+            this.wipe_buffer();
+            // SAFETY: `this` is never dropped or used again, so `core` is moved out once.
+            unsafe { core::ptr::read(&this.core) }
+        }
+    }
+
+    /// Overwrites the buffered values and position with zeros.
+    #[cfg(feature = "zeroize")]
+    fn wipe_buffer(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buffer);
+        zeroize::Zeroize::zeroize(&mut self.index);
+    }
+}
+
+impl<B: BlockBackend<N>, const N: usize> RandomBackend for BlockRng<B, N> {
+    /// Returns the next buffered value, generating a new block when the buffer is exhausted.
+    fn next_u64(&mut self) -> u64 {
+        if self.index >= N {
+            self.core.generate(&mut self.buffer);
+            self.index = 0;
+        }
+        let v = self.buffer[self.index];
+        self.index += 1;
+        v
+    }
+}
+
+impl<B: BlockBackend<N> + core::fmt::Debug, const N: usize> core::fmt::Debug for BlockRng<B, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Buffered output is as sensitive as the backend state, so it is not printed.
+        f.debug_struct("BlockRng")
+            .field("core", &self.core)
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<B: BlockBackend<N> + zeroize::Zeroize, const N: usize> zeroize::Zeroize for BlockRng<B, N> {
+    /// Overwrites the backend and the buffered values with zeros.
+    fn zeroize(&mut self) {
+        self.core.zeroize();
+        self.wipe_buffer();
+    }
+}
+
+/// Wipes the buffered values; the backend wipes its own state if it zeroizes on drop.
+#[cfg(feature = "zeroize")]
+impl<B: BlockBackend<N>, const N: usize> Drop for BlockRng<B, N> {
+    fn drop(&mut self) {
+        self.wipe_buffer();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<B: BlockBackend<N> + zeroize::ZeroizeOnDrop, const N: usize> zeroize::ZeroizeOnDrop for BlockRng<B, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{ChaCha20, MT19937_64};
+
+    #[test]
+    fn block_rng_matches_backend_sequence() {
+        let mut direct = MT19937_64::new(42);
+        let mut buffered = BlockRng::new(MT19937_64::new(42));
+        for _ in 0..700 {
+            assert_eq!(buffered.next_u64(), direct.next_u64());
+        }
+
+        let mut direct = ChaCha20::new(42);
+        let mut buffered = BlockRng::new(ChaCha20::new(42));
+        for _ in 0..20 {
+            assert_eq!(buffered.next_u64(), direct.next_u64());
+        }
+    }
+
+    #[test]
+    fn block_rng_reset_skips_buffered_values() {
+        let mut a = BlockRng::new(ChaCha20::new(1));
+        let mut b = ChaCha20::new(1);
+        let _ = a.next_u64();
+        a.reset();
+        let mut block = [0u64; 8];
+        b.generate(&mut block);
+        b.generate(&mut block);
+        assert_eq!(a.next_u64(), block[0]);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn block_rng_zeroize_wipes_buffer() {
+        use zeroize::Zeroize;
+        let mut rng = BlockRng::new(ChaCha20::new(3));
+        let _ = rng.next_u64();
+        rng.zeroize();
+        assert_eq!((rng.buffer, rng.index), ([0; 8], 0));
+        let mut wiped = ChaCha20::new(4);
+        wiped.zeroize();
+        assert_eq!(rng.into_inner(), wiped);
+    }
+}
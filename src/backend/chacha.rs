@@ -0,0 +1,416 @@
+//! ChaCha stream-cipher based random number generator implementation.
+//!
+//! ChaCha is a stream cipher designed by Daniel J. Bernstein; the 20-round variant is
+//! standardized in RFC 8439. Used as a random number generator, it produces the cipher
+//! keystream for a 256-bit key, which makes it suitable for cryptographic purposes as
+//! long as the key is kept secret and seeded from a good entropy source.
+//!
+//! # Round Count
+//!
+//! The number of rounds is a const generic parameter of [`ChaCha`], with aliases for the
+//! common choices:
+//!
+//! - [`ChaCha20`]: The standard cipher. Use this for anything security-sensitive.
+//! - [`ChaCha12`]: A conservative reduced-round variant, faster than ChaCha20.
+//! - [`ChaCha8`]: The fastest variant. No practical attack is known, but its security
+//!   margin is thin; prefer it for simulations.
+//!
+//! All variants share the same key, counter, and stream layout, so switching the round
+//! count changes the output sequence but not the API.
+//!
+//! # Characteristics
+//!
+//! - State size: 136 bytes (32-byte key, 16-byte counter/stream, 64-byte output block)
+//! - Period: 2<sup>64</sup> blocks of 64 bytes per stream
+//! - Speed: Moderate
+//! - Quality: Cryptographic
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::{ChaCha8, ChaCha20}};
+//!
+//! let backend = ChaCha20::from_key([7u8; 32]);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//!
+//! // Same API, fewer rounds
+//! let mut fast = Rng::new(ChaCha8::new(12345));
+//! let x = fast.next_f64();
+//! ```
+//!
+//! # References
+//!
+//! - [RFC 8439: ChaCha20 and Poly1305 for IETF Protocols](https://www.rfc-editor.org/rfc/rfc8439)
+//! - [Daniel J. Bernstein (2008), "ChaCha, a variant of Salsa20"](https://cr.yp.to/chacha/chacha-20080128.pdf)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{BlockBackend, RandomBackend, SeedableBackend, StateSnapshot, Streamable};
+use crate::backend::SplitMix64;
+
+/// The four constant words "expand 32-byte k".
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// Number of 32-bit words in one ChaCha block.
+const BLOCK_WORDS: usize = 16;
+
+/// ChaCha random number generator struct with `ROUNDS` rounds per block.
+///
+/// `ROUNDS` must be a non-zero even number; using an odd count fails to compile.
+/// The 64-bit block counter occupies state words 12 and 13 and the 64-bit stream
+/// identifier occupies words 14 and 15, as in the original ChaCha layout.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaCha<const ROUNDS: usize> {
+    key: [u32; 8],
+    counter: u64,
+    stream: u64,
+    buffer: [u32; BLOCK_WORDS],
+    index: usize,
+}
+
+/// ChaCha with 8 rounds: fastest, intended for simulation workloads.
+pub type ChaCha8 = ChaCha<8>;
+
+/// ChaCha with 12 rounds: a middle ground between speed and security margin.
+pub type ChaCha12 = ChaCha<12>;
+
+/// ChaCha with 20 rounds: the RFC 8439 cipher, intended for cryptographic use.
+pub type ChaCha20 = ChaCha<20>;
+
+impl<const ROUNDS: usize> ChaCha<ROUNDS> {
+    /// Number of column/diagonal double rounds, checked at compile time.
+    const DOUBLE_ROUNDS: usize = {
+        assert!(ROUNDS > 0 && ROUNDS.is_multiple_of(2), "ChaCha round count must be even and non-zero");
+        ROUNDS / 2
+    };
+
+    /// Creates a new `ChaCha` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is expanded using `SplitMix64` to fill the 256-bit key. Use
+    /// [`ChaCha::from_key`] when a full-entropy key is available.
+    pub fn new(seed: u64) -> Self {
+        let mut sm = SplitMix64::new(seed);
+        let mut key = [0u8; 32];
+        for chunk in key.chunks_exact_mut(8) {
+            chunk.copy_from_slice(&sm.next_u64().to_le_bytes());
+        }
+        Self::from_key(key)
+    }
+
+    /// Creates a new `ChaCha` instance from a 256-bit key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The 32-byte key, interpreted as eight little-endian words.
+    pub fn from_key(key: [u8; 32]) -> Self {
+        let mut words = [0u32; 8];
+        for (w, chunk) in words.iter_mut().zip(key.chunks_exact(4)) {
+            *w = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        Self {
+            key: words,
+            counter: 0,
+            stream: 0,
+            buffer: [0; BLOCK_WORDS],
+            index: BLOCK_WORDS,
+        }
+    }
+
+    /// Computes the keystream block for the current counter and advances the counter.
+    fn refill(&mut self) {
+        self.buffer = block(&self.key, self.counter, self.stream, Self::DOUBLE_ROUNDS);
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+
+    /// Returns the next buffered 32-bit word, refilling the block when exhausted.
+    #[inline]
+    fn next_word(&mut self) -> u32 {
+        if self.index >= BLOCK_WORDS {
+            self.refill();
+        }
+        let w = self.buffer[self.index];
+        self.index += 1;
+        w
+    }
+}
+
+/// Applies the ChaCha quarter round to words `a`, `b`, `c`, `d` of `x`.
+#[inline(always)]
+fn quarter_round(x: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+/// Computes one ChaCha block for the given key, counter, and stream.
+fn block(key: &[u32; 8], counter: u64, stream: u64, double_rounds: usize) -> [u32; BLOCK_WORDS] {
+    let mut input = [0u32; BLOCK_WORDS];
+    input[..4].copy_from_slice(&CONSTANTS);
+    input[4..12].copy_from_slice(key);
+    input[12] = counter as u32;
+    input[13] = (counter >> 32) as u32;
+    input[14] = stream as u32;
+    input[15] = (stream >> 32) as u32;
+
+    let mut x = input;
+    for _ in 0..double_rounds {
+        // Column rounds
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+    }
+    for (out, inp) in x.iter_mut().zip(input.iter()) {
+        *out = out.wrapping_add(*inp);
+    }
+    x
+}
+
+impl<const ROUNDS: usize> RandomBackend for ChaCha<ROUNDS> {
+    /// Generates the next random `u64` from two consecutive keystream words.
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_word());
+        let hi = u64::from(self.next_word());
+        (hi << 32) | lo
+    }
+
+    /// Generates the next random `u32` directly from the keystream.
+    fn next_u32(&mut self) -> u32 {
+        self.next_word()
+    }
+}
+
+impl<const ROUNDS: usize> BlockBackend<8> for ChaCha<ROUNDS> {
+    /// Packs a whole keystream block into eight words when positioned at a block boundary.
+    fn generate(&mut self, out: &mut [u64; 8]) {
+        if self.index < BLOCK_WORDS {
+            for o in out.iter_mut() {
+                *o = self.next_u64();
+            }
+            return;
+        }
+        let words = block(&self.key, self.counter, self.stream, Self::DOUBLE_ROUNDS);
+        self.counter = self.counter.wrapping_add(1);
+        for (o, pair) in out.iter_mut().zip(words.chunks_exact(2)) {
+            *o = (u64::from(pair[1]) << 32) | u64::from(pair[0]);
+        }
+    }
+}
+
+impl<const ROUNDS: usize> SeedableBackend for ChaCha<ROUNDS> {
+    type Seed = [u8; 32];
+
+    /// Uses the seed as the 256-bit key.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_key(seed)
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl<const ROUNDS: usize> Streamable for ChaCha<ROUNDS> {
+    /// Returns the 64-bit stream identifier (nonce).
+    fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Sets the 64-bit stream identifier (nonce), keeping the block counter.
+    fn set_stream(&mut self, stream: u64) {
+        self.stream = stream;
+        self.index = BLOCK_WORDS;
+    }
+}
+
+/// Layout (120 bytes): `key` (8 x u32), `counter` (u64), `stream` (u64), `buffer`
+/// (16 x u32), `index` (u64, at most 16).
+impl<const ROUNDS: usize> StateSnapshot for ChaCha<ROUNDS> {
+    type Bytes = [u8; 120];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 120];
+        let mut w = StateWriter::new(&mut out);
+        w.u32s(&self.key);
+        w.u64(self.counter);
+        w.u64(self.stream);
+        w.u32s(&self.buffer);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 120)?;
+        let key = r.u32s();
+        let counter = r.u64();
+        let stream = r.u64();
+        let buffer = r.u32s();
+        let index = r.index(BLOCK_WORDS)?;
+        Ok(Self { key, counter, stream, buffer, index })
+    }
+}
+
+impl<const ROUNDS: usize> core::fmt::Debug for ChaCha<ROUNDS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Never print the key or buffered keystream.
+        f.debug_struct("ChaCha")
+            .field("rounds", &ROUNDS)
+            .field("counter", &self.counter)
+            .field("stream", &self.stream)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize> zeroize::Zeroize for ChaCha<ROUNDS> {
+    /// Overwrites the key, counter, stream and buffered keystream with zeros.
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.counter.zeroize();
+        self.stream.zeroize();
+        self.buffer.zeroize();
+        self.index.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize> Drop for ChaCha<ROUNDS> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize> zeroize::ZeroizeOnDrop for ChaCha<ROUNDS> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words_from_bytes(bytes: &[u8]) -> [u32; BLOCK_WORDS] {
+        let mut out = [0u32; BLOCK_WORDS];
+        for (w, chunk) in out.iter_mut().zip(bytes.chunks_exact(4)) {
+            *w = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        out
+    }
+
+    #[test]
+    fn chacha20_rfc8439_block_function() {
+        // RFC 8439, section 2.3.2: counter = 1, nonce = 00:00:00:09:00:00:00:4a:00:00:00:00
+        let mut key = [0u8; 32];
+        for (i, b) in key.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let chacha = ChaCha20::from_key(key);
+        let counter = (0x0900_0000u64 << 32) | 1;
+        let stream = 0x4a00_0000u64;
+        let expected: [u8; 64] = [
+            0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20,
+            0x71, 0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a,
+            0xc3, 0xd4, 0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2,
+            0xd7, 0x05, 0xd9, 0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9,
+            0xcb, 0xd0, 0x83, 0xe8, 0xa2, 0x50, 0x3c, 0x4e,
+        ];
+        assert_eq!(block(&chacha.key, counter, stream, 10), words_from_bytes(&expected));
+    }
+
+    #[test]
+    fn chacha20_rfc8439_zero_key_keystream() {
+        // RFC 8439, appendix A.1, test vectors #1 and #2 (zero key and nonce, counters 0 and 1)
+        let expected: [u8; 128] = [
+            0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86,
+            0xbd, 0x28, 0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc,
+            0x8b, 0x77, 0x0d, 0xc7, 0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24,
+            0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37, 0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c,
+            0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86, 0x9f, 0x07, 0xe7, 0xbe, 0x55, 0x51,
+            0x38, 0x7a, 0x98, 0xba, 0x97, 0x7c, 0x73, 0x2d, 0x08, 0x0d, 0xcb, 0x0f, 0x29, 0xa0,
+            0x48, 0xe3, 0x65, 0x69, 0x12, 0xc6, 0x53, 0x3e, 0x32, 0xee, 0x7a, 0xed, 0x29, 0xb7,
+            0x21, 0x76, 0x9c, 0xe6, 0x4e, 0x43, 0xd5, 0x71, 0x33, 0xb0, 0x74, 0xd8, 0x39, 0xd5,
+            0x31, 0xed, 0x1f, 0x28, 0x51, 0x0a, 0xfb, 0x45, 0xac, 0xe1, 0x0a, 0x1f, 0x4b, 0x79,
+            0x4d, 0x6f,
+        ];
+        let mut chacha = ChaCha20::from_key([0u8; 32]);
+        for chunk in expected.chunks_exact(8) {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(chunk);
+            assert_eq!(chacha.next_u64(), u64::from_le_bytes(b));
+        }
+    }
+
+    #[test]
+    fn reduced_round_zero_key_keystreams() {
+        // draft-strombergson-chacha-test-vectors, TC1 (zero key and IV), first block
+        let chacha8: [u8; 64] = [
+            0x3e, 0x00, 0xef, 0x2f, 0x89, 0x5f, 0x40, 0xd6, 0x7f, 0x5b, 0xb8, 0xe8, 0x1f, 0x09,
+            0xa5, 0xa1, 0x2c, 0x84, 0x0e, 0xc3, 0xce, 0x9a, 0x7f, 0x3b, 0x18, 0x1b, 0xe1, 0x88,
+            0xef, 0x71, 0x1a, 0x1e, 0x98, 0x4c, 0xe1, 0x72, 0xb9, 0x21, 0x6f, 0x41, 0x9f, 0x44,
+            0x53, 0x67, 0x45, 0x6d, 0x56, 0x19, 0x31, 0x4a, 0x42, 0xa3, 0xda, 0x86, 0xb0, 0x01,
+            0x38, 0x7b, 0xfd, 0xb8, 0x0e, 0x0c, 0xfe, 0x42,
+        ];
+        let chacha12: [u8; 64] = [
+            0x9b, 0xf4, 0x9a, 0x6a, 0x07, 0x55, 0xf9, 0x53, 0x81, 0x1f, 0xce, 0x12, 0x5f, 0x26,
+            0x83, 0xd5, 0x04, 0x29, 0xc3, 0xbb, 0x49, 0xe0, 0x74, 0x14, 0x7e, 0x00, 0x89, 0xa5,
+            0x2e, 0xae, 0x15, 0x5f, 0x05, 0x64, 0xf8, 0x79, 0xd2, 0x7a, 0xe3, 0xc0, 0x2c, 0xe8,
+            0x28, 0x34, 0xac, 0xfa, 0x8c, 0x79, 0x3a, 0x62, 0x9f, 0x2c, 0xa0, 0xde, 0x69, 0x19,
+            0x61, 0x0b, 0xe8, 0x2f, 0x41, 0x13, 0x26, 0xbe,
+        ];
+        let mut a = ChaCha8::from_key([0u8; 32]);
+        let mut b = ChaCha12::from_key([0u8; 32]);
+        for (x, y) in chacha8.chunks_exact(8).zip(chacha12.chunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(x);
+            assert_eq!(a.next_u64(), u64::from_le_bytes(bytes));
+            bytes.copy_from_slice(y);
+            assert_eq!(b.next_u64(), u64::from_le_bytes(bytes));
+        }
+    }
+
+    #[test]
+    fn chacha20_u32_and_u64_share_keystream() {
+        let mut a = ChaCha20::new(99);
+        let mut b = ChaCha20::new(99);
+        // Misalign the u64 reads across the block boundary.
+        let _ = a.next_u32();
+        let _ = b.next_u32();
+        for _ in 0..20 {
+            let lo = u64::from(a.next_u32());
+            let hi = u64::from(a.next_u32());
+            assert_eq!(b.next_u64(), (hi << 32) | lo);
+        }
+    }
+
+    #[test]
+    fn chacha_round_counts_produce_distinct_streams() {
+        let a = ChaCha8::new(5).next_u64();
+        let b = ChaCha12::new(5).next_u64();
+        let c = ChaCha20::new(5).next_u64();
+        assert_ne!(a, b);
+        assert_ne!(b, c);
+        assert_eq!(c, ChaCha::<20>::new(5).next_u64());
+    }
+
+    #[test]
+    fn chacha_stream_sets_nonce_words() {
+        let mut chacha = ChaCha20::new(3).with_stream(0x0102_0304_0506_0708);
+        let expected = block(&chacha.key, 0, 0x0102_0304_0506_0708, 10);
+        assert_eq!(chacha.next_u32(), expected[0]);
+        assert_eq!(chacha.stream(), 0x0102_0304_0506_0708);
+    }
+}
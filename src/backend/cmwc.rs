@@ -0,0 +1,214 @@
+//! Complementary multiply-with-carry (CMWC) random number generator implementation.
+//!
+//! CMWC is George Marsaglia's lag-r multiply-with-carry generator with a complemented
+//! output. Each step costs a single 32x32→64-bit multiply and an add, yet the CMWC4096
+//! configuration implemented here has an astronomically long period. Not suitable for
+//! cryptographic purposes.
+//!
+//! The lag ([`Cmwc::LAG`]), the current carry, and the lag-table position are all exposed,
+//! and [`Cmwc::from_state`] accepts a complete state, so a sequence can be reproduced
+//! exactly from a state captured elsewhere.
+//!
+//! # Characteristics
+//!
+//! - State size: 16 KB (4096 * 4 bytes, plus carry and index)
+//! - Period: about 2<sup>131104</sup>
+//! - Speed: Very Fast
+//! - Quality: Good
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Cmwc};
+//!
+//! let backend = Cmwc::new(314159);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u32();
+//! ```
+//!
+//! # References
+//!
+//! - [George Marsaglia (2003), "Random Number Generators"](https://digitalcommons.wayne.edu/jmasm/vol2/iss1/2/)
+//! - [Wikipedia: Multiply-with-carry pseudorandom number generator](https://en.wikipedia.org/wiki/Multiply-with-carry_pseudorandom_number_generator)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot};
+use crate::backend::SplitMix64;
+
+/// Multiplier `a` of the CMWC4096 recurrence.
+const MULTIPLIER: u64 = 18782;
+
+/// CMWC4096 random number generator struct.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmwc {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    q: [u32; Cmwc::LAG],
+    carry: u32,
+    index: usize,
+}
+
+impl Cmwc {
+    /// The lag `r` of the recurrence, i.e. the number of words in the lag table.
+    pub const LAG: usize = 4096;
+
+    /// Creates a new `Cmwc` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is expanded using `SplitMix64` to fill the lag table and the carry.
+    pub fn new(seed: u64) -> Self {
+        let mut sm = SplitMix64::new(seed);
+        let mut q = [0u32; Self::LAG];
+        for pair in q.chunks_exact_mut(2) {
+            let v = sm.next_u64();
+            pair[0] = v as u32;
+            pair[1] = (v >> 32) as u32;
+        }
+        let carry = (sm.next_u64() % MULTIPLIER) as u32;
+        Self {
+            q,
+            carry,
+            index: Self::LAG - 1,
+        }
+    }
+
+    /// Creates a new `Cmwc` from an explicit lag table and carry.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The lag table; the first generated value uses `q[0]`.
+    /// * `carry` - The initial carry, which must be less than the multiplier (18782).
+    ///
+    /// Returns an error if the carry is out of range.
+    pub fn from_state(q: [u32; Self::LAG], carry: u32) -> core::result::Result<Self, crate::AporiaError> {
+        if u64::from(carry) >= MULTIPLIER {
+            return Err(crate::AporiaError::InvalidSeed("CMWC carry must be less than 18782"));
+        }
+        Ok(Self {
+            q,
+            carry,
+            index: Self::LAG - 1,
+        })
+    }
+
+    /// Returns the current carry value.
+    pub fn carry(&self) -> u32 {
+        self.carry
+    }
+
+    /// Returns the position in the lag table of the most recently generated value.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl RandomBackend for Cmwc {
+    /// Generates the next random `u64` from two consecutive `u32` outputs.
+    ///
+    /// The first output forms the low half and the second the high half.
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// Generates the next random `u32` using the CMWC recurrence.
+    fn next_u32(&mut self) -> u32 {
+        self.index = (self.index + 1) & (Self::LAG - 1);
+        let t = MULTIPLIER * u64::from(self.q[self.index]) + u64::from(self.carry);
+        self.carry = (t >> 32) as u32;
+        let mut x = (t as u32).wrapping_add(self.carry);
+        if x < self.carry {
+            x = x.wrapping_add(1);
+            self.carry += 1;
+        }
+        self.q[self.index] = 0xffff_fffe - x;
+        self.q[self.index]
+    }
+}
+
+impl SeedableBackend for Cmwc {
+    type Seed = [u8; 8];
+
+    /// Seeds with the little-endian seed word, as `new` does.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (16396 bytes): `q` (4096 x u32), `carry` (u32, below 18782), `index` (u64, below
+/// 4096).
+impl StateSnapshot for Cmwc {
+    type Bytes = [u8; 16396];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 16396];
+        let mut w = StateWriter::new(&mut out);
+        w.u32s(&self.q);
+        w.u32(self.carry);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 16396)?;
+        let q = r.u32s();
+        let carry = r.u32();
+        if u64::from(carry) >= MULTIPLIER {
+            return Err(crate::AporiaError::InvalidState("CMWC carry must be less than 18782"));
+        }
+        let index = r.index(Self::LAG - 1)?;
+        Ok(Self { q, carry, index })
+    }
+}
+
+impl core::fmt::Debug for Cmwc {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Avoid dumping the large lag table; show only minimal info.
+        f.debug_struct("Cmwc")
+            .field("carry", &self.carry)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cmwc_matches_reference() {
+        // Marsaglia's rand_cmwc() with Q[i] = i * 2654435761 and c = 1234, after 3 * 4096 draws.
+        let mut q = [0u32; Cmwc::LAG];
+        for (i, x) in q.iter_mut().enumerate() {
+            *x = (i as u32).wrapping_mul(2654435761);
+        }
+        let mut cmwc = Cmwc::from_state(q, 1234).unwrap();
+        for _ in 0..3 * Cmwc::LAG {
+            let _ = cmwc.next_u32();
+        }
+        let expected = [304467703, 3851305736, 1292566650, 3019631657, 451729368];
+        for e in expected {
+            assert_eq!(cmwc.next_u32(), e);
+        }
+        assert_eq!(cmwc.carry(), 6043);
+    }
+
+    #[test]
+    fn cmwc_rejects_out_of_range_carry() {
+        assert!(Cmwc::from_state([0; Cmwc::LAG], 18782).is_err());
+
+        let mut bytes = Cmwc::new(1).to_state_bytes();
+        bytes[4 * Cmwc::LAG..4 * Cmwc::LAG + 4].copy_from_slice(&18782u32.to_le_bytes());
+        assert!(Cmwc::from_state_bytes(&bytes).is_err());
+        bytes[4 * Cmwc::LAG..4 * Cmwc::LAG + 4].copy_from_slice(&18781u32.to_le_bytes());
+        assert!(Cmwc::from_state_bytes(&bytes).is_ok());
+    }
+}
@@ -0,0 +1,139 @@
+//! Adapter that counts the output drawn from a backend.
+//!
+//! [`CountingBackend`] forwards every call to an inner backend unchanged and records how
+//! many words and bytes it has served. Comparing the counts of two runs checks that they
+//! consumed exactly the same amount of randomness, which is often the first thing to
+//! verify when a seeded simulation stops reproducing. The counts can also drive a
+//! reseeding or rotation policy of the caller's own.
+//!
+//! A word is one `next_u64`, `next_u32` or `next_f64` call; `next_u128` counts as two
+//! `next_u64` words. `fill_bytes` adds only to the byte count, since backends serve it in
+//! different word sizes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::{CountingBackend, Xoshiro256StarStar}};
+//!
+//! let mut rng = Rng::new(CountingBackend::new(Xoshiro256StarStar::new(1)));
+//! let _ = rng.gen_range(0..10).unwrap();
+//! let mut buf = [0u8; 5];
+//! rng.fill_bytes(&mut buf);
+//! assert_eq!(rng.draw_count(), 1);
+//! assert_eq!(rng.byte_count(), 9); // a 32-bit word for the range, then 5 bytes
+//! ```
+
+use super::RandomBackend;
+use crate::Rng;
+
+/// Adapter that forwards to `B` while counting the words and bytes it produces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountingBackend<B: RandomBackend> {
+    inner: B,
+    draws: u64,
+    bytes: u64,
+}
+
+impl<B: RandomBackend> CountingBackend<B> {
+    /// Wraps `inner` with both counts at zero.
+    pub fn new(inner: B) -> Self {
+        Self { inner, draws: 0, bytes: 0 }
+    }
+
+    /// Returns the number of words served since creation or the last reset.
+    pub fn draw_count(&self) -> u64 {
+        self.draws
+    }
+
+    /// Returns the number of output bytes served since creation or the last reset,
+    /// including those of word draws.
+    pub fn byte_count(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Sets both counts back to zero.
+    pub fn reset_counts(&mut self) {
+        self.draws = 0;
+        self.bytes = 0;
+    }
+
+    /// Returns a reference to the inner backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Returns the inner backend, discarding the counts.
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    fn count(&mut self, draws: u64, bytes: u64) {
+        self.draws = self.draws.wrapping_add(draws);
+        self.bytes = self.bytes.wrapping_add(bytes);
+    }
+}
+
+impl<B: RandomBackend> RandomBackend for CountingBackend<B> {
+    fn next_u64(&mut self) -> u64 {
+        self.count(1, 8);
+        self.inner.next_u64()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.count(1, 4);
+        self.inner.next_u32()
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.count(1, 8);
+        self.inner.next_f64()
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        self.count(0, buf.len() as u64);
+        self.inner.fill_bytes(buf);
+    }
+}
+
+impl<B: RandomBackend> Rng<CountingBackend<B>> {
+    /// Returns the number of words drawn; see [`CountingBackend::draw_count`].
+    pub fn draw_count(&self) -> u64 {
+        self.backend.draw_count()
+    }
+
+    /// Returns the number of bytes drawn; see [`CountingBackend::byte_count`].
+    pub fn byte_count(&self) -> u64 {
+        self.backend.byte_count()
+    }
+
+    /// Sets both counts back to zero; see [`CountingBackend::reset_counts`].
+    pub fn reset_counts(&mut self) {
+        self.backend.reset_counts()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Pcg32;
+
+    #[test]
+    fn counts_without_changing_output() {
+        let mut counted = Rng::new(CountingBackend::new(Pcg32::new(3, 4)));
+        let mut plain = Rng::new(Pcg32::new(3, 4));
+        assert_eq!(counted.next_u64(), plain.next_u64());
+        assert_eq!(counted.next_u32(), plain.next_u32());
+        assert_eq!(counted.next_f64(), plain.next_f64());
+        assert_eq!(counted.next_u128(), plain.next_u128());
+        assert_eq!((counted.draw_count(), counted.byte_count()), (5, 36));
+
+        let (mut a, mut b) = ([0u8; 11], [0u8; 11]);
+        counted.fill_bytes(&mut a);
+        plain.fill_bytes(&mut b);
+        assert_eq!(a, b);
+        assert_eq!((counted.draw_count(), counted.byte_count()), (5, 47));
+
+        counted.reset_counts();
+        assert_eq!((counted.draw_count(), counted.byte_count()), (0, 0));
+    }
+}
@@ -0,0 +1,398 @@
+//! CTR-DRBG deterministic random bit generator (NIST SP 800-90A).
+//!
+//! This is the CTR_DRBG mechanism from SP 800-90A Rev. 1 using AES-256 with the block
+//! cipher derivation function, without prediction resistance. With the derivation function
+//! the entropy input, nonce, personalization string and additional input may have any
+//! length. Like [`crate::backend::HmacDrbg`], it exposes instantiate, reseed and generate
+//! and implements [`RandomBackend`] for use with [`crate::Rng`].
+//!
+//! AES is implemented in software inside this crate, so the backend has no dependencies;
+//! the implementation is not hardened against cache-timing side channels.
+//!
+//! Through [`RandomBackend`], `next_u64` draws from a 32-byte buffer that is refilled with
+//! one generate request at a time, and `fill_bytes` issues generate requests directly.
+//!
+//! # Characteristics
+//!
+//! - State size: ~300 bytes (expanded AES key schedule, 16-byte value, output buffer)
+//! - Period: Not applicable (reseeded before 2<sup>48</sup> requests)
+//! - Speed: Moderate
+//! - Quality: Cryptographic (256-bit security strength with adequate entropy input)
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::CtrDrbg};
+//!
+//! let entropy = [0x42u8; 32];
+//! let nonce = [0x24u8; 16];
+//! let mut drbg = CtrDrbg::instantiate(&entropy, &nonce, b"my application");
+//!
+//! let mut out = [0u8; 64];
+//! drbg.generate(&mut out, &[]).unwrap();
+//!
+//! let mut rng = Rng::new(drbg);
+//! let random_number = rng.next_u64();
+//! ```
+//!
+//! # References
+//!
+//! - [NIST SP 800-90A Rev. 1](https://csrc.nist.gov/publications/detail/sp/800-90a/rev-1/final)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot};
+use super::aes::{Aes256, BLOCK_LEN, KEY_LEN};
+
+/// Seed length: key length plus block length.
+const SEED_LEN: usize = KEY_LEN + BLOCK_LEN;
+
+/// Size of the output buffer used by `next_u64`.
+const BUFFER_LEN: usize = 32;
+
+/// CTR-DRBG (AES-256, with derivation function) random number generator struct.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CtrDrbg {
+    cipher: Aes256,
+    v: [u8; BLOCK_LEN],
+    reseed_counter: u64,
+    buffer: [u8; BUFFER_LEN],
+    index: usize,
+}
+
+impl CtrDrbg {
+    /// Maximum number of bytes a single generate request may return (2<sup>19</sup> bits).
+    pub const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+
+    /// Number of generate requests allowed between reseeds.
+    pub const RESEED_INTERVAL: u64 = 1 << 48;
+
+    /// Creates a new `CtrDrbg` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value, used as the (little-endian) entropy input.
+    ///
+    /// A 64-bit seed is far below the 256-bit security strength of the mechanism; this
+    /// constructor is meant for reproducible non-security use. Use
+    /// [`CtrDrbg::instantiate`] with real entropy otherwise.
+    pub fn new(seed: u64) -> Self {
+        Self::instantiate(&seed.to_le_bytes(), &[], &[])
+    }
+
+    /// Instantiates the DRBG (SP 800-90A `CTR_DRBG_Instantiate_algorithm`, with df).
+    ///
+    /// # Arguments
+    ///
+    /// * `entropy` - The entropy input; should contain at least 32 bytes of entropy.
+    /// * `nonce` - The nonce; typically at least 16 bytes.
+    /// * `personalization` - Optional personalization string (may be empty).
+    pub fn instantiate(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        let seed = derive(&[entropy, nonce, personalization]);
+        let mut drbg = Self {
+            cipher: Aes256::new(&[0; KEY_LEN]),
+            v: [0; BLOCK_LEN],
+            reseed_counter: 1,
+            buffer: [0; BUFFER_LEN],
+            index: BUFFER_LEN,
+        };
+        drbg.update(&seed);
+        drbg
+    }
+
+    /// Reseeds the DRBG with fresh entropy (SP 800-90A `CTR_DRBG_Reseed_algorithm`).
+    ///
+    /// Any buffered output for `next_u64` is discarded.
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) {
+        let seed = derive(&[entropy, additional_input]);
+        self.update(&seed);
+        self.reseed_counter = 1;
+        self.index = BUFFER_LEN;
+    }
+
+    /// Fills `out` with one generate request (SP 800-90A `CTR_DRBG_Generate_algorithm`).
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The output buffer; at most [`CtrDrbg::MAX_BYTES_PER_REQUEST`] bytes.
+    /// * `additional_input` - Optional additional input (may be empty).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::Drbg`] if the request is too large or if the DRBG must
+    /// be reseeded first.
+    pub fn generate(
+        &mut self,
+        out: &mut [u8],
+        additional_input: &[u8],
+    ) -> core::result::Result<(), crate::AporiaError> {
+        if out.len() > Self::MAX_BYTES_PER_REQUEST {
+            return Err(crate::AporiaError::Drbg("request exceeds maximum length"));
+        }
+        if self.reseed_counter > Self::RESEED_INTERVAL {
+            return Err(crate::AporiaError::Drbg("reseed required"));
+        }
+
+        let additional = if additional_input.is_empty() {
+            [0; SEED_LEN]
+        } else {
+            let additional = derive(&[additional_input]);
+            self.update(&additional);
+            additional
+        };
+        for chunk in out.chunks_mut(BLOCK_LEN) {
+            let block = self.next_block();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(&additional);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// Returns the number of generate requests since the last (re)seed, plus one.
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// Increments `V` and returns its encryption under the current key.
+    fn next_block(&mut self) -> [u8; BLOCK_LEN] {
+        let v = u128::from_be_bytes(self.v).wrapping_add(1);
+        self.v = v.to_be_bytes();
+        let mut block = self.v;
+        self.cipher.encrypt_block(&mut block);
+        block
+    }
+
+    /// The `CTR_DRBG_Update` function.
+    fn update(&mut self, provided: &[u8; SEED_LEN]) {
+        let mut temp = [0u8; SEED_LEN];
+        for chunk in temp.chunks_exact_mut(BLOCK_LEN) {
+            chunk.copy_from_slice(&self.next_block());
+        }
+        for (t, p) in temp.iter_mut().zip(provided) {
+            *t ^= p;
+        }
+        let mut key = [0u8; KEY_LEN];
+        key.copy_from_slice(&temp[..KEY_LEN]);
+        self.cipher = Aes256::new(&key);
+        self.v.copy_from_slice(&temp[KEY_LEN..]);
+    }
+}
+
+/// CBC-MAC state used by the `BCC` function of the derivation function.
+struct Bcc<'a> {
+    cipher: &'a Aes256,
+    chain: [u8; BLOCK_LEN],
+    len: usize,
+}
+
+impl Bcc<'_> {
+    fn absorb(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.chain[self.len] ^= byte;
+            self.len += 1;
+            if self.len == BLOCK_LEN {
+                self.cipher.encrypt_block(&mut self.chain);
+                self.len = 0;
+            }
+        }
+    }
+}
+
+/// The `Block_Cipher_df` derivation function, producing `SEED_LEN` bytes from the
+/// concatenation of `inputs`.
+fn derive(inputs: &[&[u8]]) -> [u8; SEED_LEN] {
+    let input_len: usize = inputs.iter().map(|i| i.len()).sum();
+    let df_key: [u8; KEY_LEN] = core::array::from_fn(|i| i as u8);
+    let df_cipher = Aes256::new(&df_key);
+
+    let mut temp = [0u8; SEED_LEN];
+    for (i, chunk) in temp.chunks_exact_mut(BLOCK_LEN).enumerate() {
+        let mut bcc = Bcc {
+            cipher: &df_cipher,
+            chain: [0; BLOCK_LEN],
+            len: 0,
+        };
+        // IV = i || 0^96, then S = L || N || input || 0x80 || zero padding.
+        let mut iv = [0u8; BLOCK_LEN];
+        iv[..4].copy_from_slice(&(i as u32).to_be_bytes());
+        bcc.absorb(&iv);
+        bcc.absorb(&(input_len as u32).to_be_bytes());
+        bcc.absorb(&(SEED_LEN as u32).to_be_bytes());
+        for input in inputs {
+            bcc.absorb(input);
+        }
+        bcc.absorb(&[0x80]);
+        while bcc.len != 0 {
+            bcc.absorb(&[0]);
+        }
+        chunk.copy_from_slice(&bcc.chain);
+    }
+
+    let mut key = [0u8; KEY_LEN];
+    key.copy_from_slice(&temp[..KEY_LEN]);
+    let cipher = Aes256::new(&key);
+    let mut x = [0u8; BLOCK_LEN];
+    x.copy_from_slice(&temp[KEY_LEN..]);
+
+    let mut out = [0u8; SEED_LEN];
+    for chunk in out.chunks_exact_mut(BLOCK_LEN) {
+        cipher.encrypt_block(&mut x);
+        chunk.copy_from_slice(&x);
+    }
+    out
+}
+
+impl RandomBackend for CtrDrbg {
+    /// Generates the next random `u64` from the buffered generate output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the DRBG requires a reseed.
+    fn next_u64(&mut self) -> u64 {
+        if self.index + 8 > BUFFER_LEN {
+            let mut buffer = [0u8; BUFFER_LEN];
+            if let Err(e) = self.generate(&mut buffer, &[]) {
+                panic!("{}", e);
+            }
+            self.buffer = buffer;
+            self.index = 0;
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.buffer[self.index..self.index + 8]);
+        self.index += 8;
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Fills `buf` with as few generate requests as the per-request limit allows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the DRBG requires a reseed.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(Self::MAX_BYTES_PER_REQUEST) {
+            if let Err(e) = self.generate(chunk, &[]) {
+                panic!("{}", e);
+            }
+        }
+    }
+}
+
+impl SeedableBackend for CtrDrbg {
+    type Seed = [u8; 48];
+
+    /// Instantiates with the first 32 bytes as entropy input and the last 16 as nonce.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::instantiate(&seed[..32], &seed[32..], &[])
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (96 bytes): AES `key` (32 bytes), `v` (16 bytes), `reseed_counter` (u64),
+/// `buffer` (32 bytes), `index` (u64, at most 32).
+impl StateSnapshot for CtrDrbg {
+    type Bytes = [u8; 96];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 96];
+        let mut w = StateWriter::new(&mut out);
+        w.bytes(&self.cipher.key());
+        w.bytes(&self.v);
+        w.u64(self.reseed_counter);
+        w.bytes(&self.buffer);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 96)?;
+        let cipher = Aes256::new(&r.bytes());
+        let v = r.bytes();
+        let reseed_counter = r.u64();
+        let buffer = r.bytes();
+        let index = r.index(BUFFER_LEN)?;
+        Ok(Self { cipher, v, reseed_counter, buffer, index })
+    }
+}
+
+impl core::fmt::Debug for CtrDrbg {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Do not print the key or value.
+        f.debug_struct("CtrDrbg")
+            .field("reseed_counter", &self.reseed_counter)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for CtrDrbg {
+    /// Overwrites the AES key schedule, `V` and the output buffer with zeros.
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.v.zeroize();
+        self.reseed_counter.zeroize();
+        self.buffer.zeroize();
+        self.index.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for CtrDrbg {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for CtrDrbg {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex<const N: usize>(s: &str) -> [u8; N] {
+        core::array::from_fn(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+    }
+
+    // Expected bytes below were cross-checked against an independent transcription of the
+    // SP 800-90A algorithms built on a separate AES implementation.
+
+    #[test]
+    fn ctr_drbg_instantiate_and_generate() {
+        let entropy: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let nonce: [u8; 16] = core::array::from_fn(|i| 32 + i as u8);
+        let mut drbg = CtrDrbg::instantiate(&entropy, &nonce, &[]);
+        let mut out = [0u8; 64];
+        drbg.generate(&mut out, &[]).unwrap();
+        drbg.generate(&mut out, &[]).unwrap();
+        let expected: [u8; 64] = hex(concat!(
+            "c5b1ae8dbc23056b19cf88b1997e8498b4b394c0db9760a3704b0c1d6a4c926e",
+            "5bfe234afb31b498a30810bdb8d3542b5530849f8b9b8bea8cad70e633f32a24",
+        ));
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn ctr_drbg_reseed_and_additional_input() {
+        let entropy: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let nonce: [u8; 16] = core::array::from_fn(|i| 32 + i as u8);
+        let reseed: [u8; 32] = core::array::from_fn(|i| 0x80 + i as u8);
+        let mut drbg = CtrDrbg::instantiate(&entropy, &nonce, b"aporia");
+        drbg.reseed(&reseed, b"extra");
+        let mut out = [0u8; 32];
+        drbg.generate(&mut out, b"add").unwrap();
+        let expected: [u8; 32] =
+            hex("a3b09d62e0b80098bd6360c9b64cbb039792d9cb5a6ec8087fdc78614040d4c0");
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn ctr_drbg_rejects_oversized_request() {
+        let mut drbg = CtrDrbg::new(1);
+        let mut out = [0u8; CtrDrbg::MAX_BYTES_PER_REQUEST + 1];
+        assert!(drbg.generate(&mut out, &[]).is_err());
+    }
+}
@@ -0,0 +1,430 @@
+//! Fortuna cryptographically secure generator with an entropy accumulator.
+//!
+//! Fortuna (Ferguson and Schneier, *Practical Cryptography*) combines an AES-256 counter-mode
+//! generator with 32 entropy pools. Callers feed environmental entropy (timings, interrupts,
+//! sensor noise, ...) through [`Fortuna::add_entropy`]; each source distributes its events
+//! round-robin over the pools. The generator reseeds itself automatically: pool `i` takes
+//! part in every 2<sup>i</sup>-th reseed, so even an attacker who can observe or control some
+//! sources cannot prevent the generator from eventually recovering from a state compromise.
+//!
+//! The classic design limits reseeds to one per 100 ms. This implementation does not enforce
+//! that interval: reseeds are triggered whenever pool 0 has accumulated
+//! [`Fortuna::MIN_POOL_SIZE`] bytes of events at the time of a request. See
+//! [`Fortuna`](Fortuna#reseed-interval) for what this means for callers.
+//!
+//! Through [`RandomBackend`], `next_u64` draws from a 32-byte buffer that is refilled with
+//! one request at a time, and `fill_bytes` issues requests directly. After every request the
+//! generator replaces its key, so earlier output cannot be reconstructed from a later state.
+//!
+//! # Characteristics
+//!
+//! - State size: ~4.2 KB (32 pool hashers, AES key schedule, counter, buffer)
+//! - Period: Not applicable (continuously rekeyed and reseeded)
+//! - Speed: Moderate
+//! - Quality: Cryptographic (given sufficient entropy input)
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Fortuna};
+//!
+//! let mut fortuna = Fortuna::from_seed(&[0x5a; 32]);
+//!
+//! // Keep folding in entropy from several sources as it becomes available.
+//! fortuna.add_entropy(0, &[0x12, 0x34, 0x56, 0x78]);
+//! fortuna.add_entropy(1, b"interrupt timing sample");
+//!
+//! let mut rng = Rng::new(fortuna);
+//! let random_number = rng.next_u64();
+//! ```
+//!
+//! # References
+//!
+//! - [Ferguson, Schneier, Kohno (2010), *Cryptography Engineering*, chapter 9](https://www.schneier.com/books/cryptography-engineering/)
+//! - [Wikipedia: Fortuna (PRNG)](https://en.wikipedia.org/wiki/Fortuna_(PRNG))
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot};
+use super::aes::{Aes256, BLOCK_LEN, KEY_LEN};
+use super::sha256::{DIGEST_LEN, Sha256};
+
+/// Number of entropy pools.
+const POOLS: usize = 32;
+
+/// Size of the output buffer used by `next_u64`.
+const BUFFER_LEN: usize = 32;
+
+/// Fortuna random number generator struct.
+///
+/// # Reseed interval
+///
+/// This implementation deviates from the specification, which allows at most one reseed
+/// from the pools per 100 ms. There is no clock to enforce that interval, so a request
+/// reseeds whenever pool 0 holds [`Fortuna::MIN_POOL_SIZE`] bytes. A source that adds
+/// events quickly can therefore force frequent reseeds, so each pool gathers entropy over
+/// a shorter time than the design assumes. Callers that need the interval must rate-limit
+/// their requests or their calls to [`Fortuna::add_entropy`] themselves.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fortuna {
+    cipher: Aes256,
+    key: [u8; KEY_LEN],
+    counter: u128,
+    pools: [Sha256; POOLS],
+    pool0_len: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    next_pool: [u8; 256],
+    reseed_count: u64,
+    buffer: [u8; BUFFER_LEN],
+    index: usize,
+}
+
+impl Fortuna {
+    /// Number of bytes of events pool 0 must hold before a request triggers a reseed.
+    pub const MIN_POOL_SIZE: usize = 64;
+
+    /// Maximum number of bytes produced under one key before rekeying (2<sup>20</sup>).
+    pub const MAX_BYTES_PER_REQUEST: usize = 1 << 20;
+
+    /// Creates a new `Fortuna` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value, used as the (little-endian) generator seed.
+    ///
+    /// A 64-bit seed gives no security on its own; this constructor is meant for
+    /// reproducible use. Use [`Fortuna::from_seed`] with real entropy otherwise.
+    pub fn new(seed: u64) -> Self {
+        Self::from_seed(&seed.to_le_bytes())
+    }
+
+    /// Creates a new `Fortuna` instance whose generator is seeded with `seed`.
+    ///
+    /// The seed should contain at least 32 bytes of entropy, for example from a saved seed
+    /// file or the operating system.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let mut fortuna = Self {
+            cipher: Aes256::new(&[0; KEY_LEN]),
+            key: [0; KEY_LEN],
+            counter: 0,
+            pools: core::array::from_fn(|_| Sha256::new()),
+            pool0_len: 0,
+            next_pool: [0; 256],
+            reseed_count: 0,
+            buffer: [0; BUFFER_LEN],
+            index: BUFFER_LEN,
+        };
+        fortuna.reseed_generator(&[seed]);
+        fortuna
+    }
+
+    /// Adds an entropy event from source `source_id` to the next pool for that source.
+    ///
+    /// Events longer than 32 bytes are first compressed with SHA-256. Empty events are
+    /// ignored.
+    pub fn add_entropy(&mut self, source_id: u8, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let digest;
+        let data = if bytes.len() > DIGEST_LEN {
+            let mut hasher = Sha256::new();
+            hasher.update(bytes);
+            digest = hasher.finalize();
+            &digest[..]
+        } else {
+            bytes
+        };
+
+        let pool = self.next_pool[source_id as usize] as usize;
+        self.next_pool[source_id as usize] = ((pool + 1) % POOLS) as u8;
+        self.pools[pool].update(&[source_id, data.len() as u8]);
+        self.pools[pool].update(data);
+        if pool == 0 {
+            self.pool0_len += 2 + data.len();
+        }
+    }
+
+    /// Returns the number of automatic reseeds performed from the pools so far.
+    pub fn reseed_count(&self) -> u64 {
+        self.reseed_count
+    }
+
+    /// Fills `out` with one request, reseeding from the pools first if pool 0 is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::Drbg`] if the request exceeds
+    /// [`Fortuna::MAX_BYTES_PER_REQUEST`].
+    pub fn try_fill_bytes(&mut self, out: &mut [u8]) -> core::result::Result<(), crate::AporiaError> {
+        if out.len() > Self::MAX_BYTES_PER_REQUEST {
+            return Err(crate::AporiaError::Drbg("request exceeds maximum length"));
+        }
+        if self.pool0_len >= Self::MIN_POOL_SIZE {
+            self.reseed_from_pools();
+        }
+
+        for chunk in out.chunks_mut(BLOCK_LEN) {
+            let block = self.next_block();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        // Rekey so that this output cannot be recovered from the new state.
+        let mut key = [0u8; KEY_LEN];
+        for chunk in key.chunks_exact_mut(BLOCK_LEN) {
+            chunk.copy_from_slice(&self.next_block());
+        }
+        self.set_key(key);
+        Ok(())
+    }
+
+    /// Reseeds the generator from every pool `i` with `2^i` dividing the reseed count.
+    fn reseed_from_pools(&mut self) {
+        self.reseed_count += 1;
+        let mut digests = [[0u8; DIGEST_LEN]; POOLS];
+        let mut used = 0;
+        for (i, pool) in self.pools.iter_mut().enumerate() {
+            if i > 0 && !self.reseed_count.is_multiple_of(1u64 << i) {
+                break;
+            }
+            // The pool is the running inner hash of its events, so one more pass gives
+            // SHA-d of the pool contents.
+            digests[i] = finish_sha256d(core::mem::replace(pool, Sha256::new()));
+            used += 1;
+        }
+        self.pool0_len = 0;
+        let mut parts: [&[u8]; POOLS] = [&[]; POOLS];
+        for (part, digest) in parts.iter_mut().zip(&digests[..used]) {
+            *part = digest;
+        }
+        self.reseed_generator(&parts[..used]);
+        self.index = BUFFER_LEN;
+    }
+
+    /// Generator reseed: `K = SHA-256d(K || seed)` and the counter is incremented.
+    fn reseed_generator(&mut self, seed: &[&[u8]]) {
+        let mut inner = Sha256::new();
+        inner.update(&self.key);
+        for part in seed {
+            inner.update(part);
+        }
+        self.set_key(finish_sha256d(inner));
+        self.counter = self.counter.wrapping_add(1);
+    }
+
+    fn set_key(&mut self, key: [u8; KEY_LEN]) {
+        self.key = key;
+        self.cipher = Aes256::new(&key);
+    }
+
+    /// Encrypts the 128-bit little-endian counter and increments it.
+    fn next_block(&mut self) -> [u8; BLOCK_LEN] {
+        let mut block = self.counter.to_le_bytes();
+        self.cipher.encrypt_block(&mut block);
+        self.counter = self.counter.wrapping_add(1);
+        block
+    }
+}
+
+/// Completes SHA-d, `SHA-256(SHA-256(m))`, from the running inner hash of `m`.
+fn finish_sha256d(inner: Sha256) -> [u8; DIGEST_LEN] {
+    let mut outer = Sha256::new();
+    outer.update(&inner.finalize());
+    outer.finalize()
+}
+
+impl RandomBackend for Fortuna {
+    /// Generates the next random `u64` from the buffered request output.
+    fn next_u64(&mut self) -> u64 {
+        if self.index + 8 > BUFFER_LEN {
+            let mut buffer = [0u8; BUFFER_LEN];
+            // Cannot fail: the buffer is far below the request limit.
+            let _ = self.try_fill_bytes(&mut buffer);
+            self.buffer = buffer;
+            self.index = 0;
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.buffer[self.index..self.index + 8]);
+        self.index += 8;
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Fills `buf` with as few requests as the per-request limit allows.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(Self::MAX_BYTES_PER_REQUEST) {
+            let _ = self.try_fill_bytes(chunk);
+        }
+    }
+}
+
+impl SeedableBackend for Fortuna {
+    type Seed = [u8; 32];
+
+    /// Seeds the generator with the 32 seed bytes.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_seed(&seed)
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (3944 bytes): `key` (32 bytes), `counter` (u128), 32 pools of (`h` (8 x u32),
+/// `block` (64 bytes), `block_len` (u64, below 64), `total_len` (u64)), `pool0_len` (u64),
+/// `next_pool` (256 bytes, each below 32), `reseed_count` (u64), `buffer` (32 bytes),
+/// `index` (u64, at most 32).
+impl StateSnapshot for Fortuna {
+    type Bytes = [u8; 3944];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 3944];
+        let mut w = StateWriter::new(&mut out);
+        w.bytes(&self.key);
+        w.u128(self.counter);
+        for pool in &self.pools {
+            pool.write_state(&mut w);
+        }
+        w.index(self.pool0_len);
+        w.bytes(&self.next_pool);
+        w.u64(self.reseed_count);
+        w.bytes(&self.buffer);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 3944)?;
+        let key = r.bytes();
+        let counter = r.u128();
+        let mut pools = core::array::from_fn(|_| Sha256::new());
+        for pool in pools.iter_mut() {
+            *pool = Sha256::read_state(&mut r)?;
+        }
+        let pool0_len = r.index(usize::MAX)?;
+        let next_pool: [u8; 256] = r.bytes();
+        if next_pool.iter().any(|&p| p as usize >= POOLS) {
+            return Err(crate::AporiaError::InvalidState("Fortuna pool index is out of range"));
+        }
+        let reseed_count = r.u64();
+        let buffer = r.bytes();
+        let index = r.index(BUFFER_LEN)?;
+        Ok(Self {
+            cipher: Aes256::new(&key),
+            key,
+            counter,
+            pools,
+            pool0_len,
+            next_pool,
+            reseed_count,
+            buffer,
+            index,
+        })
+    }
+}
+
+impl core::fmt::Debug for Fortuna {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Do not print the key or pool contents.
+        f.debug_struct("Fortuna")
+            .field("reseed_count", &self.reseed_count)
+            .field("pool0_len", &self.pool0_len)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Fortuna {
+    /// Overwrites the generator key, counter, entropy pools and output buffer with zeros.
+    fn zeroize(&mut self) {
+        self.cipher.zeroize();
+        self.key.zeroize();
+        self.counter.zeroize();
+        self.pools.zeroize();
+        self.pool0_len.zeroize();
+        self.next_pool.zeroize();
+        self.reseed_count.zeroize();
+        self.buffer.zeroize();
+        self.index.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Fortuna {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Fortuna {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fortuna_generator_matches_reference() {
+        // Computed with Python's `hashlib` SHA-256 and the `cryptography` package's AES-256
+        // in ECB mode: K = SHA-256(SHA-256(0^32 || seed)), counter 1, each request encrypts
+        // two little-endian counter blocks and the next two become the new key.
+        let mut fortuna = Fortuna::new(42);
+        let mut out = [0u8; 32];
+        fortuna.try_fill_bytes(&mut out).unwrap();
+        assert_eq!(
+            out,
+            [
+                0xa9, 0xce, 0x86, 0x34, 0x90, 0x6e, 0xbc, 0x53, 0xce, 0xb4, 0x0a, 0x68, 0x75, 0xc5, 0x62, 0x00,
+                0x47, 0x5b, 0x81, 0xea, 0xd4, 0x7a, 0xaa, 0x33, 0xc7, 0x4e, 0xb4, 0x06, 0x41, 0x2e, 0xd1, 0x22,
+            ]
+        );
+        fortuna.try_fill_bytes(&mut out).unwrap();
+        assert_eq!(
+            out,
+            [
+                0xab, 0xbd, 0x33, 0xe4, 0xbd, 0x3d, 0xec, 0x4d, 0xbf, 0x05, 0xd3, 0x0a, 0x79, 0xfa, 0xe3, 0x8c,
+                0x3c, 0xf9, 0xda, 0x03, 0x06, 0x50, 0xa2, 0x1e, 0x3e, 0x4c, 0xb2, 0x01, 0xdc, 0xb5, 0x47, 0x0b,
+            ]
+        );
+    }
+
+    #[test]
+    fn fortuna_pool_reseed_matches_reference() {
+        // Computed with Python's `hashlib` and `cryptography`: the new key is
+        // SHA-d(K || SHA-d(pool 0)), where pool 0 holds both events with their headers.
+        let mut fortuna = Fortuna::new(7);
+        let first: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let second: [u8; 30] = core::array::from_fn(|i| 100 + i as u8);
+        fortuna.add_entropy(0, &first);
+        fortuna.add_entropy(1, &second);
+        let mut out = [0u8; 32];
+        fortuna.try_fill_bytes(&mut out).unwrap();
+        assert_eq!(fortuna.reseed_count(), 1);
+        assert_eq!(
+            out,
+            [
+                0xb9, 0x4d, 0xa8, 0xf5, 0xeb, 0xb2, 0xbf, 0xe8, 0xb4, 0xd7, 0xc4, 0x52, 0xf2, 0x2c, 0x1c, 0x98,
+                0xd7, 0x42, 0xee, 0xeb, 0x59, 0xe3, 0xd0, 0x3f, 0x80, 0x5d, 0xc3, 0xfa, 0x61, 0x5c, 0xf4, 0xfa,
+            ]
+        );
+    }
+
+    #[test]
+    fn fortuna_reseeds_from_pools() {
+        let mut fed = Fortuna::new(7);
+        let mut plain = Fortuna::new(7);
+        // Source 3 cycles through all 32 pools; 32 rounds put 32 events in pool 0.
+        for i in 0..32 * 32u16 {
+            fed.add_entropy(3, &i.to_le_bytes());
+        }
+        assert_eq!(fed.reseed_count(), 0);
+        let a = fed.next_u64();
+        assert_eq!(fed.reseed_count(), 1);
+        assert_ne!(a, plain.next_u64());
+
+        // No new entropy in pool 0: further requests do not reseed.
+        let _ = fed.next_u64();
+        let mut buf = [0u8; 100];
+        fed.fill_bytes(&mut buf);
+        assert_eq!(fed.reseed_count(), 1);
+    }
+}
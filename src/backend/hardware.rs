@@ -0,0 +1,214 @@
+//! Hardware random number backends using the x86_64 `RDRAND` and `RDSEED` instructions.
+//!
+//! - [`RdRand`] reads from the CPU's on-chip DRBG, which is continuously reseeded from a
+//!   hardware entropy source. It is fast enough for direct use.
+//! - [`RdSeed`] reads conditioned entropy straight from the hardware source. It is slower
+//!   and may transiently run dry under load; it is intended for seeding other generators.
+//!
+//! Both instructions can fail transiently, so each read is retried a bounded number of
+//! times. Support is checked at runtime with `CPUID` when the backend is constructed; on
+//! CPUs without the instruction, `try_new` returns [`crate::AporiaError::EntropyUnavailable`].
+//!
+//! These backends are only available on `x86_64` with the `rdrand` feature enabled.
+//!
+//! # Characteristics
+//!
+//! - State size: 0 bytes
+//! - Period: Not applicable (non-deterministic)
+//! - Speed: Moderate (`RDRAND`), Slow (`RDSEED`)
+//! - Quality: Cryptographic
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::RdRand};
+//!
+//! if let Ok(backend) = RdRand::try_new() {
+//!     let mut rng = Rng::new(backend);
+//!     let random_number = rng.next_u64();
+//! }
+//! ```
+//!
+//! # References
+//!
+//! - [Intel Digital Random Number Generator (DRNG) Software Implementation Guide](https://www.intel.com/content/www/us/en/developer/articles/guide/intel-digital-random-number-generator-drng-software-implementation-guide.html)
+
+use core::arch::x86_64::{__cpuid, __cpuid_count, _rdrand64_step, _rdseed64_step};
+
+use super::RandomBackend;
+
+/// Number of attempts for a single `RDRAND` read, as recommended by Intel.
+const RDRAND_RETRIES: usize = 10;
+
+/// Number of attempts for a single `RDSEED` read; the entropy source refills slowly.
+const RDSEED_RETRIES: usize = 100;
+
+/// Returns whether the CPU supports `RDRAND` (CPUID leaf 1, ECX bit 30).
+fn has_rdrand() -> bool {
+    // `__cpuid` is safe on recent compilers but `unsafe` on older supported ones.
+    #[allow(unused_unsafe)]
+    let info = unsafe { __cpuid(1) };
+    info.ecx & (1 << 30) != 0
+}
+
+/// Returns whether the CPU supports `RDSEED` (CPUID leaf 7, EBX bit 18).
+fn has_rdseed() -> bool {
+    #[allow(unused_unsafe)]
+    let max_leaf = unsafe { __cpuid(0) }.eax;
+    if max_leaf < 7 {
+        return false;
+    }
+    #[allow(unused_unsafe)]
+    let info = unsafe { __cpuid_count(7, 0) };
+    info.ebx & (1 << 18) != 0
+}
+
+/// Reads one value with `RDRAND`, retrying on transient failure.
+///
+/// # Safety
+///
+/// The CPU must support `RDRAND`.
+#[target_feature(enable = "rdrand")]
+unsafe fn rdrand64() -> Option<u64> {
+    let mut v = 0u64;
+    for _ in 0..RDRAND_RETRIES {
+        if _rdrand64_step(&mut v) == 1 {
+            return Some(v);
+        }
+    }
+    None
+}
+
+/// Reads one value with `RDSEED`, retrying on transient failure.
+///
+/// # Safety
+///
+/// The CPU must support `RDSEED`.
+#[target_feature(enable = "rdseed")]
+unsafe fn rdseed64() -> Option<u64> {
+    let mut v = 0u64;
+    for _ in 0..RDSEED_RETRIES {
+        if _rdseed64_step(&mut v) == 1 {
+            return Some(v);
+        }
+        core::hint::spin_loop();
+    }
+    None
+}
+
+/// Backend that reads from the CPU's `RDRAND` instruction.
+///
+/// A value of this type can only be obtained on CPUs that support `RDRAND`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RdRand {
+    _private: (),
+}
+
+impl RdRand {
+    /// Creates a new `RdRand` backend after checking CPU support.
+    ///
+    /// Returns an error if the CPU does not support `RDRAND`.
+    pub fn try_new() -> core::result::Result<Self, crate::AporiaError> {
+        if has_rdrand() {
+            Ok(Self { _private: () })
+        } else {
+            Err(crate::AporiaError::EntropyUnavailable("RDRAND is not supported by this CPU"))
+        }
+    }
+
+    /// Returns a random `u64`, or an error if `RDRAND` kept failing after retries.
+    pub fn try_next_u64(&mut self) -> core::result::Result<u64, crate::AporiaError> {
+        // Safety: support was checked in `try_new`.
+        unsafe { rdrand64() }
+            .ok_or(crate::AporiaError::EntropyUnavailable("RDRAND failed after retries"))
+    }
+}
+
+impl RandomBackend for RdRand {
+    /// Reads the next random `u64` with `RDRAND`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `RDRAND` keeps failing after retries, which indicates a hardware fault;
+    /// use [`RdRand::try_next_u64`] to handle the error instead.
+    fn next_u64(&mut self) -> u64 {
+        match self.try_next_u64() {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Backend that reads from the CPU's `RDSEED` instruction.
+///
+/// A value of this type can only be obtained on CPUs that support `RDSEED`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RdSeed {
+    _private: (),
+}
+
+impl RdSeed {
+    /// Creates a new `RdSeed` backend after checking CPU support.
+    ///
+    /// Returns an error if the CPU does not support `RDSEED`.
+    pub fn try_new() -> core::result::Result<Self, crate::AporiaError> {
+        if has_rdseed() {
+            Ok(Self { _private: () })
+        } else {
+            Err(crate::AporiaError::EntropyUnavailable("RDSEED is not supported by this CPU"))
+        }
+    }
+
+    /// Returns a random `u64`, or an error if the entropy source stayed exhausted.
+    pub fn try_next_u64(&mut self) -> core::result::Result<u64, crate::AporiaError> {
+        // Safety: support was checked in `try_new`.
+        unsafe { rdseed64() }
+            .ok_or(crate::AporiaError::EntropyUnavailable("RDSEED failed after retries"))
+    }
+}
+
+impl RandomBackend for RdSeed {
+    /// Reads the next random `u64` with `RDSEED`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `RDSEED` stays exhausted after retries; use [`RdSeed::try_next_u64`]
+    /// to handle the error instead.
+    fn next_u64(&mut self) -> u64 {
+        match self.try_next_u64() {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rdrand_matches_cpuid_and_produces_output() {
+        match RdRand::try_new() {
+            Ok(mut hw) => {
+                let a = hw.try_next_u64().unwrap();
+                let b = hw.next_u64();
+                let c = hw.next_u64();
+                assert!(a != b || b != c);
+            }
+            Err(e) => assert!(!has_rdrand(), "unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn rdseed_matches_cpuid_and_produces_output() {
+        match RdSeed::try_new() {
+            Ok(mut hw) => {
+                let a = hw.try_next_u64().unwrap();
+                let b = hw.try_next_u64().unwrap();
+                let c = hw.try_next_u64().unwrap();
+                assert!(a != b || b != c);
+            }
+            Err(e) => assert!(!has_rdseed(), "unexpected error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! HMAC-DRBG deterministic random bit generator (NIST SP 800-90A).
+//!
+//! This is the HMAC_DRBG mechanism from SP 800-90A Rev. 1 instantiated with HMAC-SHA256,
+//! without prediction resistance or derivation function. It exposes the standard
+//! instantiate, reseed and generate functions so output can be checked against NIST CAVP
+//! test vectors, and it implements [`RandomBackend`] for use with [`crate::Rng`].
+//!
+//! The SP 800-90A limits are enforced by [`HmacDrbg::generate`]: a single request may
+//! return at most [`HmacDrbg::MAX_BYTES_PER_REQUEST`] bytes, and after
+//! [`HmacDrbg::RESEED_INTERVAL`] requests the generator must be reseeded.
+//!
+//! Through [`RandomBackend`], `next_u64` draws from a 32-byte buffer that is refilled with
+//! one generate request at a time, and `fill_bytes` issues generate requests directly.
+//!
+//! # Characteristics
+//!
+//! - State size: 104 bytes (32-byte key, 32-byte value, 32-byte output buffer, counters)
+//! - Period: Not applicable (reseeded before 2<sup>48</sup> requests)
+//! - Speed: Slow
+//! - Quality: Cryptographic (256-bit security strength with adequate entropy input)
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::HmacDrbg};
+//!
+//! let entropy = [0x42u8; 32];
+//! let nonce = [0x24u8; 16];
+//! let mut drbg = HmacDrbg::instantiate(&entropy, &nonce, b"my application");
+//!
+//! let mut out = [0u8; 64];
+//! drbg.generate(&mut out, &[]).unwrap();
+//!
+//! let mut rng = Rng::new(drbg);
+//! let random_number = rng.next_u64();
+//! ```
+//!
+//! # References
+//!
+//! - [NIST SP 800-90A Rev. 1](https://csrc.nist.gov/publications/detail/sp/800-90a/rev-1/final)
+//! - [NIST CAVP DRBG test vectors](https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/random-number-generators)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot};
+use super::sha256::{DIGEST_LEN, hmac_sha256};
+
+/// HMAC-DRBG (HMAC-SHA256) random number generator struct.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HmacDrbg {
+    k: [u8; DIGEST_LEN],
+    v: [u8; DIGEST_LEN],
+    reseed_counter: u64,
+    buffer: [u8; DIGEST_LEN],
+    index: usize,
+}
+
+impl HmacDrbg {
+    /// Maximum number of bytes a single generate request may return (2<sup>19</sup> bits).
+    pub const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+
+    /// Number of generate requests allowed between reseeds.
+    pub const RESEED_INTERVAL: u64 = 1 << 48;
+
+    /// Creates a new `HmacDrbg` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value, used as the (little-endian) entropy input.
+    ///
+    /// A 64-bit seed is far below the 256-bit security strength of the mechanism; this
+    /// constructor is meant for reproducible non-security use. Use
+    /// [`HmacDrbg::instantiate`] with real entropy otherwise.
+    pub fn new(seed: u64) -> Self {
+        Self::instantiate(&seed.to_le_bytes(), &[], &[])
+    }
+
+    /// Instantiates the DRBG (SP 800-90A `HMAC_DRBG_Instantiate_algorithm`).
+    ///
+    /// # Arguments
+    ///
+    /// * `entropy` - The entropy input; should contain at least 32 bytes of entropy.
+    /// * `nonce` - The nonce; typically at least 16 bytes.
+    /// * `personalization` - Optional personalization string (may be empty).
+    pub fn instantiate(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        let mut drbg = Self {
+            k: [0x00; DIGEST_LEN],
+            v: [0x01; DIGEST_LEN],
+            reseed_counter: 1,
+            buffer: [0; DIGEST_LEN],
+            index: DIGEST_LEN,
+        };
+        drbg.update(&[entropy, nonce, personalization]);
+        drbg
+    }
+
+    /// Reseeds the DRBG with fresh entropy (SP 800-90A `HMAC_DRBG_Reseed_algorithm`).
+    ///
+    /// Any buffered output for `next_u64` is discarded.
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) {
+        self.update(&[entropy, additional_input]);
+        self.reseed_counter = 1;
+        self.index = DIGEST_LEN;
+    }
+
+    /// Fills `out` with one generate request (SP 800-90A `HMAC_DRBG_Generate_algorithm`).
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The output buffer; at most [`HmacDrbg::MAX_BYTES_PER_REQUEST`] bytes.
+    /// * `additional_input` - Optional additional input (may be empty).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::Drbg`] if the request is too large or if the DRBG must
+    /// be reseeded first.
+    pub fn generate(
+        &mut self,
+        out: &mut [u8],
+        additional_input: &[u8],
+    ) -> core::result::Result<(), crate::AporiaError> {
+        if out.len() > Self::MAX_BYTES_PER_REQUEST {
+            return Err(crate::AporiaError::Drbg("request exceeds maximum length"));
+        }
+        if self.reseed_counter > Self::RESEED_INTERVAL {
+            return Err(crate::AporiaError::Drbg("reseed required"));
+        }
+
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in out.chunks_mut(DIGEST_LEN) {
+            self.v = hmac_sha256(&self.k, &[&self.v]);
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// Returns the number of generate requests since the last (re)seed, plus one.
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// The `HMAC_DRBG_Update` function; `provided` is treated as one concatenated string.
+    fn update(&mut self, provided: &[&[u8]]) {
+        let empty = provided.iter().all(|p| p.is_empty());
+        for round in [0x00u8, 0x01] {
+            if round == 0x01 && empty {
+                break;
+            }
+            let mut parts: [&[u8]; 5] = [&self.v, &[round], &[], &[], &[]];
+            for (slot, p) in parts[2..].iter_mut().zip(provided) {
+                *slot = p;
+            }
+            self.k = hmac_sha256(&self.k, &parts);
+            self.v = hmac_sha256(&self.k, &[&self.v]);
+        }
+    }
+}
+
+impl RandomBackend for HmacDrbg {
+    /// Generates the next random `u64` from the buffered generate output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the DRBG requires a reseed.
+    fn next_u64(&mut self) -> u64 {
+        if self.index + 8 > DIGEST_LEN {
+            let mut buffer = [0u8; DIGEST_LEN];
+            if let Err(e) = self.generate(&mut buffer, &[]) {
+                panic!("{}", e);
+            }
+            self.buffer = buffer;
+            self.index = 0;
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.buffer[self.index..self.index + 8]);
+        self.index += 8;
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Fills `buf` with as few generate requests as the per-request limit allows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the DRBG requires a reseed.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(Self::MAX_BYTES_PER_REQUEST) {
+            if let Err(e) = self.generate(chunk, &[]) {
+                panic!("{}", e);
+            }
+        }
+    }
+}
+
+impl SeedableBackend for HmacDrbg {
+    type Seed = [u8; 48];
+
+    /// Instantiates with the first 32 bytes as entropy input and the last 16 as nonce.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::instantiate(&seed[..32], &seed[32..], &[])
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (112 bytes): `k` (32 bytes), `v` (32 bytes), `reseed_counter` (u64), `buffer`
+/// (32 bytes), `index` (u64, at most 32).
+impl StateSnapshot for HmacDrbg {
+    type Bytes = [u8; 112];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 112];
+        let mut w = StateWriter::new(&mut out);
+        w.bytes(&self.k);
+        w.bytes(&self.v);
+        w.u64(self.reseed_counter);
+        w.bytes(&self.buffer);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 112)?;
+        let k = r.bytes();
+        let v = r.bytes();
+        let reseed_counter = r.u64();
+        let buffer = r.bytes();
+        let index = r.index(DIGEST_LEN)?;
+        Ok(Self { k, v, reseed_counter, buffer, index })
+    }
+}
+
+impl core::fmt::Debug for HmacDrbg {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Do not print the key or value.
+        f.debug_struct("HmacDrbg")
+            .field("reseed_counter", &self.reseed_counter)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for HmacDrbg {
+    /// Overwrites `K`, `V` and the output buffer with zeros.
+    fn zeroize(&mut self) {
+        self.k.zeroize();
+        self.v.zeroize();
+        self.reseed_counter.zeroize();
+        self.buffer.zeroize();
+        self.index.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for HmacDrbg {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for HmacDrbg {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> [u8; 128] {
+        let mut out = [0u8; 128];
+        for (i, b) in out.iter_mut().enumerate().take(s.len() / 2) {
+            *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    #[test]
+    fn hmac_drbg_cavp_sha256_no_reseed() {
+        // CAVP HMAC_DRBG.rsp, [SHA-256], PredictionResistance = False, COUNT = 0.
+        let entropy = hex("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488");
+        let nonce = hex("659ba96c601dc69fc902940805ec0ca8");
+        let mut drbg = HmacDrbg::instantiate(&entropy[..32], &nonce[..16], &[]);
+        let mut out = [0u8; 128];
+        drbg.generate(&mut out, &[]).unwrap();
+        drbg.generate(&mut out, &[]).unwrap();
+        let expected = hex(concat!(
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89",
+            "d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1",
+            "07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668",
+            "961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8",
+        ));
+        assert_eq!(out, expected);
+        assert_eq!(drbg.reseed_counter(), 3);
+    }
+
+    #[test]
+    fn hmac_drbg_reseed_and_additional_input() {
+        // Expected bytes from a direct transcription of the SP 800-90A algorithms.
+        let entropy: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let nonce: [u8; 16] = core::array::from_fn(|i| 32 + i as u8);
+        let reseed: [u8; 32] = core::array::from_fn(|i| 0x80 + i as u8);
+        let mut drbg = HmacDrbg::instantiate(&entropy, &nonce, b"aporia");
+        drbg.reseed(&reseed, b"extra");
+        let mut out = [0u8; 32];
+        drbg.generate(&mut out, b"add").unwrap();
+        let expected = hex("c8c030d1a4dc51a3b87aefafe161a0b6f8ae670450a6f960f3cb7d11958d0598");
+        assert_eq!(out, expected[..32]);
+    }
+
+    #[test]
+    fn hmac_drbg_rejects_oversized_request() {
+        let mut drbg = HmacDrbg::new(1);
+        let mut out = [0u8; HmacDrbg::MAX_BYTES_PER_REQUEST + 1];
+        assert!(drbg.generate(&mut out, &[]).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+//! ISAAC64 random number generator implementation.
+//!
+//! ISAAC64 is the 64-bit version of Bob Jenkins' ISAAC ("Indirection, Shift, Accumulate,
+//! Add, and Count"). It was designed with cryptographic use in mind and has no known
+//! practical attacks, but it has received far less scrutiny than modern stream ciphers, so
+//! prefer [`crate::backend::ChaCha20`] for security-sensitive work. It is fast and produces
+//! output in batches of 256 words.
+//!
+//! Seeding follows the reference `randinit(TRUE)` routine, and outputs are returned in the
+//! same order as the reference `rand()` macro (from the end of each result batch).
+//!
+//! # Characteristics
+//!
+//! - State size: 4 KB (256-word memory plus 256-word result batch)
+//! - Period: at least 2<sup>72</sup>, expected 2<sup>16583</sup>
+//! - Speed: Fast
+//! - Quality: High
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Isaac64};
+//!
+//! let backend = Isaac64::new(8675309);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//! ```
+//!
+//! # References
+//!
+//! - [Bob Jenkins, "ISAAC and RC4"](https://burtleburtle.net/bob/rand/isaac.html)
+//! - [Wikipedia: ISAAC (cipher)](https://en.wikipedia.org/wiki/ISAAC_(cipher))
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot, words_from_le_bytes};
+
+/// Number of words in the memory and result arrays.
+const RANDSIZ: usize = 256;
+
+/// ISAAC64 random number generator struct.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isaac64 {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    mm: [u64; RANDSIZ],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    rsl: [u64; RANDSIZ],
+    a: u64,
+    b: u64,
+    c: u64,
+    cnt: usize,
+}
+
+impl Isaac64 {
+    /// Creates a new `Isaac64` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is placed in the first seed word (all others zero) and the state is
+    /// initialized with the reference seeding routine.
+    pub fn new(seed: u64) -> Self {
+        Self::from_seed_words(&[seed])
+    }
+
+    /// Creates a new `Isaac64` instance from up to 256 seed words.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed words; words beyond the 256th are ignored and missing words
+    ///   are treated as zero.
+    ///
+    /// This is equivalent to filling `randrsl[]` and calling `randinit(TRUE)` in the
+    /// reference implementation.
+    pub fn from_seed_words(seed: &[u64]) -> Self {
+        let mut rng = Self {
+            mm: [0; RANDSIZ],
+            rsl: [0; RANDSIZ],
+            a: 0,
+            b: 0,
+            c: 0,
+            cnt: 0,
+        };
+        for (r, s) in rng.rsl.iter_mut().zip(seed.iter()) {
+            *r = *s;
+        }
+        rng.init();
+        rng
+    }
+
+    /// The reference `randinit(TRUE)` seeding routine.
+    fn init(&mut self) {
+        let mut x = [0x9e37_79b9_7f4a_7c13u64; 8];
+        for _ in 0..4 {
+            mix(&mut x);
+        }
+
+        for i in (0..RANDSIZ).step_by(8) {
+            for (xj, r) in x.iter_mut().zip(&self.rsl[i..i + 8]) {
+                *xj = xj.wrapping_add(*r);
+            }
+            mix(&mut x);
+            self.mm[i..i + 8].copy_from_slice(&x);
+        }
+        for i in (0..RANDSIZ).step_by(8) {
+            for (xj, m) in x.iter_mut().zip(&self.mm[i..i + 8]) {
+                *xj = xj.wrapping_add(*m);
+            }
+            mix(&mut x);
+            self.mm[i..i + 8].copy_from_slice(&x);
+        }
+
+        self.isaac64();
+    }
+
+    /// Generates the next batch of 256 results.
+    fn isaac64(&mut self) {
+        self.c = self.c.wrapping_add(1);
+        let mut a = self.a;
+        let mut b = self.b.wrapping_add(self.c);
+
+        const HALF: usize = RANDSIZ / 2;
+        for i in 0..RANDSIZ {
+            let mix = match i % 4 {
+                0 => !(a ^ (a << 21)),
+                1 => a ^ (a >> 5),
+                2 => a ^ (a << 12),
+                _ => a ^ (a >> 33),
+            };
+            let x = self.mm[i];
+            a = mix.wrapping_add(self.mm[(i + HALF) % RANDSIZ]);
+            let y = self.ind(x).wrapping_add(a).wrapping_add(b);
+            self.mm[i] = y;
+            b = self.ind(y >> 8).wrapping_add(x);
+            self.rsl[i] = b;
+        }
+
+        self.a = a;
+        self.b = b;
+        self.cnt = RANDSIZ;
+    }
+
+    /// Looks up the memory word selected by bits 3..11 of `x`.
+    #[inline(always)]
+    fn ind(&self, x: u64) -> u64 {
+        self.mm[((x >> 3) as usize) & (RANDSIZ - 1)]
+    }
+}
+
+/// The ISAAC64 seeding mix function.
+#[inline(always)]
+fn mix(x: &mut [u64; 8]) {
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *x;
+    a = a.wrapping_sub(e);
+    f ^= h >> 9;
+    h = h.wrapping_add(a);
+    b = b.wrapping_sub(f);
+    g ^= a << 9;
+    a = a.wrapping_add(b);
+    c = c.wrapping_sub(g);
+    h ^= b >> 23;
+    b = b.wrapping_add(c);
+    d = d.wrapping_sub(h);
+    a ^= c << 15;
+    c = c.wrapping_add(d);
+    e = e.wrapping_sub(a);
+    b ^= d >> 14;
+    d = d.wrapping_add(e);
+    f = f.wrapping_sub(b);
+    c ^= e << 20;
+    e = e.wrapping_add(f);
+    g = g.wrapping_sub(c);
+    d ^= f >> 17;
+    f = f.wrapping_add(g);
+    h = h.wrapping_sub(d);
+    e ^= g << 14;
+    g = g.wrapping_add(h);
+    *x = [a, b, c, d, e, f, g, h];
+}
+
+impl RandomBackend for Isaac64 {
+    /// Generates the next random `u64`, consuming the current batch from the end.
+    fn next_u64(&mut self) -> u64 {
+        if self.cnt == 0 {
+            self.isaac64();
+        }
+        self.cnt -= 1;
+        self.rsl[self.cnt]
+    }
+}
+
+impl SeedableBackend for Isaac64 {
+    type Seed = [u8; 32];
+
+    /// Uses the seed bytes as the first four (little-endian) seed words.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_seed_words(&words_from_le_bytes::<4>(&seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (4128 bytes): `mm` (256 x u64), `rsl` (256 x u64), `a` (u64), `b` (u64), `c` (u64),
+/// `cnt` (u64, at most 256).
+impl StateSnapshot for Isaac64 {
+    type Bytes = [u8; 4128];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 4128];
+        let mut w = StateWriter::new(&mut out);
+        w.u64s(&self.mm);
+        w.u64s(&self.rsl);
+        w.u64(self.a);
+        w.u64(self.b);
+        w.u64(self.c);
+        w.index(self.cnt);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 4128)?;
+        let mm = r.u64s();
+        let rsl = r.u64s();
+        let a = r.u64();
+        let b = r.u64();
+        let c = r.u64();
+        let cnt = r.index(RANDSIZ)?;
+        Ok(Self { mm, rsl, a, b, c, cnt })
+    }
+}
+
+impl core::fmt::Debug for Isaac64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Avoid dumping large internal state; show only minimal info.
+        f.debug_struct("Isaac64").field("cnt", &self.cnt).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isaac64_zero_seed_golden_values() {
+        // Reference test program: randinit(TRUE) on a zero seed, then one more isaac64().
+        let mut isaac = Isaac64::from_seed_words(&[]);
+        isaac.isaac64();
+        assert_eq!(
+            isaac.rsl[..4],
+            [
+                0x12a8f216af9418c2,
+                0xd4490ad526f14431,
+                0xb49c3b3995091a36,
+                0x5b45e522e4b1b4ef
+            ]
+        );
+    }
+
+    #[test]
+    fn isaac64_seeded_outputs_match_reference_rand() {
+        // Reference rand() macro after randrsl = {1, 2, 3, 4} and randinit(TRUE).
+        let mut isaac = Isaac64::from_seed_words(&[1, 2, 3, 4]);
+        let expected = [
+            17575370567115077426,
+            2968485918251710669,
+            12597542537441236847,
+            16046776110141225594,
+        ];
+        for e in expected {
+            assert_eq!(isaac.next_u64(), e);
+        }
+    }
+}
@@ -25,10 +25,52 @@
 //! - [Wikipedia: Linear congruential generator](https://en.wikipedia.org/wiki/Linear_congruential_generator)
 //! - Donald Knuth, *The Art of Computer Programming*, Vol. 2
 
-use super::RandomBackend;
+use super::snapshot::{StateReader, StateWriter};
+use super::{AtomicBackend, RandomBackend, Reversible, SeedableBackend, Skippable, StateSnapshot};
+
+// Parameters from MMIX by Donald Knuth
+const MULTIPLIER: u64 = 6364136223846793005;
+const INCREMENT: u64 = 1442695040888963407;
+
+/// Returns the state of the LCG `x -> x * multiplier + increment` (mod 2<sup>64</sup>) after
+/// `delta` steps from `state`, in O(log delta) time.
+///
+/// This is Brown's algorithm ("Random Number Generation with Arbitrary Strides", 1994),
+/// which composes the affine transition with itself by repeated squaring.
+pub(crate) fn lcg64_advance(state: u64, multiplier: u64, increment: u64, mut delta: u64) -> u64 {
+    let mut cur_mult = multiplier;
+    let mut cur_plus = increment;
+    let mut acc_mult = 1u64;
+    let mut acc_plus = 0u64;
+    while delta > 0 {
+        if delta & 1 == 1 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+        }
+        cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta >>= 1;
+    }
+    acc_mult.wrapping_mul(state).wrapping_add(acc_plus)
+}
+
+/// Returns the multiplicative inverse of the odd number `a` modulo 2<sup>64</sup>.
+///
+/// Each Newton iteration doubles the number of correct low bits, starting from the three
+/// bits that `a` itself gets right.
+pub(crate) const fn mul_inverse_u64(a: u64) -> u64 {
+    let mut x = a;
+    let mut i = 0;
+    while i < 5 {
+        x = x.wrapping_mul(2u64.wrapping_sub(a.wrapping_mul(x)));
+        i += 1;
+    }
+    x
+}
 
 /// Linear Congruential Generator (LCG) struct.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LCG {
     state: u64,
 }
@@ -39,7 +81,7 @@ impl LCG {
     /// # Arguments
     ///
     /// * `seed` - The initial seed value.
-    pub fn new(seed: u64) -> Self {
+    pub const fn new(seed: u64) -> Self {
         Self { state: seed }
     }
 }
@@ -47,15 +89,69 @@ impl LCG {
 impl RandomBackend for LCG {
     /// Generates the next random `u64` using the LCG algorithm.
     fn next_u64(&mut self) -> u64 {
-        // Parameters from MMIX by Donald Knuth
-        const MULTIPLIER: u64 = 6364136223846793005;
-        const INCREMENT: u64 = 1442695040888963407;
-
         self.state = self.state.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT);
         self.state
     }
 }
 
+impl SeedableBackend for LCG {
+    type Seed = [u8; 8];
+
+    /// Uses the seed bytes (little-endian) as the initial state.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl Skippable for LCG {
+    fn discard(&mut self, n: u128) {
+        // The period is 2^64, so only the low 64 bits of `n` matter.
+        self.state = lcg64_advance(self.state, MULTIPLIER, INCREMENT, n as u64);
+    }
+}
+
+impl Reversible for LCG {
+    fn prev_u64(&mut self) -> u64 {
+        let current = self.state;
+        self.state = current
+            .wrapping_sub(INCREMENT)
+            .wrapping_mul(mul_inverse_u64(MULTIPLIER));
+        current
+    }
+}
+
+impl AtomicBackend for LCG {
+    fn state_word(&self) -> u64 {
+        self.state
+    }
+
+    fn set_state_word(&mut self, word: u64) {
+        self.state = word;
+    }
+}
+
+/// Layout (8 bytes): `state` (u64).
+impl StateSnapshot for LCG {
+    type Bytes = [u8; 8];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 8];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.state);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 8)?;
+        let state = r.u64();
+        Ok(Self { state })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,4 +163,31 @@ mod tests {
         let b = lcg.next_u64();
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn lcg_discard_matches_sequential_draws() {
+        let mut skipped = LCG::new(2024);
+        let mut stepped = skipped.clone();
+        skipped.discard(1000);
+        for _ in 0..1000 {
+            let _ = stepped.next_u64();
+        }
+        assert_eq!(skipped.next_u64(), stepped.next_u64());
+
+        // A full period brings the generator back to where it started.
+        let before = skipped.clone();
+        skipped.discard(1u128 << 64);
+        assert_eq!(skipped.state, before.state);
+    }
+
+    #[test]
+    fn lcg_prev_undoes_next() {
+        assert_eq!(MULTIPLIER.wrapping_mul(mul_inverse_u64(MULTIPLIER)), 1);
+        let mut lcg = LCG::new(99);
+        let a = lcg.next_u64();
+        let b = lcg.next_u64();
+        assert_eq!(lcg.prev_u64(), b);
+        assert_eq!(lcg.prev_u64(), a);
+        assert_eq!(lcg.state, 99);
+    }
 }
@@ -1,13 +1,20 @@
 //! Random number generator backend implementations.
 //!
 //! This module defines the core RNG backend trait [`RandomBackend`] and re-exports
-//! a set of concrete algorithms. Each backend is small, dependency-free, and focuses
-//! on clarity and correctness. These are not cryptographic RNGs.
+//! a set of concrete algorithms. Each backend is small, dependency-free (except the optional
+//! OS entropy backend), and focuses on clarity and correctness. Apart from [`ChaCha20`],
+//! the DRBGs, [`Fortuna`] and the OS and hardware entropy backends, these are not cryptographic RNGs.
 //!
 //! Highlights:
 //! - Minimal trait surface with sensible defaults: `next_u64`, `next_u32`, `next_f64`, `fill_bytes`
 //! - Multiple algorithms with different trade-offs (speed, state size, quality)
 //! - Designed to be used through the high-level [`crate::Rng`] wrapper
+//! - [`AnyBackend`] enum for choosing an algorithm by name at runtime
+//! - [`BlockRng`] adapter that buffers whole blocks from [`BlockBackend`] implementations
+//! - [`AtomicRng`] wrapper that draws through `&self`, for sharing small-state backends
+//! - [`ReseedingRng`] adapter that reseeds a backend from an entropy source every N bytes
+//! - `const fn` constructors for [`XorShift`], [`LCG`], [`SplitMix64`] and [`PCG`], so these
+//!   backends can initialize a `static`
 //!
 //! This module provides various random number generator (RNG) implementations with different
 //! characteristics and trade-offs. Each backend implements the [`RandomBackend`] trait,
@@ -17,10 +24,31 @@
 //!
 //! - [`LCG`]: Linear Congruential Generator - Simple and fast, but with known limitations
 //! - [`PCG`]: Permuted Congruential Generator - High-quality output with good statistical properties
+//! - [`Pcg64Dxsm`]: 128-bit-state PCG with the DXSM output function, NumPy's default generator
+//! - [`Pcg32`], [`Pcg32Fast`]: Reference PCG32 variants with native 32-bit output
 //! - [`XorShift`]: Simple and fast algorithm with reasonable quality
+//! - [`XorShift128Plus`]: The generator used by JavaScript engines for `Math.random()`
+//! - [`XorWow`]: cuRAND's default generator, for cross-checking GPU results on the CPU
 //! - [`MT19937_64`]: 64-bit Mersenne Twister - Large state, very long period (2^19937-1)
+//! - [`MT19937`]: 32-bit Mersenne Twister, compatible with C++ `std::mt19937`
+//! - [`Well19937a`]: Better-equidistributed alternative to the Mersenne Twister with the same period
 //! - [`SplitMix64`]: Fast, simple generator suitable for initialization
 //! - [`Xoshiro256StarStar`]: Modern, high-quality generator with excellent statistical properties
+//! - [`Xoshiro256PlusPlus`]: Xoshiro256 with the `++` scrambler, the recommended general-purpose variant
+//! - [`Xoshiro256Plus`]: Fastest xoshiro256 variant, intended for floating-point output
+//! - [`Xoshiro512StarStar`]: Large-state xoshiro with jump functions for massive parallel ensembles
+//! - [`RomuTrio`], [`RomuDuoJr`]: Nonlinear generators favoring raw throughput over a guaranteed period
+//! - [`Cmwc`]: Marsaglia's complementary multiply-with-carry with a 4096-word lag table
+//! - [`Isaac64`]: Bob Jenkins' ISAAC64, a fast generator with cryptographic heritage
+//! - [`ChaCha20`]: Stream-cipher based generator suitable for cryptographic use
+//! - [`ChaCha12`], [`ChaCha8`]: Reduced-round ChaCha variants trading security margin for speed
+//! - [`Philox`]: Counter-based generator with random access, suited to parallel simulations
+//! - [`Squares`]: Lightweight counter-based generator with random access and a 64-bit key
+//! - [`HmacDrbg`]: NIST SP 800-90A HMAC_DRBG with instantiate/reseed/generate semantics
+//! - [`CtrDrbg`]: NIST SP 800-90A CTR_DRBG using AES-256 and the derivation function
+//! - [`Fortuna`]: Entropy accumulator with 32 pools that keeps reseeding an AES generator
+//! - `OsRandom`: Non-deterministic values from the operating system CSPRNG (`getrandom` feature)
+//! - `RdRand`, `RdSeed`: x86_64 hardware random instructions (`rdrand` feature)
 //!
 //! # Choosing a Backend
 //!
@@ -30,10 +58,34 @@
 //! |---------|------------|-------|---------|---------|
 //! | LCG | 8 bytes | Very Fast | Basic | 2^64 |
 //! | PCG | 16 bytes | Fast | High | 2^64 |
+//! | PCG64-DXSM | 32 bytes | Fast | Excellent | 2^128 |
+//! | Pcg32 | 16 bytes | Fast | High | 2^64 |
+//! | Pcg32Fast | 8 bytes | Very Fast | High | 2^62 |
 //! | XorShift | 8 bytes | Very Fast | Good | 2^64 - 1 |
+//! | XorShift128+ | 16 bytes | Very Fast | Good | 2^128 - 1 |
+//! | XORWOW | 24 bytes | Very Fast | Basic | 2^192 - 2^32 |
 //! | MT19937_64 | 2.5KB | Moderate | High | 2^19937 - 1 |
+//! | MT19937 | 2.5KB | Moderate | High | 2^19937 - 1 |
+//! | WELL19937a | 2.5KB | Fast | High | 2^19937 - 1 |
 //! | SplitMix64 | 8 bytes | Very Fast | Good | 2^64 |
 //! | Xoshiro256** | 32 bytes | Very Fast | Excellent | 2^256 - 1 |
+//! | Xoshiro256++ | 32 bytes | Very Fast | Excellent | 2^256 - 1 |
+//! | Xoshiro256+ | 32 bytes | Very Fast | Excellent (floats) | 2^256 - 1 |
+//! | Xoshiro512** | 64 bytes | Very Fast | Excellent | 2^512 - 1 |
+//! | RomuTrio | 24 bytes | Very Fast | Good | Probabilistic |
+//! | RomuDuoJr | 16 bytes | Very Fast | Good | Probabilistic |
+//! | CMWC4096 | 16KB | Very Fast | Good | ~2^131104 |
+//! | ISAAC64 | 4KB | Fast | High | ≥ 2^72 |
+//! | ChaCha20 | 136 bytes | Moderate | Cryptographic | 2^70 bytes per stream |
+//! | ChaCha8 | 136 bytes | Fast | Excellent | 2^70 bytes per stream |
+//! | Philox4x64 | 88 bytes | Fast | Excellent | 2^258 per key |
+//! | Squares | 16 bytes | Very Fast | Excellent | 2^64 per key |
+//! | HMAC-DRBG | 104 bytes | Slow | Cryptographic | reseed every 2^48 requests |
+//! | CTR-DRBG | ~300 bytes | Moderate | Cryptographic | reseed every 2^48 requests |
+//! | Fortuna | ~4.2KB | Moderate | Cryptographic | continuously reseeded |
+//! | OsRandom | 0 bytes | Slow | Cryptographic | N/A |
+//! | RdRand | 0 bytes | Moderate | Cryptographic | N/A |
+//! | RdSeed | 0 bytes | Slow | Cryptographic | N/A |
 //!
 //! # Examples
 //!
@@ -50,19 +102,79 @@
 //! ```
 
 // Re-export all backends
+pub use self::any::{Algorithm, AnyBackend};
+pub use self::block::BlockRng;
+#[cfg(target_has_atomic = "64")]
+pub use self::atomic::AtomicRng;
+pub use self::reseeding::ReseedingRng;
+pub use self::counting::CountingBackend;
 pub use self::lcg::LCG;
 pub use self::pcg::PCG;
+pub use self::pcg64dxsm::Pcg64Dxsm;
+pub use self::pcg32::{Pcg32, Pcg32Fast};
 pub use self::xorshift::XorShift;
+pub use self::xorshift128plus::XorShift128Plus;
+pub use self::xorwow::XorWow;
 pub use self::mt19937_64::MT19937_64;
+pub use self::mt19937::MT19937;
+pub use self::well19937a::Well19937a;
 pub use self::splitmix64::SplitMix64;
+pub(crate) use self::splitmix64::mix64;
 pub use self::xoshiro256starstar::Xoshiro256StarStar;
+pub use self::xoshiro256plusplus::Xoshiro256PlusPlus;
+pub use self::xoshiro256plus::Xoshiro256Plus;
+pub use self::xoshiro512starstar::Xoshiro512StarStar;
+pub use self::romu::{RomuDuoJr, RomuTrio};
+pub use self::cmwc::Cmwc;
+pub use self::isaac64::Isaac64;
+pub use self::chacha::{ChaCha, ChaCha8, ChaCha12, ChaCha20};
+pub use self::philox::Philox;
+pub use self::squares::Squares;
+pub use self::hmac_drbg::HmacDrbg;
+pub use self::ctr_drbg::CtrDrbg;
+pub use self::fortuna::Fortuna;
+#[cfg(feature = "getrandom")]
+pub use self::os::OsRandom;
+#[cfg(all(feature = "rdrand", target_arch = "x86_64"))]
+pub use self::hardware::{RdRand, RdSeed};
 
+mod any;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+mod block;
+mod reseeding;
+mod counting;
 mod lcg;
 mod pcg;
+mod pcg64dxsm;
+mod pcg32;
 mod xorshift;
+mod xorshift128plus;
+mod xorwow;
 mod mt19937_64;
+mod mt19937;
+mod well19937a;
 mod splitmix64;
 mod xoshiro256starstar;
+mod xoshiro256plusplus;
+mod xoshiro256plus;
+mod xoshiro512starstar;
+mod romu;
+mod cmwc;
+mod isaac64;
+mod chacha;
+mod philox;
+mod squares;
+mod sha256;
+mod snapshot;
+mod hmac_drbg;
+mod aes;
+mod ctr_drbg;
+mod fortuna;
+#[cfg(feature = "getrandom")]
+mod os;
+#[cfg(all(feature = "rdrand", target_arch = "x86_64"))]
+mod hardware;
 
 /// Trait that defines the interface for random number generator backends.
 ///
@@ -121,6 +233,29 @@ pub trait RandomBackend {
         (self.next_u64() >> 32) as u32
     }
 
+    /// Generates the next 128-bit unsigned integer.
+    ///
+    /// Default implementation joins two `next_u64()` calls, the first forming the high half.
+    fn next_u128(&mut self) -> u128 {
+        let hi = u128::from(self.next_u64());
+        (hi << 64) | u128::from(self.next_u64())
+    }
+
+    /// Generates the next 32-bit signed integer from the bits of `next_u32()`.
+    fn next_i32(&mut self) -> i32 {
+        self.next_u32() as i32
+    }
+
+    /// Generates the next 64-bit signed integer from the bits of `next_u64()`.
+    fn next_i64(&mut self) -> i64 {
+        self.next_u64() as i64
+    }
+
+    /// Generates the next 128-bit signed integer from the bits of `next_u128()`.
+    fn next_i128(&mut self) -> i128 {
+        self.next_u128() as i128
+    }
+
     /// Fills `buf` with random bytes using repeated `next_u64()` calls.
     /// The tail shorter than 8 bytes is handled with a final partial copy.
     fn fill_bytes(&mut self, buf: &mut [u8]) {
@@ -138,3 +273,637 @@ pub trait RandomBackend {
         }
     }
 }
+
+// `RandomBackend` is object safe: backends can be chosen at runtime as `dyn RandomBackend`.
+const _: Option<&dyn RandomBackend> = None;
+
+/// Forwards to the referenced backend, so `Rng<&mut B>` borrows an existing backend.
+impl<B: RandomBackend + ?Sized> RandomBackend for &mut B {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (**self).next_f64()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        (**self).next_u128()
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        (**self).fill_bytes(buf)
+    }
+}
+
+/// Forwards to the boxed backend, so `Rng<Box<dyn RandomBackend>>` can hold a backend
+/// selected at runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{RandomBackend, Rng, backend::{PCG, Xoshiro256StarStar}};
+///
+/// fn backend_from_config(name: &str, seed: u64) -> Box<dyn RandomBackend> {
+///     match name {
+///         "pcg" => Box::new(PCG::new(seed, 0)),
+///         _ => Box::new(Xoshiro256StarStar::new(seed)),
+///     }
+/// }
+///
+/// let mut rng = Rng::new(backend_from_config("pcg", 7));
+/// let _ = rng.gen_range(0..10);
+/// ```
+#[cfg(feature = "alloc")]
+impl<B: RandomBackend + ?Sized> RandomBackend for alloc::boxed::Box<B> {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (**self).next_f64()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        (**self).next_u128()
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        (**self).fill_bytes(buf)
+    }
+}
+
+/// Trait for backends that naturally produce their output a block at a time.
+///
+/// [`BlockBackend::generate`] fills a whole block in one call, letting the backend run its
+/// block function (a Mersenne Twister twist, a ChaCha block) without per-value bookkeeping.
+/// Wrap a block backend in [`BlockRng`] to buffer one block and serve single values from it.
+///
+/// `generate` returns exactly the values that `N` calls to `next_u64` would have returned,
+/// so [`BlockRng`] reproduces the backend's own sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{BlockBackend, RandomBackend, backend::{BlockRng, MT19937_64}};
+///
+/// let mut block = [0u64; 312];
+/// MT19937_64::new(5489).generate(&mut block);
+/// assert_eq!(block[0], 14514284786278117030);
+///
+/// let mut rng = BlockRng::new(MT19937_64::new(5489));
+/// assert_eq!(rng.next_u64(), block[0]);
+/// ```
+pub trait BlockBackend<const N: usize>: RandomBackend {
+    /// Fills `out` with the next `N` outputs.
+    fn generate(&mut self, out: &mut [u64; N]);
+}
+
+/// Metadata describing a backend algorithm.
+///
+/// Every built-in backend implements this trait, so tools can list and compare backends
+/// programmatically instead of parsing the documentation tables.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{BackendInfo, backend::{MT19937_64, RomuTrio}};
+///
+/// assert_eq!(MT19937_64::name(), "mt19937_64");
+/// assert_eq!(MT19937_64::period_log2(), Some(19937));
+/// assert_eq!(RomuTrio::period_log2(), None);
+/// assert!(MT19937_64::state_bytes() >= 312 * 8);
+/// ```
+pub trait BackendInfo: Sized {
+    /// Returns the backend's canonical lowercase name, e.g. `"xoshiro256**"`.
+    fn name() -> &'static str;
+
+    /// Returns the base-2 logarithm of the period in `next_u64` outputs, rounded to the
+    /// nearest integer (so 2<sup>64</sup>−1 reports 64).
+    ///
+    /// Backends with 32-bit steps spend two steps on each `next_u64`, so they report one
+    /// less than their period in native outputs: `MT19937` reports 19936.
+    ///
+    /// Returns `None` when the period is not fixed: probabilistic periods, reseeding
+    /// generators, and entropy sources.
+    fn period_log2() -> Option<u32>;
+
+    /// Returns the size of the generator state in bytes, including any output buffer.
+    fn state_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+}
+
+/// Trait for backends that can be constructed from a seed.
+///
+/// Every deterministic backend implements this trait, so generic code can construct any of
+/// them. [`SeedableBackend::from_seed_u64`] follows the backend's own `new` constructor,
+/// while [`SeedableBackend::from_seed`] takes a byte array whose size matches the backend's
+/// natural seed material (for example its full state or key). Multi-byte values are read
+/// in little-endian order.
+///
+/// Backends whose state must not be all zero replace an all-zero byte seed with a fixed
+/// valid state instead of failing.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{RandomBackend, SeedableBackend};
+/// use aporia::backend::{ChaCha20, Xoshiro256StarStar};
+///
+/// fn first_value<B: SeedableBackend>(seed: u64) -> u64 {
+///     B::from_seed_u64(seed).next_u64()
+/// }
+///
+/// assert_eq!(first_value::<Xoshiro256StarStar>(7), Xoshiro256StarStar::new(7).next_u64());
+/// let mut chacha = ChaCha20::from_seed([1u8; 32]);
+/// let _ = chacha.next_u64();
+/// ```
+pub trait SeedableBackend: RandomBackend + Sized {
+    /// The byte-array seed type, e.g. `[u8; 32]`.
+    type Seed: SeedBytes;
+
+    /// Creates a backend from a byte seed.
+    fn from_seed(seed: Self::Seed) -> Self;
+
+    /// Creates a backend from a `u64` seed, like the backend's `new` constructor.
+    fn from_seed_u64(seed: u64) -> Self;
+}
+
+/// Byte-array seed types that can be created zero-filled and then written in place.
+///
+/// Implemented for every `[u8; N]`, so generic code can fill a seed of any length, e.g.
+/// from another generator.
+pub trait SeedBytes: Sized + AsRef<[u8]> + AsMut<[u8]> {
+    /// Returns an all-zero seed.
+    fn zeroed() -> Self;
+}
+
+impl<const N: usize> SeedBytes for [u8; N] {
+    fn zeroed() -> Self {
+        [0; N]
+    }
+}
+
+/// Reads `N` little-endian `u64` words from the start of `bytes`.
+pub(crate) fn words_from_le_bytes<const N: usize>(bytes: &[u8]) -> [u64; N] {
+    core::array::from_fn(|i| {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[8 * i..8 * i + 8]);
+        u64::from_le_bytes(word)
+    })
+}
+
+/// Inverts `x ^= x << shift`.
+pub(crate) fn unxorshift_left(x: u64, shift: u32) -> u64 {
+    let mut y = x;
+    let mut k = shift;
+    while k < 64 {
+        y ^= y << k;
+        k *= 2;
+    }
+    y
+}
+
+/// Inverts `x ^= x >> shift`.
+pub(crate) fn unxorshift_right(x: u64, shift: u32) -> u64 {
+    let mut y = x;
+    let mut k = shift;
+    while k < 64 {
+        y ^= y >> k;
+        k *= 2;
+    }
+    y
+}
+
+/// Trait for backends whose state can be saved to and restored from a fixed byte layout.
+///
+/// Each implementation documents its layout: fields are stored back to back in a fixed
+/// order, integers are little-endian, and buffer positions are stored as `u64`. The layout
+/// does not depend on the platform, so snapshots can be written to disk or passed across
+/// FFI without a serialization framework. Restoring a snapshot resumes the sequence exactly.
+///
+/// [`StateSnapshot::from_state_bytes`] rejects input of the wrong length and states the
+/// backend could never reach (such as an all-zero xorshift state or an out-of-range
+/// buffer position). Snapshots of the cryptographic backends contain their keys.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{RandomBackend, StateSnapshot, backend::Pcg64Dxsm};
+///
+/// let mut rng = Pcg64Dxsm::new(42, 54);
+/// let _ = rng.next_u64();
+/// let saved = rng.to_state_bytes();
+///
+/// let mut restored = Pcg64Dxsm::from_state_bytes(&saved).unwrap();
+/// assert_eq!(restored.next_u64(), rng.next_u64());
+/// ```
+pub trait StateSnapshot: Sized {
+    /// Byte array holding a snapshot, e.g. `[u8; 32]`.
+    type Bytes: Sized + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Returns the current state in the backend's documented layout.
+    fn to_state_bytes(&self) -> Self::Bytes;
+
+    /// Restores a backend from bytes produced by [`StateSnapshot::to_state_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::InvalidState`] if `bytes` has the wrong length or
+    /// describes an invalid state.
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError>;
+}
+
+/// Trait for backends that can be split into statistically independent children.
+///
+/// Splitting is deterministic: the same parent state always produces the same child, and
+/// the parent advances so that repeated splits produce distinct children. This is useful
+/// for tree-structured computations where each task needs its own reproducible RNG.
+///
+/// [`SplitMix64`] implements a true split (a new seed and a new increment, as in Java's
+/// `SplittableRandom`). The other backends split by seeding a fresh instance from the
+/// parent's output.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{RandomBackend, Splittable, backend::SplitMix64};
+///
+/// let mut parent = SplitMix64::new(42);
+/// let mut left = parent.split();
+/// let mut right = parent.split();
+/// assert_ne!(left.next_u64(), right.next_u64());
+/// ```
+pub trait Splittable: RandomBackend + Sized {
+    /// Returns a new child generator, advancing `self`.
+    fn split(&mut self) -> Self;
+}
+
+/// Trait for linear backends that can advance their state by a huge power of two in O(1).
+///
+/// Jumping applies the generator's published jump polynomial, which is equivalent to
+/// calling `next_u64` 2<sup>k</sup> times. Cloning a generator and jumping each clone a
+/// different number of times yields non-overlapping subsequences for parallel work.
+///
+/// | Backend | `jump` | `long_jump` |
+/// |---------|--------|-------------|
+/// | [`XorShift128Plus`] | 2<sup>64</sup> | 2<sup>96</sup> |
+/// | [`Xoshiro256StarStar`], [`Xoshiro256PlusPlus`], [`Xoshiro256Plus`] | 2<sup>128</sup> | 2<sup>192</sup> |
+/// | [`Xoshiro512StarStar`] | 2<sup>256</sup> | 2<sup>384</sup> |
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Jumpable, RandomBackend, backend::Xoshiro256PlusPlus};
+///
+/// let base = Xoshiro256PlusPlus::new(42);
+/// let mut workers: Vec<_> = (0..4)
+///     .map(|i| {
+///         let mut w = base.clone();
+///         for _ in 0..i {
+///             w.jump();
+///         }
+///         w
+///     })
+///     .collect();
+/// assert_ne!(workers[0].next_u64(), workers[1].next_u64());
+/// ```
+pub trait Jumpable: RandomBackend {
+    /// Advances the state as if `next_u64` had been called the number of times given by
+    /// the backend's jump distance.
+    fn jump(&mut self);
+
+    /// Advances the state by the backend's long jump distance, which is used to create
+    /// starting points for groups of [`Jumpable::jump`] subsequences.
+    fn long_jump(&mut self);
+}
+
+/// Trait for backends that can skip ahead an arbitrary number of draws in O(log n) time.
+///
+/// Congruential generators advance by an affine map, so `n` steps can be composed by
+/// repeated squaring instead of being generated one by one.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{RandomBackend, Skippable, backend::PCG};
+///
+/// let mut skipped = PCG::new(42, 54);
+/// let mut stepped = skipped.clone();
+/// skipped.discard(10);
+/// for _ in 0..10 {
+///     stepped.next_u64();
+/// }
+/// assert_eq!(skipped.next_u64(), stepped.next_u64());
+/// ```
+pub trait Skippable: RandomBackend {
+    /// Advances the state as if `next_u64` had been called `n` times.
+    fn discard(&mut self, n: u128);
+}
+
+/// Trait for backends that support many independent streams under one seed.
+///
+/// A stream selector picks one of 2<sup>64</sup> (2<sup>63</sup> for [`PCG`]) sequences
+/// that share the same seed or key but do not overlap, so parallel workers can derive
+/// their generators from a single root seed and their worker index.
+///
+/// | Backend | Stream selector |
+/// |---------|-----------------|
+/// | [`PCG`] | LCG increment (`2 * stream + 1`, top bit of `stream` ignored) |
+/// | [`Philox`] | Most significant counter word |
+/// | [`ChaCha`] | 64-bit nonce (state words 14 and 15) |
+///
+/// For [`Philox`] and [`ChaCha`], changing the stream keeps the block counter and discards
+/// buffered output. For [`PCG`], the state is offset so that switching a freshly constructed
+/// generator is the same as constructing it with the new stream.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{RandomBackend, Streamable, backend::ChaCha8};
+///
+/// let mut a = ChaCha8::new(7).with_stream(1);
+/// let mut b = ChaCha8::new(7).with_stream(2);
+/// assert_ne!(a.next_u64(), b.next_u64());
+/// assert_eq!(a.stream(), 1);
+/// ```
+pub trait Streamable: RandomBackend + Sized {
+    /// Returns the current stream selector.
+    fn stream(&self) -> u64;
+
+    /// Switches to the given stream.
+    fn set_stream(&mut self, stream: u64);
+
+    /// Returns `self` switched to the given stream.
+    fn with_stream(mut self, stream: u64) -> Self {
+        self.set_stream(stream);
+        self
+    }
+}
+
+/// Trait for backends whose state transition can be run backwards.
+///
+/// [`Reversible::prev_u64`] undoes the most recent [`RandomBackend::next_u64`] call and
+/// returns the value it produced, so a simulation can be rewound draw by draw to the point
+/// where something went wrong. Calling `next_u64` afterwards produces that value again.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{RandomBackend, Reversible, backend::Xoshiro256StarStar};
+///
+/// let mut rng = Xoshiro256StarStar::new(3);
+/// let a = rng.next_u64();
+/// let b = rng.next_u64();
+/// assert_eq!(rng.prev_u64(), b);
+/// assert_eq!(rng.prev_u64(), a);
+/// assert_eq!(rng.next_u64(), a);
+/// ```
+pub trait Reversible: RandomBackend {
+    /// Steps the state back by one draw and returns the value that draw produced.
+    fn prev_u64(&mut self) -> u64;
+}
+
+/// Trait for backends whose changing state is a single `u64` word.
+///
+/// Everything else in the backend (an increment, a key) stays fixed after construction.
+/// This is what [`AtomicRng`] needs to advance the generator with one atomic
+/// compare-exchange.
+pub trait AtomicBackend: RandomBackend + Clone {
+    /// Returns the state word.
+    fn state_word(&self) -> u64;
+
+    /// Replaces the state word, leaving the fixed parameters unchanged.
+    fn set_state_word(&mut self, word: u64);
+}
+
+/// Implements [`Splittable`] for backends constructed from a single `u64` seed.
+///
+/// The child seed is the parent's next output passed through the SplitMix64 finalizer,
+/// which is a bijection that keeps non-zero outputs non-zero.
+macro_rules! impl_splittable_by_seed {
+    ($($backend:ty),* $(,)?) => {$(
+        impl Splittable for $backend {
+            fn split(&mut self) -> Self {
+                Self::new(splitmix64::mix64(self.next_u64()))
+            }
+        }
+    )*};
+}
+
+impl_splittable_by_seed!(
+    LCG,
+    XorShift,
+    XorShift128Plus,
+    XorWow,
+    MT19937_64,
+    Well19937a,
+    Xoshiro256StarStar,
+    Xoshiro256PlusPlus,
+    Xoshiro256Plus,
+    Xoshiro512StarStar,
+    RomuTrio,
+    RomuDuoJr,
+    Cmwc,
+    Isaac64,
+    Philox,
+    Squares,
+    Pcg32Fast,
+);
+
+impl<const ROUNDS: usize> Splittable for ChaCha<ROUNDS> {
+    fn split(&mut self) -> Self {
+        Self::new(splitmix64::mix64(self.next_u64()))
+    }
+}
+
+impl Splittable for MT19937 {
+    fn split(&mut self) -> Self {
+        Self::new(self.next_u32())
+    }
+}
+
+impl Splittable for PCG {
+    fn split(&mut self) -> Self {
+        let seed = splitmix64::mix64(self.next_u64());
+        let sequence = splitmix64::mix64(self.next_u64());
+        Self::new(seed, sequence)
+    }
+}
+
+impl Splittable for Pcg32 {
+    fn split(&mut self) -> Self {
+        let seed = splitmix64::mix64(self.next_u64());
+        let sequence = splitmix64::mix64(self.next_u64());
+        Self::new(seed, sequence)
+    }
+}
+
+impl Splittable for Pcg64Dxsm {
+    fn split(&mut self) -> Self {
+        let seed = (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64());
+        let stream = (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64());
+        Self::new(seed, stream)
+    }
+}
+
+/// Implements [`BackendInfo`] from a name and a period exponent.
+macro_rules! impl_backend_info {
+    ($($backend:ty => $name:expr, $period:expr;)*) => {$(
+        impl BackendInfo for $backend {
+            fn name() -> &'static str {
+                $name
+            }
+
+            fn period_log2() -> Option<u32> {
+                $period
+            }
+        }
+    )*};
+}
+
+impl_backend_info! {
+    LCG => "lcg", Some(64);
+    PCG => "pcg", Some(63);
+    Pcg64Dxsm => "pcg64dxsm", Some(128);
+    Pcg32 => "pcg32", Some(63);
+    Pcg32Fast => "pcg32fast", Some(61);
+    XorShift => "xorshift", Some(64);
+    XorShift128Plus => "xorshift128+", Some(128);
+    XorWow => "xorwow", Some(191);
+    MT19937_64 => "mt19937_64", Some(19937);
+    MT19937 => "mt19937", Some(19936);
+    Well19937a => "well19937a", Some(19936);
+    SplitMix64 => "splitmix64", Some(64);
+    Xoshiro256StarStar => "xoshiro256**", Some(256);
+    Xoshiro256PlusPlus => "xoshiro256++", Some(256);
+    Xoshiro256Plus => "xoshiro256+", Some(256);
+    Xoshiro512StarStar => "xoshiro512**", Some(512);
+    RomuTrio => "romutrio", None;
+    RomuDuoJr => "romuduojr", None;
+    Cmwc => "cmwc", Some(131103);
+    Isaac64 => "isaac64", None;
+    Philox => "philox", Some(258);
+    Squares => "squares", Some(64);
+    HmacDrbg => "hmac_drbg", None;
+    CtrDrbg => "ctr_drbg", None;
+    Fortuna => "fortuna", None;
+}
+
+#[cfg(feature = "getrandom")]
+impl_backend_info! {
+    OsRandom => "os", None;
+}
+
+#[cfg(all(feature = "rdrand", target_arch = "x86_64"))]
+impl_backend_info! {
+    RdRand => "rdrand", None;
+    RdSeed => "rdseed", None;
+}
+
+impl<const ROUNDS: usize> BackendInfo for ChaCha<ROUNDS> {
+    fn name() -> &'static str {
+        match ROUNDS {
+            8 => "chacha8",
+            12 => "chacha12",
+            20 => "chacha20",
+            _ => "chacha",
+        }
+    }
+
+    /// 2<sup>64</sup> blocks of eight `u64` outputs per stream.
+    fn period_log2() -> Option<u32> {
+        Some(67)
+    }
+}
+
+impl<B: BlockBackend<N> + BackendInfo, const N: usize> BackendInfo for BlockRng<B, N> {
+    fn name() -> &'static str {
+        B::name()
+    }
+
+    fn period_log2() -> Option<u32> {
+        B::period_log2()
+    }
+}
+
+/// Creates a backend from a byte seed filled by the operating system.
+///
+/// # Panics
+///
+/// Panics if the OS random source fails.
+#[cfg(feature = "getrandom")]
+fn from_os_seed<B: SeedableBackend>(mut seed: B::Seed) -> B {
+    if let Err(e) = OsRandom::new().try_fill_bytes(seed.as_mut()) {
+        panic!("{}", e);
+    }
+    B::from_seed(seed)
+}
+
+/// Implements `Default` by seeding the full byte seed from the operating system.
+macro_rules! impl_default_from_os {
+    ($($backend:ty),* $(,)?) => {$(
+        #[cfg(feature = "getrandom")]
+        impl Default for $backend {
+            /// Creates a non-deterministic instance seeded from the operating system.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the OS random source fails; seed through
+            /// [`OsRandom::try_fill_bytes`] and [`SeedableBackend::from_seed`] to handle
+            /// the error instead.
+            fn default() -> Self {
+                from_os_seed(core::array::from_fn(|_| 0))
+            }
+        }
+    )*};
+}
+
+impl_default_from_os!(
+    LCG,
+    PCG,
+    Pcg64Dxsm,
+    Pcg32,
+    Pcg32Fast,
+    XorShift,
+    XorShift128Plus,
+    XorWow,
+    MT19937_64,
+    MT19937,
+    Well19937a,
+    SplitMix64,
+    Xoshiro256StarStar,
+    Xoshiro256PlusPlus,
+    Xoshiro256Plus,
+    Xoshiro512StarStar,
+    RomuTrio,
+    RomuDuoJr,
+    Cmwc,
+    Isaac64,
+    Philox,
+    Squares,
+    HmacDrbg,
+    CtrDrbg,
+    Fortuna,
+);
+
+#[cfg(feature = "getrandom")]
+impl<const ROUNDS: usize> Default for ChaCha<ROUNDS> {
+    /// Creates a ChaCha instance with a key read from the operating system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS random source fails.
+    fn default() -> Self {
+        from_os_seed(core::array::from_fn(|_| 0))
+    }
+}
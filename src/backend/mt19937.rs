@@ -0,0 +1,175 @@
+//! 32-bit Mersenne Twister (MT19937) implementation.
+//!
+//! This is the original 32-bit Mersenne Twister, the algorithm behind C++ `std::mt19937`,
+//! Python's `random` module, and many legacy codebases. Its native output is a `u32`; use it
+//! when sequences from those environments must be reproduced exactly. For new code prefer
+//! [`crate::backend::MT19937_64`] or a modern generator.
+//!
+//! # Characteristics
+//!
+//! - State size: 2.5 KB (624 * 4 bytes)
+//! - Period: 2<sup>19937</sup>−1
+//! - Speed: Moderate
+//! - Quality: High
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::MT19937};
+//!
+//! let backend = MT19937::new(5489); // Same as a default-constructed std::mt19937
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u32();
+//! ```
+//!
+//! # References
+//!
+//! - [Makoto Matsumoto and Takuji Nishimura (1998), "Mersenne Twister: A 623-dimensionally equidistributed uniform pseudo-random number generator"](https://www.math.sci.hiroshima-u.ac.jp/~m-mat/MT/ARTICLES/mt.pdf)
+//! - [cppreference: std::mersenne_twister_engine](https://en.cppreference.com/w/cpp/numeric/random/mersenne_twister_engine)
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot};
+
+const N: usize = 624;
+const M: usize = 397;
+
+/// 32-bit Mersenne Twister (MT19937) struct.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MT19937 {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    mt: [u32; N],
+    index: usize,
+}
+
+impl MT19937 {
+    /// Creates a new `MT19937` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value, as passed to `std::mt19937::seed`.
+    pub fn new(seed: u32) -> Self {
+        let mut mt = [0u32; N];
+        mt[0] = seed;
+
+        for i in 1..N {
+            mt[i] = 1_812_433_253u32
+                .wrapping_mul(mt[i - 1] ^ (mt[i - 1] >> 30))
+                .wrapping_add(i as u32);
+        }
+
+        Self { mt, index: N }
+    }
+
+    /// Performs the twist operation to regenerate all 624 state words.
+    fn twist(&mut self) {
+        const UPPER_MASK: u32 = 0x8000_0000;
+        const LOWER_MASK: u32 = 0x7fff_ffff;
+        const MATRIX_A: u32 = 0x9908_b0df;
+
+        for i in 0..N {
+            let x = (self.mt[i] & UPPER_MASK) | (self.mt[(i + 1) % N] & LOWER_MASK);
+            let mut x_a = x >> 1;
+
+            if x & 1 != 0 {
+                x_a ^= MATRIX_A;
+            }
+
+            self.mt[i] = self.mt[(i + M) % N] ^ x_a;
+        }
+        self.index = 0;
+    }
+}
+
+impl RandomBackend for MT19937 {
+    /// Generates the next random `u64` from two consecutive `u32` outputs.
+    ///
+    /// The first output forms the low half and the second the high half.
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// Generates the next 32-bit unsigned integer, identical to `std::mt19937::operator()`.
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= N {
+            self.twist();
+        }
+
+        let mut y = self.mt[self.index];
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c_5680;
+        y ^= (y << 15) & 0xefc6_0000;
+        y ^= y >> 18;
+
+        self.index += 1;
+        y
+    }
+}
+
+impl SeedableBackend for MT19937 {
+    type Seed = [u8; 4];
+
+    /// Seeds with the little-endian seed word, as `new` does.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u32::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        // Like `std::mt19937`, a wider seed is reduced modulo 2^32.
+        Self::new(seed as u32)
+    }
+}
+
+/// Layout (2504 bytes): `mt` (624 x u32), `index` (u64, at most 624).
+impl StateSnapshot for MT19937 {
+    type Bytes = [u8; 2504];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 2504];
+        let mut w = StateWriter::new(&mut out);
+        w.u32s(&self.mt);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 2504)?;
+        let mt = r.u32s();
+        let index = r.index(N)?;
+        Ok(Self { mt, index })
+    }
+}
+
+impl core::fmt::Debug for MT19937 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Avoid dumping large internal state; show only minimal info.
+        f.debug_struct("MT19937")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mt19937_cpp_standard_check_value() {
+        // C++ [rand.predef]: the 10000th consecutive invocation of a default-constructed
+        // std::mt19937 produces 4123659995.
+        let mut mt = MT19937::new(5489);
+        for _ in 0..9999 {
+            let _ = mt.next_u32();
+        }
+        assert_eq!(mt.next_u32(), 4123659995);
+    }
+
+    #[test]
+    fn mt19937_first_outputs() {
+        let mut mt = MT19937::new(5489);
+        assert_eq!(mt.next_u32(), 3499211612);
+        assert_eq!(mt.next_u32(), 581869302);
+        assert_eq!(mt.next_u32(), 3890346734);
+    }
+}
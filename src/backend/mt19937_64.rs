@@ -25,10 +25,14 @@
 //!
 //! - [Mutsuo Saito and Makoto Matsumoto - MT19937-64 C code](https://www.math.sci.hiroshima-u.ac.jp/~m-mat/MT/emt64.html)
 //! - [Wikipedia: Mersenne Twister](https://en.wikipedia.org/wiki/Mersenne_Twister)
-use super::RandomBackend;
+use super::snapshot::{StateReader, StateWriter};
+use super::{BlockBackend, RandomBackend, SeedableBackend, StateSnapshot};
 
 /// 64-bit Mersenne Twister (MT19937-64) struct.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MT19937_64 {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
     mt: [u64; 312],
     index: usize,
 }
@@ -51,6 +55,55 @@ impl MT19937_64 {
         
         Self { mt, index: 312 }
     }
+
+    /// Creates a new `MT19937_64` instance from an array of seed words.
+    ///
+    /// This follows `init_by_array64` of the reference implementation exactly, so a
+    /// generator seeded this way reproduces the C sequence for the same key. An empty
+    /// slice is treated like `&[0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{RandomBackend, backend::MT19937_64};
+    ///
+    /// // Key and first output from the reference `mt19937-64.out.txt`.
+    /// let mut mt = MT19937_64::from_seed_slice(&[0x12345, 0x23456, 0x34567, 0x45678]);
+    /// assert_eq!(mt.next_u64(), 7266447313870364031);
+    /// ```
+    pub fn from_seed_slice(key: &[u64]) -> Self {
+        let mut s = Self::new(19650218);
+        let mt = &mut s.mt;
+        let mut i = 1;
+        let mut j = 0;
+        for _ in 0..312.max(key.len()) {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 62);
+            mt[i] = (mt[i] ^ prev.wrapping_mul(3935559000370003845))
+                .wrapping_add(key.get(j).copied().unwrap_or(0))
+                .wrapping_add(j as u64);
+            i += 1;
+            j += 1;
+            if i >= 312 {
+                mt[0] = mt[311];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..311 {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 62);
+            mt[i] = (mt[i] ^ prev.wrapping_mul(2862933555777941757)).wrapping_sub(i as u64);
+            i += 1;
+            if i >= 312 {
+                mt[0] = mt[311];
+                i = 1;
+            }
+        }
+        // Ensures a non-zero initial state.
+        mt[0] = 1 << 63;
+        s
+    }
     
     /// Performs the twist operation to update the state of the MT19937-64 generator.
     fn twist(&mut self) {
@@ -70,6 +123,15 @@ impl MT19937_64 {
         }
         self.index = 0;
     }
+
+    /// Applies the MT19937-64 tempering transform to a state word.
+    fn temper(mut y: u64) -> u64 {
+        y ^= (y >> 29) & 0x5555555555555555;
+        y ^= (y << 17) & 0x71D67FFFEDA60000;
+        y ^= (y << 37) & 0xFFF7EEE000000000;
+        y ^= y >> 43;
+        y
+    }
 }
 
 impl RandomBackend for MT19937_64 {
@@ -79,17 +141,62 @@ impl RandomBackend for MT19937_64 {
             self.twist();
         }
         
-        let mut y = self.mt[self.index];
-        y ^= (y >> 29) & 0x5555555555555555;
-        y ^= (y << 17) & 0x71D67FFFEDA60000;
-        y ^= (y << 37) & 0xFFF7EEE000000000;
-        y ^= y >> 43;
-        
+        let y = Self::temper(self.mt[self.index]);
         self.index += 1;
         y
     }
 }
 
+impl BlockBackend<312> for MT19937_64 {
+    /// Tempers a whole freshly twisted state when positioned at a block boundary.
+    fn generate(&mut self, out: &mut [u64; 312]) {
+        if self.index < 312 {
+            for o in out.iter_mut() {
+                *o = self.next_u64();
+            }
+            return;
+        }
+        self.twist();
+        for (o, &word) in out.iter_mut().zip(self.mt.iter()) {
+            *o = Self::temper(word);
+        }
+        self.index = 312;
+    }
+}
+
+impl SeedableBackend for MT19937_64 {
+    type Seed = [u8; 8];
+
+    /// Seeds with the little-endian seed word, as `new` does.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (2504 bytes): `mt` (312 x u64), `index` (u64, at most 312).
+impl StateSnapshot for MT19937_64 {
+    type Bytes = [u8; 2504];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 2504];
+        let mut w = StateWriter::new(&mut out);
+        w.u64s(&self.mt);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 2504)?;
+        let mt = r.u64s();
+        let index = r.index(312)?;
+        Ok(Self { mt, index })
+    }
+}
+
 impl core::fmt::Debug for MT19937_64 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // Avoid dumping large internal state; show only minimal info.
@@ -110,4 +217,20 @@ mod tests {
         let b = mt.next_u64();
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn mt19937_64_init_by_array_matches_reference() {
+        // First outputs of mt19937-64.out.txt from the reference implementation.
+        let mut mt = MT19937_64::from_seed_slice(&[0x12345, 0x23456, 0x34567, 0x45678]);
+        let expected = [
+            7266447313870364031,
+            4946485549665804864,
+            16945909448695747420,
+            16394063075524226720,
+            4873882236456199058,
+        ];
+        for e in expected {
+            assert_eq!(mt.next_u64(), e);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,105 @@
+//! Operating system entropy backend.
+//!
+//! [`OsRandom`] draws every value directly from the operating system's cryptographically
+//! secure random number generator (for example `getrandom(2)` on Linux, `BCryptGenRandom`
+//! on Windows), via the [`getrandom`](https://docs.rs/getrandom) crate. Output is
+//! non-deterministic and suitable for keys, nonces and seeding other generators.
+//!
+//! Every call goes through the OS, so it is much slower than the userspace generators in
+//! this module. A common pattern is to seed a fast generator once from `OsRandom`.
+//!
+//! This backend is only available with the `getrandom` feature enabled.
+//!
+//! # Characteristics
+//!
+//! - State size: 0 bytes
+//! - Period: Not applicable (non-deterministic)
+//! - Speed: Slow (one system call per request)
+//! - Quality: Cryptographic
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::{OsRandom, Xoshiro256StarStar}};
+//!
+//! let mut rng = Rng::new(OsRandom::new());
+//! let random_number = rng.next_u64();
+//!
+//! // Seed a fast generator from OS entropy.
+//! let seed = OsRandom::new().try_next_u64().expect("OS entropy unavailable");
+//! let mut fast = Rng::new(Xoshiro256StarStar::new(seed));
+//! ```
+
+use super::RandomBackend;
+
+/// Backend that reads from the operating system's secure random number generator.
+///
+/// `OsRandom` holds no state: every value is written straight into the caller's buffer, so
+/// there is nothing for the `zeroize` feature to wipe.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OsRandom;
+
+impl OsRandom {
+    /// Creates a new `OsRandom` handle.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns a random `u64` from the OS, or an error if no entropy source is available.
+    pub fn try_next_u64(&mut self) -> core::result::Result<u64, crate::AporiaError> {
+        getrandom::u64().map_err(|_| crate::AporiaError::EntropyUnavailable("OS random source failed"))
+    }
+
+    /// Fills `buf` with random bytes from the OS, or returns an error if no entropy source is
+    /// available.
+    pub fn try_fill_bytes(&mut self, buf: &mut [u8]) -> core::result::Result<(), crate::AporiaError> {
+        getrandom::fill(buf).map_err(|_| crate::AporiaError::EntropyUnavailable("OS random source failed"))
+    }
+}
+
+impl RandomBackend for OsRandom {
+    /// Reads the next random `u64` from the OS.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS random source fails; use [`OsRandom::try_next_u64`] to handle
+    /// the error instead.
+    fn next_u64(&mut self) -> u64 {
+        match self.try_next_u64() {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Fills `buf` directly from the OS in a single request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS random source fails; use [`OsRandom::try_fill_bytes`] to handle
+    /// the error instead.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        if let Err(e) = self.try_fill_bytes(buf) {
+            panic!("{}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn os_random_produces_varied_output() {
+        let mut os = OsRandom::new();
+        let a = os.try_next_u64().unwrap();
+        let b = os.try_next_u64().unwrap();
+        let c = os.next_u64();
+        // Three equal 64-bit values from a working OS source is practically impossible.
+        assert!(a != b || b != c);
+
+        let mut buf = [0u8; 64];
+        os.fill_bytes(&mut buf);
+        assert!(buf.iter().any(|&x| x != 0));
+    }
+}
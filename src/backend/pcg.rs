@@ -6,10 +6,17 @@
 //! # Characteristics
 //!
 //! - State size: 16 bytes
-//! - Period: 2<sup>64</sup>
+//! - Period: 2<sup>64</sup> 32-bit outputs (2<sup>63</sup> `u64` outputs)
 //! - Speed: Fast
 //! - Quality: High
 //!
+//! # Output
+//!
+//! Each step produces 32 bits. `next_u32` returns one step's output, and
+//! [`PCG::next_u64`] joins two consecutive outputs, the first as the low half. This is the
+//! same stream as [`crate::backend::Pcg32`], so `PCG::new(seed, seq)` reproduces the
+//! reference `pcg32` generator.
+//!
 //! # Example
 //!
 //! ```rust
@@ -25,10 +32,18 @@
 //! - [PCG: A Family of Better Random Number Generators](http://www.pcg-random.org)
 //! - [Melissa E. O'Neill (2014), "PCG: A Family of Simple Fast Space-Efficient Statistically Good Algorithms for Random Number Generation"](https://www.cs.hmc.edu/tr/hmc-cs-2014-0905.pdf)
 
-use super::RandomBackend;
+use super::lcg::{lcg64_advance, mul_inverse_u64};
+use super::snapshot::{StateReader, StateWriter};
+use super::{
+    AtomicBackend, RandomBackend, Reversible, SeedableBackend, Skippable, StateSnapshot,
+    Streamable, words_from_le_bytes,
+};
+
+const MULTIPLIER: u64 = 6364136223846793005;
 
 /// Permuted Congruential Generator (PCG) struct.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PCG {
     state: u64,
     increment: u64,
@@ -41,32 +56,135 @@ impl PCG {
     ///
     /// * `seed` - The initial seed value.
     /// * `sequence` - The stream/sequence selector.
-    pub fn new(seed: u64, sequence: u64) -> Self {
+    pub const fn new(seed: u64, sequence: u64) -> Self {
         let increment = (sequence << 1) | 1;
-        let mut pcg = Self {
-            state: 0,
-            increment,
-        };
-        pcg.state = seed.wrapping_add(increment);
-        let _ = pcg.next_u64(); // Advance to initial state
-        pcg
+        // The reference seeding routine steps once after adding the seed; the step is
+        // applied inline so it can run at compile time.
+        let state = seed
+            .wrapping_add(increment)
+            .wrapping_mul(MULTIPLIER)
+            .wrapping_add(increment);
+        Self { state, increment }
     }
 }
 
+/// Applies the XSH-RR output permutation to a state.
+fn output(state: u64) -> u32 {
+    let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+    let rot = (state >> 59) as u32;
+
+    xorshifted.rotate_right(rot)
+}
+
 impl RandomBackend for PCG {
-    /// Generates the next random `u64` using the PCG algorithm.
+    /// Generates the next random `u64` from two consecutive 32-bit outputs.
+    ///
+    /// The first output forms the low half and the second the high half.
     fn next_u64(&mut self) -> u64 {
-        const MULTIPLIER: u64 = 6364136223846793005;
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
 
+    /// Generates the next random `u32` using the PCG-XSH-RR output function.
+    fn next_u32(&mut self) -> u32 {
         let old_state = self.state;
         self.state = old_state
             .wrapping_mul(MULTIPLIER)
             .wrapping_add(self.increment);
 
-        let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
-        let rot = (old_state >> 59) as u32;
+        output(old_state)
+    }
+}
+
+impl SeedableBackend for PCG {
+    type Seed = [u8; 16];
 
-        u64::from(xorshifted.rotate_right(rot))
+    /// Reads the seed and sequence selector as two little-endian words.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [seed, sequence] = words_from_le_bytes(&seed);
+        Self::new(seed, sequence)
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed, 0)
+    }
+}
+
+impl Skippable for PCG {
+    fn discard(&mut self, n: u128) {
+        // Each draw is two steps and the period is 2^64 steps, so only the low 63 bits of
+        // `n` matter.
+        let steps = (n as u64).wrapping_mul(2);
+        self.state = lcg64_advance(self.state, MULTIPLIER, self.increment, steps);
+    }
+}
+
+impl Streamable for PCG {
+    fn stream(&self) -> u64 {
+        self.increment >> 1
+    }
+
+    /// Replaces the increment with `2 * stream + 1` and offsets the state by the same
+    /// amount `PCG::new` would, so `PCG::new(seed, a).with_stream(b)` equals
+    /// `PCG::new(seed, b)`.
+    fn set_stream(&mut self, stream: u64) {
+        let increment = (stream << 1) | 1;
+        let offset = increment.wrapping_sub(self.increment).wrapping_mul(MULTIPLIER.wrapping_add(1));
+        self.state = self.state.wrapping_add(offset);
+        self.increment = increment;
+    }
+}
+
+impl PCG {
+    /// Steps the state back once and returns the 32-bit output of that step.
+    fn prev_u32(&mut self) -> u32 {
+        self.state = self
+            .state
+            .wrapping_sub(self.increment)
+            .wrapping_mul(mul_inverse_u64(MULTIPLIER));
+        output(self.state)
+    }
+}
+
+impl Reversible for PCG {
+    fn prev_u64(&mut self) -> u64 {
+        let hi = u64::from(self.prev_u32());
+        let lo = u64::from(self.prev_u32());
+        (hi << 32) | lo
+    }
+}
+
+impl AtomicBackend for PCG {
+    fn state_word(&self) -> u64 {
+        self.state
+    }
+
+    fn set_state_word(&mut self, word: u64) {
+        self.state = word;
+    }
+}
+
+/// Layout (16 bytes): `state` (u64), `increment` (u64, odd).
+impl StateSnapshot for PCG {
+    type Bytes = [u8; 16];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 16];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.state);
+        w.u64(self.increment);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 16)?;
+        let state = r.u64();
+        let increment = r.u64();
+        if increment & 1 == 0 {
+            return Err(crate::AporiaError::InvalidState("PCG increment must be odd"));
+        }
+        Ok(Self { state, increment })
     }
 }
 
@@ -81,4 +199,67 @@ mod tests {
         let b = pcg.next_u64();
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn pcg_next_u32_matches_reference() {
+        // First outputs of the reference pcg32 demo for pcg32_srandom(42, 54).
+        let mut pcg = PCG::new(42, 54);
+        for expected in [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293] {
+            assert_eq!(pcg.next_u32(), expected);
+        }
+    }
+
+    #[test]
+    fn pcg_u64_draws_use_all_bits() {
+        use crate::{Rng, SliceRandom};
+
+        // Two reference outputs per draw, low half first.
+        assert_eq!(PCG::new(42, 54).next_u64(), 0x7b47f409_a15c02b7);
+
+        // Widening-multiply bounded draws read the high half of `next_u64`.
+        let mut rng = Rng::new(PCG::new(42, 54));
+        let mut seen = [false; 10];
+        for _ in 0..200 {
+            seen[rng.gen_range(0u64..10).unwrap() as usize] = true;
+            seen[rng.gen_range(0usize..10).unwrap()] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let mut v: [u32; 16] = core::array::from_fn(|i| i as u32);
+        v.shuffle(&mut rng);
+        assert_ne!(v, core::array::from_fn::<u32, 16, _>(|i| i as u32));
+    }
+
+    #[test]
+    fn pcg_discard_matches_sequential_draws() {
+        for n in [0u128, 1, 7, 4096, 123_457] {
+            let mut skipped = PCG::new(42, 54);
+            let mut stepped = skipped.clone();
+            skipped.discard(n);
+            for _ in 0..n {
+                let _ = stepped.next_u64();
+            }
+            assert_eq!(skipped.next_u64(), stepped.next_u64());
+        }
+    }
+
+    #[test]
+    fn pcg_streams_are_distinct() {
+        let mut a = PCG::new(42, 0).with_stream(1);
+        let mut b = PCG::new(42, 0).with_stream(2);
+        assert_eq!(a.stream(), 1);
+        assert_eq!(b.state, PCG::new(42, 2).state);
+        assert_ne!(a.next_u64(), b.next_u64());
+    }
+
+    #[test]
+    fn pcg_prev_undoes_next() {
+        let mut pcg = PCG::new(7, 11);
+        let start = pcg.state;
+        let a = pcg.next_u64();
+        let b = pcg.next_u64();
+        assert_eq!(pcg.prev_u64(), b);
+        assert_eq!(pcg.prev_u64(), a);
+        assert_eq!(pcg.state, start);
+    }
 }
@@ -0,0 +1,252 @@
+//! PCG32 random number generator implementations with native 32-bit output.
+//!
+//! [`Pcg32`] is the reference `pcg32` generator (PCG-XSH-RR with 64-bit state and 32-bit
+//! output). It produces `u32` values natively and builds each `u64` from two consecutive
+//! outputs. [`crate::backend::PCG`] produces the same stream and adds jump-ahead, stream
+//! selection and reversal.
+//!
+//! [`Pcg32Fast`] is the `pcg32_fast` variant: a multiplicative (MCG) state transition with
+//! the XSH-RS output function. It is slightly faster, has a period of 2<sup>62</sup>, and
+//! offers no stream selection.
+//!
+//! # Characteristics
+//!
+//! | Variant | State Size | Speed | Quality | Period |
+//! |---------|------------|-------|---------|--------|
+//! | Pcg32 | 16 bytes | Fast | High | 2<sup>64</sup> |
+//! | Pcg32Fast | 8 bytes | Very Fast | High | 2<sup>62</sup> |
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Pcg32};
+//!
+//! let backend = Pcg32::new(42, 54); // Seed and sequence values
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u32();
+//! ```
+//!
+//! # References
+//!
+//! - [PCG: A Family of Better Random Number Generators](http://www.pcg-random.org)
+//! - [Wikipedia: Permuted congruential generator](https://en.wikipedia.org/wiki/Permuted_congruential_generator)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot, words_from_le_bytes};
+
+/// Multiplier shared by the 64-bit PCG state transitions.
+const MULTIPLIER: u64 = 6364136223846793005;
+
+/// PCG32 (PCG-XSH-RR 64/32) random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    /// Creates a new `Pcg32` instance with the given seed and sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    /// * `sequence` - The stream/sequence selector.
+    ///
+    /// Seeding follows the reference `pcg32_srandom_r` procedure, so outputs match the
+    /// reference C implementation for the same arguments.
+    pub fn new(seed: u64, sequence: u64) -> Self {
+        let mut pcg = Self {
+            state: 0,
+            increment: (sequence << 1) | 1,
+        };
+        let _ = pcg.next_u32();
+        pcg.state = pcg.state.wrapping_add(seed);
+        let _ = pcg.next_u32();
+        pcg
+    }
+}
+
+impl RandomBackend for Pcg32 {
+    /// Generates the next random `u64` from two consecutive `u32` outputs.
+    ///
+    /// The first output forms the low half and the second the high half.
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// Generates the next random `u32` using the PCG-XSH-RR output function.
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(MULTIPLIER)
+            .wrapping_add(self.increment);
+
+        let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rot = (old_state >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+}
+
+/// `pcg32_fast` (PCG-XSH-RS 64/32 MCG) random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pcg32Fast {
+    state: u64,
+}
+
+impl Pcg32Fast {
+    /// Creates a new `Pcg32Fast` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The state is set to `2 * seed + 1` (the MCG state must be odd) and advanced once,
+    /// matching the reference `pcg32_fast_init`.
+    pub fn new(seed: u64) -> Self {
+        let mut pcg = Self {
+            state: seed.wrapping_mul(2).wrapping_add(1),
+        };
+        let _ = pcg.next_u32();
+        pcg
+    }
+}
+
+impl RandomBackend for Pcg32Fast {
+    /// Generates the next random `u64` from two consecutive `u32` outputs.
+    ///
+    /// The first output forms the low half and the second the high half.
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// Generates the next random `u32` using the PCG-XSH-RS output function.
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        let count = (x >> 61) as u32;
+        self.state = x.wrapping_mul(MULTIPLIER);
+        x ^= x >> 22;
+        (x >> (22 + count)) as u32
+    }
+}
+
+impl SeedableBackend for Pcg32 {
+    type Seed = [u8; 16];
+
+    /// Reads the seed and sequence selector as two little-endian words.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [seed, sequence] = words_from_le_bytes(&seed);
+        Self::new(seed, sequence)
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed, 0)
+    }
+}
+
+impl SeedableBackend for Pcg32Fast {
+    type Seed = [u8; 8];
+
+    /// Reads the seed as a little-endian word.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (16 bytes): `state` (u64), `increment` (u64, odd).
+impl StateSnapshot for Pcg32 {
+    type Bytes = [u8; 16];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 16];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.state);
+        w.u64(self.increment);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 16)?;
+        let state = r.u64();
+        let increment = r.u64();
+        if increment & 1 == 0 {
+            return Err(crate::AporiaError::InvalidState("Pcg32 increment must be odd"));
+        }
+        Ok(Self { state, increment })
+    }
+}
+
+/// Layout (8 bytes): `state` (u64, odd).
+impl StateSnapshot for Pcg32Fast {
+    type Bytes = [u8; 8];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 8];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.state);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 8)?;
+        let state = r.u64();
+        if state & 1 == 0 {
+            return Err(crate::AporiaError::InvalidState("Pcg32Fast state must be odd"));
+        }
+        Ok(Self { state })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pcg32_matches_reference_demo() {
+        // First outputs of the reference pcg32-demo with seed 42 and sequence 54.
+        let mut pcg = Pcg32::new(42, 54);
+        let expected = [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e];
+        for e in expected {
+            assert_eq!(pcg.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn pcg32_fast_matches_reference() {
+        // Outputs of the reference pcg32_fast after pcg32_fast_init(42).
+        let mut pcg = Pcg32Fast::new(42);
+        let expected = [0x361246b5, 0xf7b5ea8e, 0x0ee0dbf7, 0x52462699, 0xfb95e95e, 0xed1e70e8];
+        for e in expected {
+            assert_eq!(pcg.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn pcg32_u64_combines_two_draws() {
+        let mut a = Pcg32::new(1, 2);
+        let mut b = Pcg32::new(1, 2);
+        let lo = u64::from(a.next_u32());
+        let hi = u64::from(a.next_u32());
+        assert_eq!(b.next_u64(), (hi << 32) | lo);
+    }
+
+    #[test]
+    fn pcg32_fast_rejects_even_state() {
+        let pcg = Pcg32Fast::new(42);
+        let bytes = pcg.to_state_bytes();
+        assert_eq!(Pcg32Fast::from_state_bytes(&bytes).unwrap(), pcg);
+
+        let mut even = bytes;
+        even[0] &= !1;
+        assert!(Pcg32Fast::from_state_bytes(&even).is_err());
+    }
+}
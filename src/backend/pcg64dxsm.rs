@@ -0,0 +1,187 @@
+//! PCG64-DXSM random number generator implementation.
+//!
+//! PCG64-DXSM is the 128-bit-state member of the PCG family that NumPy uses as its default
+//! bit generator. It advances a 128-bit LCG with a 64-bit "cheap" multiplier and applies
+//! the DXSM (double xorshift multiply) output function to the pre-advance state, which
+//! gives stronger stream independence than the older XSL-RR output function.
+//!
+//! # Reproducing NumPy Streams
+//!
+//! NumPy derives the initial state from `SeedSequence`, so the simplest way to reproduce
+//! a NumPy stream is to copy its raw state:
+//!
+//! ```python
+//! bg = numpy.random.PCG64DXSM(seed)
+//! st = bg.state["state"]  # {"state": ..., "inc": ...}
+//! ```
+//!
+//! and pass both values to [`Pcg64Dxsm::from_state`]. The `u64` outputs then match
+//! `bg.random_raw()` exactly.
+//!
+//! # Characteristics
+//!
+//! - State size: 32 bytes (128-bit state, 128-bit increment)
+//! - Period: 2<sup>128</sup> per stream, 2<sup>127</sup> streams
+//! - Speed: Fast
+//! - Quality: Excellent
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Pcg64Dxsm};
+//!
+//! let backend = Pcg64Dxsm::new(42, 54); // Seed and stream values
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//! ```
+//!
+//! # References
+//!
+//! - [PCG: A Family of Better Random Number Generators](http://www.pcg-random.org)
+//! - [NumPy PCG64DXSM documentation](https://numpy.org/doc/stable/reference/random/bit_generators/pcg64dxsm.html)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot, words_from_le_bytes};
+
+/// The 64-bit "cheap multiplier" used for both the state transition and DXSM output.
+const CHEAP_MULTIPLIER: u64 = 0xda94_2042_e4dd_58b5;
+
+/// PCG64-DXSM random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pcg64Dxsm {
+    state: u128,
+    increment: u128,
+}
+
+impl Pcg64Dxsm {
+    /// Creates a new `Pcg64Dxsm` instance with the given seed and stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    /// * `stream` - The stream/sequence selector. Only the low 127 bits are significant.
+    ///
+    /// Seeding follows the reference `pcg_setseq_128_srandom_r` procedure.
+    pub fn new(seed: u128, stream: u128) -> Self {
+        let mut pcg = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        pcg.step();
+        pcg.state = pcg.state.wrapping_add(seed);
+        pcg.step();
+        pcg
+    }
+
+    /// Creates a new `Pcg64Dxsm` from a raw state and increment.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The 128-bit LCG state.
+    /// * `increment` - The 128-bit LCG increment; it is forced odd.
+    ///
+    /// These correspond to NumPy's `state["state"]["state"]` and `state["state"]["inc"]`.
+    pub fn from_state(state: u128, increment: u128) -> Self {
+        Self {
+            state,
+            increment: increment | 1,
+        }
+    }
+
+    /// Advances the underlying 128-bit LCG by one step.
+    #[inline]
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(u128::from(CHEAP_MULTIPLIER))
+            .wrapping_add(self.increment);
+    }
+}
+
+impl RandomBackend for Pcg64Dxsm {
+    /// Generates the next random `u64` using the DXSM output function.
+    fn next_u64(&mut self) -> u64 {
+        let mut hi = (self.state >> 64) as u64;
+        let lo = (self.state as u64) | 1;
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(CHEAP_MULTIPLIER);
+        hi ^= hi >> 48;
+        hi = hi.wrapping_mul(lo);
+        self.step();
+        hi
+    }
+}
+
+impl SeedableBackend for Pcg64Dxsm {
+    type Seed = [u8; 32];
+
+    /// Reads the 128-bit seed and stream selector as little-endian values.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [s0, s1, t0, t1] = words_from_le_bytes(&seed);
+        let seed = (u128::from(s1) << 64) | u128::from(s0);
+        let stream = (u128::from(t1) << 64) | u128::from(t0);
+        Self::new(seed, stream)
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(u128::from(seed), 0)
+    }
+}
+
+/// Layout (32 bytes): `state` (u128), `increment` (u128, odd).
+impl StateSnapshot for Pcg64Dxsm {
+    type Bytes = [u8; 32];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 32];
+        let mut w = StateWriter::new(&mut out);
+        w.u128(self.state);
+        w.u128(self.increment);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 32)?;
+        let state = r.u128();
+        let increment = r.u128();
+        if increment & 1 == 0 {
+            return Err(crate::AporiaError::InvalidState("Pcg64Dxsm increment must be odd"));
+        }
+        Ok(Self { state, increment })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pcg64dxsm_seeded_reference_outputs() {
+        // Outputs of NumPy's pcg64.h DXSM code after pcg_cm_srandom_r(42, 54).
+        let mut pcg = Pcg64Dxsm::new(42, 54);
+        let expected = [
+            17331114245835578256,
+            10267467544499227306,
+            9726600296081716989,
+            10165951391103677450,
+            12131334649314727261,
+            10134094537930450875,
+        ];
+        for e in expected {
+            assert_eq!(pcg.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn pcg64dxsm_from_raw_state() {
+        // Raw state as reported by `PCG64DXSM().state["state"]`.
+        let mut pcg = Pcg64Dxsm::from_state(
+            0x0123456789abcdef_fedcba9876543210,
+            0x1111111111111111_3333333333333333,
+        );
+        assert_eq!(pcg.next_u64(), 11944377826318632098);
+        assert_eq!(pcg.next_u64(), 3979426529131279547);
+        assert_eq!(pcg.next_u64(), 17069302060439782387);
+    }
+}
@@ -0,0 +1,273 @@
+//! Philox4x64 counter-based random number generator implementation.
+//!
+//! Philox is a counter-based generator from the Random123 family. Instead of evolving a
+//! hidden state, each 256-bit output block is a keyed bijection of a 256-bit counter, so
+//! any position in the stream can be computed directly. This makes it a natural fit for
+//! reproducible parallel simulations: a work item can derive its random values from its
+//! own index without depending on the order in which other items draw.
+//!
+//! This implementation uses the standard 10-round Philox4x64 variant.
+//!
+//! # Characteristics
+//!
+//! - State size: 88 bytes (16-byte key, 32-byte counter, 32-byte output block)
+//! - Period: 2<sup>258</sup> outputs per key
+//! - Speed: Fast
+//! - Quality: Excellent (passes BigCrush)
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Philox};
+//!
+//! let backend = Philox::new(2024);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//!
+//! // Random access: the value at index 10 is independent of draw order.
+//! let philox = Philox::new(2024);
+//! let tenth = philox.at(10);
+//! ```
+//!
+//! # References
+//!
+//! - [Salmon, Moraes, Dror, Shaw (2011), "Parallel Random Numbers: As Easy as 1, 2, 3"](https://www.thesalmons.org/john/random123/papers/random123sc11.pdf)
+//! - [Random123 library](https://github.com/DEShawResearch/random123)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot, Streamable, words_from_le_bytes};
+
+/// Round multipliers for Philox4x64.
+const M0: u64 = 0xD2E7_470E_E14C_6C93;
+const M1: u64 = 0xCA5A_8263_9512_1157;
+
+/// Weyl sequence constants used to bump the key between rounds.
+const W0: u64 = 0x9E37_79B9_7F4A_7C15;
+const W1: u64 = 0xBB67_AE85_84CA_A73B;
+
+/// Number of rounds applied per block.
+const ROUNDS: usize = 10;
+
+/// Philox4x64-10 random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Philox {
+    key: [u64; 2],
+    counter: [u64; 4],
+    buffer: [u64; 4],
+    index: usize,
+}
+
+impl Philox {
+    /// Creates a new `Philox` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value, used as the low word of the key.
+    ///
+    /// The counter starts at zero.
+    pub fn new(seed: u64) -> Self {
+        Self::from_key_counter([seed, 0], [0; 4])
+    }
+
+    /// Creates a new `Philox` instance from an explicit key and starting counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The 128-bit key as two words.
+    /// * `counter` - The 256-bit starting counter as four words, least significant first.
+    pub fn from_key_counter(key: [u64; 2], counter: [u64; 4]) -> Self {
+        Self {
+            key,
+            counter,
+            buffer: [0; 4],
+            index: 4,
+        }
+    }
+
+    /// Returns the current key.
+    pub fn key(&self) -> [u64; 2] {
+        self.key
+    }
+
+    /// Replaces the key and discards any buffered output.
+    ///
+    /// The counter is left unchanged, so the next draw comes from the same counter
+    /// position under the new key.
+    pub fn set_key(&mut self, key: [u64; 2]) {
+        self.key = key;
+        self.index = 4;
+    }
+
+    /// Returns the counter of the next block to be generated.
+    pub fn counter(&self) -> [u64; 4] {
+        self.counter
+    }
+
+    /// Sets the counter of the next block to be generated and discards any buffered output.
+    pub fn set_counter(&mut self, counter: [u64; 4]) {
+        self.counter = counter;
+        self.index = 4;
+    }
+
+    /// Returns the value at position `index` of the stream without changing any state.
+    ///
+    /// Position `index` lives in the block whose low counter word is `index / 4`; the upper
+    /// three counter words are taken from the current counter, so they can be used to
+    /// select an independent sequence (for example, a work-item id). For a generator whose
+    /// counter starts at zero, `at(i)` equals the `i`-th value returned by `next_u64`.
+    pub fn at(&self, index: u64) -> u64 {
+        let ctr = [index / 4, self.counter[1], self.counter[2], self.counter[3]];
+        philox4x64(ctr, self.key)[(index % 4) as usize]
+    }
+
+    /// Increments the 256-bit counter by one.
+    fn increment_counter(&mut self) {
+        for word in self.counter.iter_mut() {
+            *word = word.wrapping_add(1);
+            if *word != 0 {
+                break;
+            }
+        }
+    }
+}
+
+/// Returns the high and low halves of the 128-bit product `a * b`.
+#[inline(always)]
+fn mulhilo(a: u64, b: u64) -> (u64, u64) {
+    let product = u128::from(a) * u128::from(b);
+    ((product >> 64) as u64, product as u64)
+}
+
+/// Computes one Philox4x64-10 block for the given counter and key.
+fn philox4x64(mut ctr: [u64; 4], mut key: [u64; 2]) -> [u64; 4] {
+    for round in 0..ROUNDS {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(W0);
+            key[1] = key[1].wrapping_add(W1);
+        }
+        let (hi0, lo0) = mulhilo(M0, ctr[0]);
+        let (hi1, lo1) = mulhilo(M1, ctr[2]);
+        ctr = [hi1 ^ ctr[1] ^ key[0], lo1, hi0 ^ ctr[3] ^ key[1], lo0];
+    }
+    ctr
+}
+
+impl RandomBackend for Philox {
+    /// Generates the next random `u64` from the current counter block.
+    fn next_u64(&mut self) -> u64 {
+        if self.index >= 4 {
+            self.buffer = philox4x64(self.counter, self.key);
+            self.increment_counter();
+            self.index = 0;
+        }
+        let v = self.buffer[self.index];
+        self.index += 1;
+        v
+    }
+}
+
+impl SeedableBackend for Philox {
+    type Seed = [u8; 16];
+
+    /// Uses the seed bytes (little-endian words) as the key, with the counter at zero.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_key_counter(words_from_le_bytes(&seed), [0; 4])
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl Streamable for Philox {
+    /// Returns the most significant counter word.
+    fn stream(&self) -> u64 {
+        self.counter[3]
+    }
+
+    /// Sets the most significant counter word, leaving 2<sup>192</sup> blocks per stream
+    /// in the lower three words.
+    fn set_stream(&mut self, stream: u64) {
+        self.counter[3] = stream;
+        self.index = 4;
+    }
+}
+
+/// Layout (88 bytes): `key` (2 x u64), `counter` (4 x u64), `buffer` (4 x u64), `index` (u64, at most 4).
+impl StateSnapshot for Philox {
+    type Bytes = [u8; 88];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 88];
+        let mut w = StateWriter::new(&mut out);
+        w.u64s(&self.key);
+        w.u64s(&self.counter);
+        w.u64s(&self.buffer);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 88)?;
+        let key = r.u64s();
+        let counter = r.u64s();
+        let buffer = r.u64s();
+        let index = r.index(4)?;
+        Ok(Self { key, counter, buffer, index })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn philox4x64_known_answers() {
+        // Known-answer vectors from Random123's kat_vectors for philox4x64_10.
+        assert_eq!(
+            philox4x64([0; 4], [0; 2]),
+            [0x16554d9eca36314c, 0xdb20fe9d672d0fdc, 0xd7e772cee186176b, 0x7e68b68aec7ba23b]
+        );
+        assert_eq!(
+            philox4x64([u64::MAX; 4], [u64::MAX; 2]),
+            [0x87b092c3013fe90b, 0x438c3c67be8d0224, 0x9cc7d7c69cd777b6, 0xa09caebf594f0ba0]
+        );
+        assert_eq!(
+            philox4x64(
+                [0x243f6a8885a308d3, 0x13198a2e03707344, 0xa4093822299f31d0, 0x082efa98ec4e6c89],
+                [0x452821e638d01377, 0xbe5466cf34e90c6c]
+            ),
+            [0xa528f45403e61d95, 0x38c72dbd566e9788, 0xa5a1610e72fd18b5, 0x57bd43b5e52b7fe6]
+        );
+    }
+
+    #[test]
+    fn philox_at_matches_sequential_draws() {
+        let mut seq = Philox::new(77);
+        let random_access = Philox::new(77);
+        for i in 0..20 {
+            assert_eq!(seq.next_u64(), random_access.at(i));
+        }
+    }
+
+    #[test]
+    fn philox_set_counter_and_key() {
+        let mut a = Philox::new(1);
+        let _ = a.next_u64();
+        a.set_counter([8, 0, 0, 0]);
+        assert_eq!(a.next_u64(), Philox::new(1).at(32));
+
+        a.set_key([2, 0]);
+        a.set_counter([0; 4]);
+        assert_eq!(a.next_u64(), Philox::new(2).at(0));
+    }
+
+    #[test]
+    fn philox_stream_selects_top_counter_word() {
+        let mut a = Philox::new(5).with_stream(3);
+        let expected = Philox::from_key_counter([5, 0], [0, 0, 0, 3]);
+        assert_eq!(a.stream(), 3);
+        assert_eq!(a.next_u64(), expected.at(0));
+    }
+}
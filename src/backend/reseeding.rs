@@ -0,0 +1,219 @@
+//! Adapter that periodically reseeds a backend from an entropy source.
+//!
+//! [`ReseedingRng`] serves values from an inner [`SeedableBackend`] and replaces it with a
+//! freshly seeded instance after a configurable number of output bytes. The new seed is
+//! read from a second backend, typically [`OsRandom`](super::OsRandom). Long-lived services
+//! use this to bound how much output depends on any single seed, so a leaked state exposes
+//! at most one reseed interval.
+//!
+//! The check happens before each draw: once at least `threshold` bytes have been produced
+//! since the last reseed, the next call reseeds first. A single `fill_bytes` call is never
+//! split across two seeds.
+//!
+//! # Fork Safety
+//!
+//! A process that forks after creating a generator leaves parent and child with identical
+//! state, so both would produce the same stream. On Unix with the `std` feature,
+//! [`ReseedingRng::reseed_on_fork`] makes the adapter compare the process id before every
+//! draw and reseed when it has changed. The check costs a `getpid` call per draw, so it is
+//! opt-in. The source must itself be fork-safe; `OsRandom` is, a seeded userspace
+//! generator is not.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::{ChaCha20, ReseedingRng, SplitMix64}};
+//!
+//! // In production the source would be `OsRandom`; SplitMix64 keeps this example portable.
+//! let backend = ReseedingRng::<ChaCha20, _>::from_seed([7; 32], SplitMix64::new(1), 1 << 20);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//! ```
+
+use super::{RandomBackend, SeedBytes, SeedableBackend};
+
+/// Adapter that reseeds its inner backend from `source` every `threshold` output bytes.
+pub struct ReseedingRng<B: SeedableBackend, S: RandomBackend> {
+    inner: B,
+    source: S,
+    /// Scratch buffer for new seeds; zeroed again after every reseed.
+    seed: B::Seed,
+    threshold: u64,
+    bytes_since_reseed: u64,
+    /// Process id at the last reseed, when fork detection is enabled.
+    #[cfg(all(unix, feature = "std"))]
+    fork_pid: Option<u32>,
+}
+
+impl<B: SeedableBackend, S: RandomBackend> ReseedingRng<B, S> {
+    /// Creates an adapter whose first inner backend is built from `seed`.
+    ///
+    /// Later backends are seeded with bytes drawn from `source` once `threshold` bytes of
+    /// output have been served. A `threshold` of zero reseeds before every draw.
+    pub fn from_seed(seed: B::Seed, source: S, threshold: u64) -> Self
+    where
+        B::Seed: Clone,
+    {
+        let mut rng = Self {
+            inner: B::from_seed(seed.clone()),
+            source,
+            seed,
+            threshold,
+            bytes_since_reseed: 0,
+            #[cfg(all(unix, feature = "std"))]
+            fork_pid: None,
+        };
+        rng.seed.as_mut().fill(0);
+        rng
+    }
+
+    /// Creates an adapter whose first inner backend is also seeded from `source`.
+    pub fn new(mut source: S, threshold: u64) -> Self
+    where
+        B::Seed: Clone,
+    {
+        let mut seed = B::Seed::zeroed();
+        source.fill_bytes(seed.as_mut());
+        Self::from_seed(seed, source, threshold)
+    }
+
+    /// Replaces the inner backend with one seeded from the source right away.
+    pub fn reseed(&mut self)
+    where
+        B::Seed: Clone,
+    {
+        self.source.fill_bytes(self.seed.as_mut());
+        self.inner = B::from_seed(self.seed.clone());
+        self.seed.as_mut().fill(0);
+        self.bytes_since_reseed = 0;
+        #[cfg(all(unix, feature = "std"))]
+        if self.fork_pid.is_some() {
+            self.fork_pid = Some(std::process::id());
+        }
+    }
+
+    /// Enables reseeding whenever the process id changes, i.e. in a forked child.
+    ///
+    /// See the [module documentation](self) for the cost and the requirement on the source.
+    #[cfg(all(unix, feature = "std"))]
+    pub fn reseed_on_fork(mut self) -> Self {
+        self.fork_pid = Some(std::process::id());
+        self
+    }
+
+    /// Returns `true` if this process is not the one that last reseeded.
+    #[cfg(all(unix, feature = "std"))]
+    fn forked(&self) -> bool {
+        self.fork_pid.is_some_and(|pid| pid != std::process::id())
+    }
+
+    #[cfg(not(all(unix, feature = "std")))]
+    fn forked(&self) -> bool {
+        false
+    }
+
+    /// Returns the number of output bytes between reseeds.
+    pub fn threshold(&self) -> u64 {
+        self.threshold
+    }
+
+    /// Returns the number of bytes served since the last reseed.
+    pub fn bytes_since_reseed(&self) -> u64 {
+        self.bytes_since_reseed
+    }
+
+    /// Returns a reference to the current inner backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Reseeds if the threshold has been reached, then counts `bytes` of upcoming output.
+    fn before_draw(&mut self, bytes: u64)
+    where
+        B::Seed: Clone,
+    {
+        if self.bytes_since_reseed >= self.threshold || self.forked() {
+            self.reseed();
+        }
+        self.bytes_since_reseed = self.bytes_since_reseed.saturating_add(bytes);
+    }
+}
+
+impl<B, S> RandomBackend for ReseedingRng<B, S>
+where
+    B: SeedableBackend,
+    B::Seed: Clone,
+    S: RandomBackend,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.before_draw(8);
+        self.inner.next_u64()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.before_draw(4);
+        self.inner.next_u32()
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.before_draw(8);
+        self.inner.next_f64()
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        self.before_draw(buf.len() as u64);
+        self.inner.fill_bytes(buf);
+    }
+}
+
+impl<B, S> core::fmt::Debug for ReseedingRng<B, S>
+where
+    B: SeedableBackend + core::fmt::Debug,
+    S: RandomBackend + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReseedingRng")
+            .field("inner", &self.inner)
+            .field("source", &self.source)
+            .field("threshold", &self.threshold)
+            .field("bytes_since_reseed", &self.bytes_since_reseed)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{CtrDrbg, SplitMix64};
+
+    #[test]
+    fn reseeds_after_threshold_bytes() {
+        let mut rng = ReseedingRng::<CtrDrbg, _>::from_seed([1; 48], SplitMix64::new(2), 16);
+        let mut plain = CtrDrbg::from_seed([1; 48]);
+        assert_eq!(rng.next_u64(), plain.next_u64());
+        assert_eq!(rng.next_u64(), plain.next_u64());
+        assert_eq!(rng.bytes_since_reseed(), 16);
+
+        // The third draw comes from a backend seeded with the source's first 48 bytes.
+        let mut seed = [0u8; 48];
+        SplitMix64::new(2).fill_bytes(&mut seed);
+        assert_eq!(rng.next_u64(), CtrDrbg::from_seed(seed).next_u64());
+        assert_eq!(rng.bytes_since_reseed(), 8);
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn reseeds_when_process_id_changes() {
+        let mut rng = ReseedingRng::<CtrDrbg, _>::from_seed([1; 48], SplitMix64::new(2), u64::MAX)
+            .reseed_on_fork();
+        let mut plain = CtrDrbg::from_seed([1; 48]);
+        assert_eq!(rng.next_u64(), plain.next_u64());
+
+        // Pretend the last reseed happened in a parent process.
+        rng.fork_pid = Some(0);
+        let mut seed = [0u8; 48];
+        SplitMix64::new(2).fill_bytes(&mut seed);
+        assert_eq!(rng.next_u64(), CtrDrbg::from_seed(seed).next_u64());
+        assert_eq!(rng.fork_pid, Some(std::process::id()));
+    }
+}
@@ -0,0 +1,254 @@
+//! Romu family random number generator implementations.
+//!
+//! Romu generators combine a multiplication with rotations in a nonlinear state update.
+//! They are among the fastest generators available because the multiply is off the
+//! critical path, which lets modern CPUs overlap it with the rest of the work. Not suitable
+//! for cryptographic purposes.
+//!
+//! # Probabilistic Period
+//!
+//! Unlike linear generators, Romu generators have no single guaranteed period: the state
+//! graph consists of many cycles of unknown length. The period of a randomly seeded
+//! instance is instead bounded probabilistically. The authors estimate that the chance of
+//! landing on a cycle shorter than 2<sup>k</sup> is at most about 2<sup>k</sup> /
+//! 2<sup>state bits</sup>, so short cycles are astronomically unlikely but not impossible.
+//! The all-zero state is a fixed point and is never produced by the constructors.
+//!
+//! - [`RomuTrio`]: 192-bit state; the authors' recommendation when the capacity of a
+//!   probabilistic period is a concern (about 2<sup>75</sup> values before a short cycle
+//!   becomes plausible).
+//! - [`RomuDuoJr`]: 128-bit state and the fastest variant, best for small-to-moderate jobs
+//!   (the authors suggest staying below 2<sup>51</sup> values per stream).
+//!
+//! # Characteristics
+//!
+//! | Variant | State Size | Speed | Quality | Period |
+//! |---------|------------|-------|---------|--------|
+//! | RomuTrio | 24 bytes | Very Fast | Good | Probabilistic, ≥ 2<sup>75</sup> expected |
+//! | RomuDuoJr | 16 bytes | Very Fast | Good | Probabilistic, ≥ 2<sup>51</sup> expected |
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::{RomuDuoJr, RomuTrio}};
+//!
+//! let mut rng = Rng::new(RomuTrio::new(24680));
+//! let random_number = rng.next_u64();
+//!
+//! let mut fast = Rng::new(RomuDuoJr::new(24680));
+//! let x = fast.next_f64();
+//! ```
+//!
+//! # References
+//!
+//! - [Mark A. Overton (2020), "Romu: Fast Nonlinear Pseudo-Random Number Generators Providing High Quality"](https://arxiv.org/abs/2002.11331)
+//! - [romu-random.org](https://www.romu-random.org/)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot, words_from_le_bytes};
+use crate::backend::SplitMix64;
+
+/// Multiplier shared by the Romu generators.
+const MULTIPLIER: u64 = 15241094284759029579;
+
+/// Fills `state` from a `SplitMix64` stream seeded with `seed`, avoiding the all-zero state.
+fn seed_state<const N: usize>(seed: u64) -> [u64; N] {
+    let mut sm = SplitMix64::new(seed);
+    let mut s = [0u64; N];
+    for x in s.iter_mut() {
+        *x = sm.next_u64();
+    }
+    if s.iter().all(|&x| x == 0) {
+        s[0] = 1;
+    }
+    s
+}
+
+/// RomuTrio random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomuTrio {
+    x: u64,
+    y: u64,
+    z: u64,
+}
+
+impl RomuTrio {
+    /// Creates a new `RomuTrio` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is expanded using `SplitMix64` to fill the state.
+    pub fn new(seed: u64) -> Self {
+        let [x, y, z] = seed_state::<3>(seed);
+        Self { x, y, z }
+    }
+}
+
+impl RandomBackend for RomuTrio {
+    /// Generates the next random `u64` using the RomuTrio algorithm.
+    fn next_u64(&mut self) -> u64 {
+        let (xp, yp, zp) = (self.x, self.y, self.z);
+        self.x = MULTIPLIER.wrapping_mul(zp);
+        self.y = yp.wrapping_sub(xp).rotate_left(12);
+        self.z = zp.wrapping_sub(yp).rotate_left(44);
+        xp
+    }
+}
+
+/// RomuDuoJr random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomuDuoJr {
+    x: u64,
+    y: u64,
+}
+
+impl RomuDuoJr {
+    /// Creates a new `RomuDuoJr` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is expanded using `SplitMix64` to fill the state.
+    pub fn new(seed: u64) -> Self {
+        let [x, y] = seed_state::<2>(seed);
+        Self { x, y }
+    }
+}
+
+impl RandomBackend for RomuDuoJr {
+    /// Generates the next random `u64` using the RomuDuoJr algorithm.
+    fn next_u64(&mut self) -> u64 {
+        let xp = self.x;
+        self.x = MULTIPLIER.wrapping_mul(self.y);
+        self.y = self.y.wrapping_sub(xp).rotate_left(27);
+        xp
+    }
+}
+
+impl SeedableBackend for RomuTrio {
+    type Seed = [u8; 24];
+
+    /// Uses the seed bytes (little-endian words) as the state; an all-zero seed is replaced
+    /// by the state of `new(0)`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [x, y, z] = words_from_le_bytes(&seed);
+        if [x, y, z] == [0; 3] {
+            return Self::new(0);
+        }
+        Self { x, y, z }
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl SeedableBackend for RomuDuoJr {
+    type Seed = [u8; 16];
+
+    /// Uses the seed bytes (little-endian words) as the state; an all-zero seed is replaced
+    /// by the state of `new(0)`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [x, y] = words_from_le_bytes(&seed);
+        if [x, y] == [0; 2] {
+            return Self::new(0);
+        }
+        Self { x, y }
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (24 bytes): `x` (u64), `y` (u64), `z` (u64).
+impl StateSnapshot for RomuTrio {
+    type Bytes = [u8; 24];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 24];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.x);
+        w.u64(self.y);
+        w.u64(self.z);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 24)?;
+        let x = r.u64();
+        let y = r.u64();
+        let z = r.u64();
+        if x | y | z == 0 {
+            return Err(crate::AporiaError::InvalidState("RomuTrio state must not be all zero"));
+        }
+        Ok(Self { x, y, z })
+    }
+}
+
+/// Layout (16 bytes): `x` (u64), `y` (u64).
+impl StateSnapshot for RomuDuoJr {
+    type Bytes = [u8; 16];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 16];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.x);
+        w.u64(self.y);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 16)?;
+        let x = r.u64();
+        let y = r.u64();
+        if x | y == 0 {
+            return Err(crate::AporiaError::InvalidState("RomuDuoJr state must not be all zero"));
+        }
+        Ok(Self { x, y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn romu_trio_matches_reference() {
+        // Outputs of the reference C implementation with state {1, 2, 3}.
+        let mut r = RomuTrio { x: 1, y: 2, z: 3 };
+        let expected = [
+            1,
+            8829794706857985505,
+            14228190636816728064,
+            7047022733925001397,
+            11050715128277420919,
+            15593090640687002226,
+        ];
+        for e in expected {
+            assert_eq!(r.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn romu_duo_jr_matches_reference() {
+        // Outputs of the reference C implementation with state {1, 2}.
+        let mut r = RomuDuoJr { x: 1, y: 2 };
+        let expected = [
+            1,
+            12035444495808507542,
+            178563687714390016,
+            13542421656172534717,
+            9222735459507768234,
+            14604799755248147759,
+        ];
+        for e in expected {
+            assert_eq!(r.next_u64(), e);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Little-endian writer and reader used by [`super::StateSnapshot`] implementations.
+//!
+//! Fields are written back to back in declaration order with no padding. Integers are
+//! little-endian and buffer positions (`usize` fields) are stored as `u64`.
+
+use crate::AporiaError;
+
+/// Sequential writer over a fixed-size snapshot buffer.
+pub(crate) struct StateWriter<'a> {
+    out: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> StateWriter<'a> {
+    pub(crate) fn new(out: &'a mut [u8]) -> Self {
+        Self { out, pos: 0 }
+    }
+
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) {
+        self.out[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+    }
+
+    pub(crate) fn u32(&mut self, v: u32) {
+        self.bytes(&v.to_le_bytes());
+    }
+
+    pub(crate) fn u64(&mut self, v: u64) {
+        self.bytes(&v.to_le_bytes());
+    }
+
+    pub(crate) fn u128(&mut self, v: u128) {
+        self.bytes(&v.to_le_bytes());
+    }
+
+    pub(crate) fn index(&mut self, v: usize) {
+        self.u64(v as u64);
+    }
+
+    pub(crate) fn u32s(&mut self, words: &[u32]) {
+        for &w in words {
+            self.u32(w);
+        }
+    }
+
+    pub(crate) fn u64s(&mut self, words: &[u64]) {
+        for &w in words {
+            self.u64(w);
+        }
+    }
+}
+
+/// Sequential reader over a snapshot whose length has already been checked.
+pub(crate) struct StateReader<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> StateReader<'a> {
+    /// Returns a reader over `input`, or an error if it is not exactly `len` bytes long.
+    pub(crate) fn new(input: &'a [u8], len: usize) -> Result<Self, AporiaError> {
+        if input.len() != len {
+            return Err(AporiaError::InvalidState("snapshot has the wrong length"));
+        }
+        Ok(Self { input, pos: 0 })
+    }
+
+    pub(crate) fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        out.copy_from_slice(&self.input[self.pos..self.pos + N]);
+        self.pos += N;
+        out
+    }
+
+    pub(crate) fn u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.bytes())
+    }
+
+    pub(crate) fn u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.bytes())
+    }
+
+    pub(crate) fn u128(&mut self) -> u128 {
+        u128::from_le_bytes(self.bytes())
+    }
+
+    /// Reads a buffer position, which must not exceed `max`.
+    pub(crate) fn index(&mut self, max: usize) -> Result<usize, AporiaError> {
+        let v = self.u64();
+        if v > max as u64 {
+            return Err(AporiaError::InvalidState("snapshot index is out of range"));
+        }
+        Ok(v as usize)
+    }
+
+    pub(crate) fn u32s<const N: usize>(&mut self) -> [u32; N] {
+        core::array::from_fn(|_| self.u32())
+    }
+
+    pub(crate) fn u64s<const N: usize>(&mut self) -> [u64; N] {
+        core::array::from_fn(|_| self.u64())
+    }
+}
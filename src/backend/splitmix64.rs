@@ -25,12 +25,18 @@
 //! - [Wikipedia: SplitMix64](https://en.wikipedia.org/wiki/SplitMix64)
 //! - [Steele, G.L., Vigna, S. (2019) "Computationally easy, spectrally pure pseudorandom number generators"](https://vigna.di.unimi.it/ftp/papers/SplitMix.pdf)
 
-use super::RandomBackend;
+use super::snapshot::{StateReader, StateWriter};
+use super::{AtomicBackend, RandomBackend, SeedableBackend, Splittable, StateSnapshot};
+
+/// The default increment ("gamma"): the odd integer closest to 2^64 / phi.
+const GOLDEN_GAMMA: u64 = 0x9E3779B97F4A7C15;
 
 /// SplitMix64 random number generator struct.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SplitMix64 {
     state: u64,
+    gamma: u64,
 }
 
 impl SplitMix64 {
@@ -39,21 +45,99 @@ impl SplitMix64 {
     /// # Arguments
     ///
     /// * `seed` - The initial seed value.
-    pub fn new(seed: u64) -> Self {
-        Self { state: seed }
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed, gamma: GOLDEN_GAMMA }
+    }
+}
+
+/// The SplitMix64 output finalizer (a variant of MurmurHash3's `fmix64`).
+///
+/// This is a bijection on `u64` that maps zero to zero.
+#[inline(always)]
+pub(crate) fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Derives a new odd gamma, avoiding values with too few bit transitions.
+fn mix_gamma(mut z: u64) -> u64 {
+    z = (z ^ (z >> 33)).wrapping_mul(0xFF51AFD7ED558CCD);
+    z = (z ^ (z >> 33)).wrapping_mul(0xC4CEB9FE1A85EC53);
+    z = (z ^ (z >> 33)) | 1;
+    if (z ^ (z >> 1)).count_ones() < 24 {
+        z ^ 0xAAAAAAAAAAAAAAAA
+    } else {
+        z
     }
 }
 
 impl RandomBackend for SplitMix64 {
     /// Generates the next random `u64` using the SplitMix64 algorithm.
     fn next_u64(&mut self) -> u64 {
-        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
-        let mut z = self.state;
-        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
-        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
-        z ^ (z >> 31)
+        self.state = self.state.wrapping_add(self.gamma);
+        mix64(self.state)
+    }
+}
+
+impl Splittable for SplitMix64 {
+    /// Splits off a child generator with its own seed and gamma.
+    ///
+    /// This follows Java's `SplittableRandom.split()`: the child's seed is the parent's
+    /// next output, and its gamma is derived from the parent's next raw state.
+    fn split(&mut self) -> Self {
+        let state = self.next_u64();
+        self.state = self.state.wrapping_add(self.gamma);
+        let gamma = mix_gamma(self.state);
+        Self { state, gamma }
+    }
+}
+
+impl SeedableBackend for SplitMix64 {
+    type Seed = [u8; 8];
+
+    /// Uses the seed bytes (little-endian) as the initial state.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
     }
-} 
+}
+
+impl AtomicBackend for SplitMix64 {
+    fn state_word(&self) -> u64 {
+        self.state
+    }
+
+    fn set_state_word(&mut self, word: u64) {
+        self.state = word;
+    }
+}
+
+/// Layout (16 bytes): `state` (u64), `gamma` (u64, odd).
+impl StateSnapshot for SplitMix64 {
+    type Bytes = [u8; 16];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 16];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.state);
+        w.u64(self.gamma);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 16)?;
+        let state = r.u64();
+        let gamma = r.u64();
+        if gamma & 1 == 0 {
+            return Err(crate::AporiaError::InvalidState("SplitMix64 gamma must be odd"));
+        }
+        Ok(Self { state, gamma })
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -66,4 +150,15 @@ mod tests {
         let b = sm.next_u64();
         assert_ne!(a, b);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn splitmix64_split_matches_java_splittable_random() {
+        // Values from java.util.SplittableRandom's algorithm: seed 0, split once.
+        let mut parent = SplitMix64::new(0);
+        let mut child = parent.split();
+        assert_eq!(child.state, 0xe220a8397b1dcdaf);
+        assert_eq!(child.gamma, 0xd30b054265133dd7);
+        assert_eq!(child.next_u64(), 0x184c6c53fb60892d);
+        assert_ne!(child.next_u64(), parent.next_u64());
+    }
+}
@@ -0,0 +1,241 @@
+//! Squares counter-based random number generator implementation.
+//!
+//! Squares (Widynski, 2020) derives each output from a 64-bit counter and a 64-bit key
+//! using a few rounds of middle-square mixing. Like [`crate::backend::Philox`] it offers
+//! random access to any position of the stream, but with a much smaller state and a
+//! simpler round function.
+//!
+//! Not every 64-bit value makes a good key. [`Squares::new`] derives a key with the
+//! properties recommended by the paper (non-zero, mostly distinct hexadecimal digits and an
+//! odd low digit); [`Squares::from_key_counter`] accepts any key as-is, for example one
+//! taken from the author's published key list.
+//!
+//! # Characteristics
+//!
+//! - State size: 16 bytes
+//! - Period: 2<sup>64</sup> outputs per key
+//! - Speed: Very Fast
+//! - Quality: Excellent (passes BigCrush and PractRand with recommended keys)
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Squares};
+//!
+//! let backend = Squares::new(2024);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//!
+//! // Random access: the value at index 10 is independent of draw order.
+//! let squares = Squares::new(2024);
+//! let tenth = squares.at(10);
+//! ```
+//!
+//! # References
+//!
+//! - [Widynski (2020), "Squares: A Fast Counter-Based RNG"](https://arxiv.org/abs/2004.06278)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{AtomicBackend, RandomBackend, SeedableBackend, StateSnapshot};
+use crate::backend::SplitMix64;
+
+/// Squares random number generator struct (64-bit output, five rounds).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Squares {
+    key: u64,
+    counter: u64,
+}
+
+impl Squares {
+    /// Creates a new `Squares` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value, used to derive a well-formed key.
+    ///
+    /// The counter starts at zero.
+    pub fn new(seed: u64) -> Self {
+        Self::from_key_counter(derive_key(seed), 0)
+    }
+
+    /// Creates a new `Squares` instance from an explicit key and starting counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key, used unchanged. Poorly chosen keys (such as zero) produce
+    ///   low-quality output.
+    /// * `counter` - The counter of the first value to be generated.
+    pub fn from_key_counter(key: u64, counter: u64) -> Self {
+        Self { key, counter }
+    }
+
+    /// Returns the current key.
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// Replaces the key, leaving the counter unchanged.
+    pub fn set_key(&mut self, key: u64) {
+        self.key = key;
+    }
+
+    /// Returns the counter of the next value to be generated.
+    pub fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Sets the counter of the next value to be generated.
+    pub fn set_counter(&mut self, counter: u64) {
+        self.counter = counter;
+    }
+
+    /// Returns the value at position `index` of the stream without changing any state.
+    ///
+    /// For a generator whose counter starts at zero, `at(i)` equals the `i`-th value
+    /// returned by `next_u64`.
+    pub fn at(&self, index: u64) -> u64 {
+        squares64(index, self.key)
+    }
+}
+
+/// Derives a key following the paper's recommendations from a `SplitMix64` stream.
+///
+/// Each 32-bit half is built from eight distinct non-zero hexadecimal digits, and the
+/// lowest digit is odd.
+fn derive_key(seed: u64) -> u64 {
+    let mut sm = SplitMix64::new(seed);
+    let mut half = |low: bool| -> u64 {
+        let mut digits: [u64; 15] = core::array::from_fn(|i| i as u64 + 1);
+        let mut word = 0u64;
+        for i in 0..8 {
+            // The lowest digit of the key is drawn from the odd digits only.
+            let odd_only = low && i == 0;
+            let pool = if odd_only { 8 } else { 15 - i };
+            let mut j = (sm.next_u64() % pool as u64) as usize;
+            if odd_only {
+                j *= 2;
+            } else {
+                j += i;
+            }
+            digits.swap(i, j);
+            word |= digits[i] << (4 * i);
+        }
+        word
+    };
+    let lo = half(true);
+    let hi = half(false);
+    (hi << 32) | lo
+}
+
+/// Computes the five-round `squares64` output for the given counter and key.
+#[inline(always)]
+fn squares64(ctr: u64, key: u64) -> u64 {
+    let y = ctr.wrapping_mul(key);
+    let z = y.wrapping_add(key);
+    let mut x = y;
+    x = x.wrapping_mul(x).wrapping_add(y).rotate_left(32);
+    x = x.wrapping_mul(x).wrapping_add(z).rotate_left(32);
+    x = x.wrapping_mul(x).wrapping_add(y).rotate_left(32);
+    let t = x.wrapping_mul(x).wrapping_add(z);
+    x = t.rotate_left(32);
+    t ^ (x.wrapping_mul(x).wrapping_add(y) >> 32)
+}
+
+impl RandomBackend for Squares {
+    /// Generates the next random `u64` and advances the counter.
+    fn next_u64(&mut self) -> u64 {
+        let v = squares64(self.counter, self.key);
+        self.counter = self.counter.wrapping_add(1);
+        v
+    }
+}
+
+impl SeedableBackend for Squares {
+    type Seed = [u8; 8];
+
+    /// Derives a well-formed key from the little-endian seed word, as `new` does.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl AtomicBackend for Squares {
+    fn state_word(&self) -> u64 {
+        self.counter
+    }
+
+    fn set_state_word(&mut self, word: u64) {
+        self.counter = word;
+    }
+}
+
+/// Layout (16 bytes): `key` (u64), `counter` (u64).
+impl StateSnapshot for Squares {
+    type Bytes = [u8; 16];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 16];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.key);
+        w.u64(self.counter);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 16)?;
+        let key = r.u64();
+        let counter = r.u64();
+        Ok(Self { key, counter })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squares64_matches_reference() {
+        // Values from the paper's reference squares64 routine with the example key.
+        let mut sq = Squares::from_key_counter(0x548c9decbce65297, 0);
+        assert_eq!(sq.next_u64(), 0x36d88366cee633a5);
+        assert_eq!(sq.next_u64(), 0x944716e00e60dfaa);
+        assert_eq!(sq.next_u64(), 0xc8a8f4e0678654bf);
+        assert_eq!(sq.next_u64(), 0x35cc666aab11c80d);
+        assert_eq!(sq.at(1_000_000), 0xb2712f4cc4f0d43e);
+    }
+
+    #[test]
+    fn derived_keys_are_well_formed() {
+        for seed in 0..100 {
+            let key = Squares::new(seed).key();
+            assert_eq!(key & 1, 1);
+            for half in [key as u32, (key >> 32) as u32] {
+                let mut seen = 0u16;
+                for d in 0..8 {
+                    let digit = (half >> (4 * d)) & 0xf;
+                    assert_ne!(digit, 0);
+                    assert_eq!(seen & (1 << digit), 0);
+                    seen |= 1 << digit;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn squares_set_counter_and_key() {
+        let mut sq = Squares::new(5);
+        let key = sq.key();
+        sq.set_counter(42);
+        assert_eq!(sq.next_u64(), Squares::from_key_counter(key, 0).at(42));
+        assert_eq!(sq.counter(), 43);
+
+        sq.set_key(0x548c9decbce65297);
+        sq.set_counter(0);
+        assert_eq!(sq.next_u64(), 0x36d88366cee633a5);
+    }
+}
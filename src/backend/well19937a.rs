@@ -0,0 +1,194 @@
+//! WELL19937a random number generator implementation.
+//!
+//! WELL (Well Equidistributed Long-period Linear) generators were designed by Panneton,
+//! L'Ecuyer, and Matsumoto as an improvement on the Mersenne Twister. WELL19937a has the
+//! same 2<sup>19937</sup>−1 period as MT19937 but better equidistribution and much faster
+//! recovery from states containing mostly zero bits. Not suitable for cryptographic
+//! purposes.
+//!
+//! # Characteristics
+//!
+//! - State size: 2.5 KB (624 * 4 bytes)
+//! - Period: 2<sup>19937</sup>−1
+//! - Speed: Fast
+//! - Quality: High
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Well19937a};
+//!
+//! let backend = Well19937a::new(5489);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u32();
+//! ```
+//!
+//! # References
+//!
+//! - [F. Panneton, P. L'Ecuyer, M. Matsumoto (2006), "Improved Long-Period Generators Based on Linear Recurrences Modulo 2"](https://www.iro.umontreal.ca/~lecuyer/myftp/papers/wellrng.pdf)
+//! - [Wikipedia: Well equidistributed long-period linear](https://en.wikipedia.org/wiki/Well_equidistributed_long-period_linear)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot};
+
+/// Number of 32-bit words in the state.
+const R: usize = 624;
+const M1: usize = 70;
+const M2: usize = 179;
+const M3: usize = 449;
+const MASK_UPPER: u32 = 0x8000_0000;
+const MASK_LOWER: u32 = 0x7fff_ffff;
+
+/// WELL19937a random number generator struct.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Well19937a {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    state: [u32; R],
+    index: usize,
+}
+
+impl Well19937a {
+    /// Creates a new `Well19937a` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value; only the low 32 bits are used.
+    ///
+    /// All 624 state words are filled with the MT19937 initialization recurrence
+    /// `s[i] = 1812433253 * (s[i-1] ^ (s[i-1] >> 30)) + i`, starting from `s[0] = seed`.
+    pub fn new(seed: u64) -> Self {
+        let mut state = [0u32; R];
+        state[0] = seed as u32;
+        for i in 1..R {
+            let prev = state[i - 1];
+            state[i] = 1_812_433_253u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(i as u32);
+        }
+        Self { state, index: 0 }
+    }
+
+    /// Creates a new `Well19937a` from a complete 624-word state, as in the reference
+    /// `InitWELLRNG19937a`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial state words.
+    ///
+    /// Returns an error if every word is zero, which is a fixed point of the generator.
+    pub fn from_state(state: [u32; R]) -> core::result::Result<Self, crate::AporiaError> {
+        if state.iter().all(|&w| w == 0) {
+            return Err(crate::AporiaError::InvalidSeed("WELL19937a state must not be all zero"));
+        }
+        Ok(Self { state, index: 0 })
+    }
+
+    /// Returns the state word at `offset` positions from the current index, modulo `R`.
+    #[inline(always)]
+    fn at(&self, offset: usize) -> u32 {
+        self.state[(self.index + offset) % R]
+    }
+}
+
+impl RandomBackend for Well19937a {
+    /// Generates the next random `u64` from two consecutive `u32` outputs.
+    ///
+    /// The first output forms the low half and the second the high half.
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// Generates the next random `u32` using the WELL19937a recurrence.
+    fn next_u32(&mut self) -> u32 {
+        let v0 = self.at(0);
+        let vm1 = self.at(M1);
+        let vm2 = self.at(M2);
+        let vm3 = self.at(M3);
+        let vrm1 = self.at(R - 1);
+        let vrm2 = self.at(R - 2);
+
+        let z0 = (vrm1 & MASK_UPPER) | (vrm2 & MASK_LOWER);
+        let z1 = (v0 ^ (v0 << 25)) ^ (vm1 ^ (vm1 >> 27));
+        let z2 = (vm2 >> 9) ^ (vm3 ^ (vm3 >> 1));
+        let new_v1 = z1 ^ z2;
+        let new_v0 = z0 ^ (z1 ^ (z1 << 9)) ^ (z2 ^ (z2 << 21)) ^ (new_v1 ^ (new_v1 >> 21));
+
+        let prev = (self.index + R - 1) % R;
+        self.state[self.index] = new_v1;
+        self.state[prev] = new_v0;
+        self.index = prev;
+        new_v0
+    }
+}
+
+impl SeedableBackend for Well19937a {
+    type Seed = [u8; 8];
+
+    /// Seeds with the little-endian seed word, as `new` does.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (2504 bytes): `state` (624 x u32, not all zero), `index` (u64, below 624).
+impl StateSnapshot for Well19937a {
+    type Bytes = [u8; 2504];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 2504];
+        let mut w = StateWriter::new(&mut out);
+        w.u32s(&self.state);
+        w.index(self.index);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 2504)?;
+        let state = r.u32s();
+        let index = r.index(R - 1)?;
+        if state.iter().all(|&w| w == 0) {
+            return Err(crate::AporiaError::InvalidState("WELL19937a state must not be all zero"));
+        }
+        Ok(Self { state, index })
+    }
+}
+
+impl core::fmt::Debug for Well19937a {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Avoid dumping large internal state; show only minimal info.
+        f.debug_struct("Well19937a")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well19937a_matches_reference() {
+        // Reference WELLRNG19937a recurrence over the state produced by `new(5489)`.
+        let mut well = Well19937a::new(5489);
+        let expected = [436613738, 2284173179, 3218077192, 4080368780, 3596990492, 1191717142];
+        for e in expected {
+            assert_eq!(well.next_u32(), e);
+        }
+        for _ in 0..10000 - expected.len() - 1 {
+            let _ = well.next_u32();
+        }
+        assert_eq!(well.next_u32(), 2010163703);
+    }
+
+    #[test]
+    fn well19937a_zero_state_rejected() {
+        assert!(Well19937a::from_state([0; R]).is_err());
+    }
+}
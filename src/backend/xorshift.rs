@@ -25,10 +25,18 @@
 //! - [George Marsaglia (2003), "Xorshift RNGs"](https://www.jstatsoft.org/article/view/v008i14/xorshift.pdf)
 //! - [Wikipedia: Xorshift](https://en.wikipedia.org/wiki/Xorshift)
 
-use super::RandomBackend;
+use super::snapshot::{StateReader, StateWriter};
+use super::{
+    AtomicBackend, RandomBackend, Reversible, SeedableBackend, StateSnapshot, unxorshift_left,
+    unxorshift_right,
+};
+
+/// State used in place of an all-zero seed, which would make the generator output only zeros.
+const ZERO_SEED_REPLACEMENT: u64 = 0x9E37_79B9_7F4A_7C15;
 
 /// XorShift random number generator struct.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XorShift {
     state: u64,
 }
@@ -41,7 +49,7 @@ impl XorShift {
     /// * `seed` - The initial seed value.
     ///
     /// Returns a new `XorShift` instance, or an error if the seed is invalid.
-    pub fn try_new(seed: u64) -> core::result::Result<Self, crate::AporiaError> {
+    pub const fn try_new(seed: u64) -> core::result::Result<Self, crate::AporiaError> {
         if seed == 0 {
             return Err(crate::AporiaError::InvalidSeed("XorShift seed must be non-zero"));
         }
@@ -50,7 +58,9 @@ impl XorShift {
 
     /// Creates a new `XorShift` and will panic on invalid seed.
     /// Only use when the seed is known-nonzero at call site.
-    pub fn new(seed: u64) -> Self {
+    ///
+    /// In a `static` initializer, a zero seed fails to compile instead of panicking.
+    pub const fn new(seed: u64) -> Self {
         // Safety: caller guarantees seed != 0 to avoid invalid state.
         if seed == 0 { panic!("invalid zero seed for XorShift"); }
         Self { state: seed }
@@ -69,6 +79,69 @@ impl RandomBackend for XorShift {
     }
 }
 
+impl SeedableBackend for XorShift {
+    type Seed = [u8; 8];
+
+    /// Uses the seed bytes (little-endian) as the state; an all-zero seed is replaced by a
+    /// fixed non-zero state.
+    fn from_seed(seed: Self::Seed) -> Self {
+        match Self::try_new(u64::from_le_bytes(seed)) {
+            Ok(rng) => rng,
+            Err(_) => Self::new(ZERO_SEED_REPLACEMENT),
+        }
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        match Self::try_new(seed) {
+            Ok(rng) => rng,
+            Err(_) => Self::new(ZERO_SEED_REPLACEMENT),
+        }
+    }
+}
+
+impl Reversible for XorShift {
+    /// Undoes the three xorshift steps in reverse order.
+    fn prev_u64(&mut self) -> u64 {
+        let x = self.state;
+        let mut prev = unxorshift_left(x, 17);
+        prev = unxorshift_right(prev, 7);
+        prev = unxorshift_left(prev, 13);
+        self.state = prev;
+        x
+    }
+}
+
+impl AtomicBackend for XorShift {
+    fn state_word(&self) -> u64 {
+        self.state
+    }
+
+    fn set_state_word(&mut self, word: u64) {
+        self.state = word;
+    }
+}
+
+/// Layout (8 bytes): `state` (u64, non-zero).
+impl StateSnapshot for XorShift {
+    type Bytes = [u8; 8];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 8];
+        let mut w = StateWriter::new(&mut out);
+        w.u64(self.state);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 8)?;
+        let state = r.u64();
+        if state == 0 {
+            return Err(crate::AporiaError::InvalidState("XorShift state must not be zero"));
+        }
+        Ok(Self { state })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +161,14 @@ mod tests {
     fn xorshift_zero_seed_panics() {
         assert!(XorShift::try_new(0).is_err());
     }
+
+    #[test]
+    fn xorshift_prev_undoes_next() {
+        let mut backend = XorShift::new(0xDEAD_BEEF);
+        let forward: [u64; 4] = core::array::from_fn(|_| backend.next_u64());
+        for &v in forward.iter().rev() {
+            assert_eq!(backend.prev_u64(), v);
+        }
+        assert_eq!(backend.state, 0xDEAD_BEEF);
+    }
 }
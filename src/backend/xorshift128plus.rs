@@ -0,0 +1,217 @@
+//! XorShift128+ random number generator implementation.
+//!
+//! XorShift128+ is the generator behind `Math.random()` in the major JavaScript engines
+//! (V8, SpiderMonkey, and JavaScriptCore). This implementation uses the shift triple
+//! `(23, 17, 26)` adopted by those engines, so sequences can be reproduced exactly when
+//! starting from the same internal state. Not suitable for cryptographic purposes.
+//!
+//! Note that engines differ in how they seed the state and convert outputs to doubles;
+//! use [`XorShift128Plus::from_state`] to start from a captured engine state.
+//!
+//! # Characteristics
+//!
+//! - State size: 16 bytes (2 * 8 bytes)
+//! - Period: 2<sup>128</sup>−1
+//! - Speed: Very Fast
+//! - Quality: Good (weak low bits)
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::XorShift128Plus};
+//!
+//! let backend = XorShift128Plus::new(2468);
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u64();
+//! ```
+//!
+//! # References
+//!
+//! - [Sebastiano Vigna (2017), "Further scramblings of Marsaglia's xorshift generators"](https://vigna.di.unimi.it/ftp/papers/xorshiftplus.pdf)
+//! - [V8 blog: There's Math.random(), and then there's Math.random()](https://v8.dev/blog/math-random)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{Jumpable, RandomBackend, SeedableBackend, StateSnapshot, words_from_le_bytes};
+use crate::backend::SplitMix64;
+
+/// Jump polynomial advancing the state by 2^64 steps.
+///
+/// Computed for the `(23, 17, 26)` shift triple; the constants published with the original
+/// xorshift128+ (shift triple `(23, 18, 5)`) do not apply to this engine.
+const JUMP: [u64; 2] = [0x8c405782bca686ad, 0xc44f35946fef49c6];
+
+/// Jump polynomial advancing the state by 2^96 steps.
+const LONG_JUMP: [u64; 2] = [0xeec5431970b882bc, 0x397adbe826b37b9e];
+
+/// XorShift128+ random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XorShift128Plus {
+    s: [u64; 2],
+}
+
+impl XorShift128Plus {
+    /// Creates a new `XorShift128Plus` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is expanded using `SplitMix64` to fill the state array.
+    pub fn new(seed: u64) -> Self {
+        let mut state = SplitMix64::new(seed);
+        let mut s = [state.next_u64(), state.next_u64()];
+        if s == [0, 0] {
+            s[0] = 1;
+        }
+        Self { s }
+    }
+
+    /// Creates a new `XorShift128Plus` from a raw state, e.g. one captured from a JS engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The two state words, `[state0, state1]`.
+    ///
+    /// Returns an error if both words are zero, which is a fixed point of the generator.
+    pub fn from_state(state: [u64; 2]) -> core::result::Result<Self, crate::AporiaError> {
+        if state == [0, 0] {
+            return Err(crate::AporiaError::InvalidSeed("XorShift128Plus state must not be all zero"));
+        }
+        Ok(Self { s: state })
+    }
+
+    /// Advances the state by 2<sup>64</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>64</sup> non-overlapping subsequences for
+    /// parallel computations.
+    pub fn jump(&mut self) {
+        self.apply_jump(&JUMP);
+    }
+
+    /// Advances the state by 2<sup>96</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>32</sup> starting points, from each of which
+    /// [`XorShift128Plus::jump`] will generate 2<sup>32</sup> non-overlapping subsequences.
+    pub fn long_jump(&mut self) {
+        self.apply_jump(&LONG_JUMP);
+    }
+
+    /// Replaces the state with `P(T) * s`, where `P` is the given jump polynomial.
+    fn apply_jump(&mut self, poly: &[u64; 2]) {
+        let mut t = [0u64; 2];
+        for &word in poly {
+            for b in 0..64 {
+                if word & (1u64 << b) != 0 {
+                    t[0] ^= self.s[0];
+                    t[1] ^= self.s[1];
+                }
+                let _ = self.next_u64();
+            }
+        }
+        self.s = t;
+    }
+}
+
+impl RandomBackend for XorShift128Plus {
+    /// Generates the next random `u64` using the XorShift128+ algorithm.
+    fn next_u64(&mut self) -> u64 {
+        let mut s1 = self.s[0];
+        let s0 = self.s[1];
+        self.s[0] = s0;
+        s1 ^= s1 << 23;
+        self.s[1] = s1 ^ s0 ^ (s1 >> 17) ^ (s0 >> 26);
+        self.s[1].wrapping_add(s0)
+    }
+}
+
+impl SeedableBackend for XorShift128Plus {
+    type Seed = [u8; 16];
+
+    /// Uses the seed bytes (little-endian words) as the state; an all-zero seed is replaced
+    /// by the state of `new(0)`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_state(words_from_le_bytes(&seed)).unwrap_or_else(|_| Self::new(0))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl Jumpable for XorShift128Plus {
+    fn jump(&mut self) {
+        XorShift128Plus::jump(self);
+    }
+
+    fn long_jump(&mut self) {
+        XorShift128Plus::long_jump(self);
+    }
+}
+
+/// Layout (16 bytes): `s` (2 x u64, not all zero).
+impl StateSnapshot for XorShift128Plus {
+    type Bytes = [u8; 16];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 16];
+        let mut w = StateWriter::new(&mut out);
+        w.u64s(&self.s);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 16)?;
+        let s = r.u64s();
+        if s == [0; 2] {
+            return Err(crate::AporiaError::InvalidState("XorShift128Plus state must not be all zero"));
+        }
+        Ok(Self { s })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xorshift128plus_matches_engine_reference() {
+        // Outputs of SpiderMonkey's XorShift128PlusRNG::next() with state {1, 2}.
+        let mut xs = XorShift128Plus::from_state([1, 2]).unwrap();
+        let expected = [
+            8388677,
+            33554692,
+            70368777736387,
+            211106267148357,
+            281509366091972,
+            360777324180299,
+        ];
+        for e in expected {
+            assert_eq!(xs.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn xorshift128plus_zero_state_rejected() {
+        assert!(XorShift128Plus::from_state([0, 0]).is_err());
+    }
+
+    #[test]
+    fn xorshift128plus_jump_known_state() {
+        let mut xs = XorShift128Plus::from_state([1, 2]).unwrap();
+        xs.jump();
+        assert_eq!(xs.next_u64(), 16174566094698472457);
+    }
+
+    #[test]
+    fn xorshift128plus_apply_jump_matches_sequential_steps() {
+        // The polynomial x^3 advances the state by exactly three steps.
+        let mut a = XorShift128Plus::new(11);
+        let mut b = a.clone();
+        a.apply_jump(&[1 << 3, 0]);
+        for _ in 0..3 {
+            let _ = b.next_u64();
+        }
+        assert_eq!(a.s, b.s);
+    }
+}
@@ -0,0 +1,150 @@
+//! XORWOW random number generator implementation.
+//!
+//! XORWOW is Marsaglia's xorshift generator combined with a Weyl sequence. It is the
+//! default generator of NVIDIA's cuRAND library (`curandStateXORWOW_t`). This
+//! implementation follows the state layout, seeding, and output function of
+//! `curand_init`/`curand` exactly, so sequences generated on the GPU can be verified on
+//! the CPU. Not suitable for cryptographic purposes.
+//!
+//! Only subsequence 0 and offset 0 of `curand_init` are supported; cuRAND's precomputed
+//! skip-ahead matrices are not included.
+//!
+//! # Characteristics
+//!
+//! - State size: 24 bytes (6 * 4 bytes)
+//! - Period: 2<sup>192</sup>−2<sup>32</sup>
+//! - Speed: Very Fast
+//! - Quality: Basic
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::XorWow};
+//!
+//! let backend = XorWow::new(1234); // same as curand_init(1234, 0, 0, &state)
+//! let mut rng = Rng::new(backend);
+//! let random_number = rng.next_u32();
+//! ```
+//!
+//! # References
+//!
+//! - [George Marsaglia (2003), "Xorshift RNGs"](https://www.jstatsoft.org/article/view/v008i14/xorshift.pdf)
+//! - [cuRAND library documentation](https://docs.nvidia.com/cuda/curand/)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{RandomBackend, SeedableBackend, StateSnapshot};
+
+/// XORWOW random number generator struct, laid out like `curandStateXORWOW_t`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XorWow {
+    d: u32,
+    v: [u32; 5],
+}
+
+impl XorWow {
+    /// Creates a new `XorWow` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value, as passed to `curand_init`.
+    pub fn new(seed: u64) -> Self {
+        let s0 = (seed as u32) ^ 0xaad2_6b49;
+        let s1 = ((seed >> 32) as u32) ^ 0xf7dc_efdd;
+        let t0 = 1_099_087_573u32.wrapping_mul(s0);
+        let t1 = 2_591_861_531u32.wrapping_mul(s1);
+        Self {
+            d: 6_615_241u32.wrapping_add(t1).wrapping_add(t0),
+            v: [
+                123_456_789u32.wrapping_add(t0),
+                362_436_069u32 ^ t0,
+                521_288_629u32.wrapping_add(t1),
+                88_675_123u32 ^ t1,
+                5_783_321u32.wrapping_add(t0),
+            ],
+        }
+    }
+}
+
+impl RandomBackend for XorWow {
+    /// Generates the next random `u64` from two consecutive `u32` outputs.
+    ///
+    /// The first output forms the low half and the second the high half.
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// Generates the next random `u32`, identical to `curand(&state)`.
+    fn next_u32(&mut self) -> u32 {
+        let t = self.v[0] ^ (self.v[0] >> 2);
+        self.v[0] = self.v[1];
+        self.v[1] = self.v[2];
+        self.v[2] = self.v[3];
+        self.v[3] = self.v[4];
+        self.v[4] = (self.v[4] ^ (self.v[4] << 4)) ^ (t ^ (t << 1));
+        self.d = self.d.wrapping_add(362_437);
+        self.v[4].wrapping_add(self.d)
+    }
+}
+
+impl SeedableBackend for XorWow {
+    type Seed = [u8; 8];
+
+    /// Seeds like `curand_init` with the little-endian seed word.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// Layout (24 bytes): `d` (u32), `v` (5 x u32, not all zero).
+impl StateSnapshot for XorWow {
+    type Bytes = [u8; 24];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 24];
+        let mut w = StateWriter::new(&mut out);
+        w.u32(self.d);
+        w.u32s(&self.v);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 24)?;
+        let d = r.u32();
+        let v = r.u32s();
+        if v == [0; 5] {
+            return Err(crate::AporiaError::InvalidState("XorWow state must not be all zero"));
+        }
+        Ok(Self { d, v })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xorwow_matches_curand_algorithm() {
+        // Outputs of the curand_kernel.h XORWOW recurrence after curand_init(1234, 0, 0).
+        let mut xw = XorWow::new(1234);
+        let expected = [624778773, 1867875844, 3739671282, 1954919316, 3681184389, 2501467243];
+        for e in expected {
+            assert_eq!(xw.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn xorwow_u64_combines_two_draws() {
+        let mut a = XorWow::new(0);
+        let mut b = XorWow::new(0);
+        let lo = u64::from(a.next_u32());
+        let hi = u64::from(a.next_u32());
+        assert_eq!(b.next_u64(), (hi << 32) | lo);
+    }
+}
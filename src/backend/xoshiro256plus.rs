@@ -0,0 +1,203 @@
+//! Xoshiro256+ random number generator implementation.
+//!
+//! Xoshiro256+ uses the xoshiro256 linear engine with the cheapest possible scrambler, a
+//! single addition. It is the fastest member of the family and is intended primarily for
+//! generating floating-point numbers. Not suitable for cryptographic purposes.
+//!
+//! # Weak Low Bits
+//!
+//! The lowest bits of each output have low linear complexity (the lowest bit is an LFSR
+//! and fails linearity tests). The upper 53 bits used by `next_f64` are unaffected, so
+//! float generation is excellent; prefer [`crate::backend::Xoshiro256PlusPlus`] or
+//! [`crate::backend::Xoshiro256StarStar`] when raw 64-bit integers are needed.
+//!
+//! # Characteristics
+//!
+//! - State size: 32 bytes (4 * 8 bytes)
+//! - Period: 2<sup>256</sup>−1
+//! - Speed: Very Fast
+//! - Quality: Excellent for floats, weak low bits
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Xoshiro256Plus};
+//!
+//! let backend = Xoshiro256Plus::new(13579);
+//! let mut rng = Rng::new(backend);
+//! let random_float = rng.next_f64();
+//! ```
+//!
+//! # References
+//!
+//! - [David Blackman and Sebastiano Vigna (2021), "Scrambled Linear Pseudorandom Number Generators"](https://vigna.di.unimi.it/ftp/papers/ScrambledLinear.pdf)
+//! - [xoshiro256+ reference C implementation](https://prng.di.unimi.it/xoshiro256plus.c)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{Jumpable, RandomBackend, Reversible, SeedableBackend, StateSnapshot, words_from_le_bytes};
+use super::xoshiro256starstar;
+use crate::backend::SplitMix64;
+
+/// Xoshiro256+ random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xoshiro256Plus {
+    s: [u64; 4],
+}
+
+impl Xoshiro256Plus {
+    /// Creates a new `Xoshiro256Plus` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is expanded using `SplitMix64` to fill the state array.
+    pub fn new(seed: u64) -> Self {
+        let mut state = SplitMix64::new(seed);
+        let mut s = [0u64; 4];
+
+        for x in s.iter_mut() {
+            *x = state.next_u64();
+        }
+
+        Self { s }
+    }
+
+    /// Advances the state by 2<sup>128</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>128</sup> non-overlapping subsequences for
+    /// parallel computations.
+    pub fn jump(&mut self) {
+        xoshiro256starstar::apply_jump(&mut self.s, &xoshiro256starstar::JUMP);
+    }
+
+    /// Advances the state by 2<sup>192</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>64</sup> starting points, from each of which
+    /// [`Xoshiro256Plus::jump`] will generate 2<sup>64</sup> non-overlapping subsequences.
+    pub fn long_jump(&mut self) {
+        xoshiro256starstar::apply_jump(&mut self.s, &xoshiro256starstar::LONG_JUMP);
+    }
+}
+
+impl RandomBackend for Xoshiro256Plus {
+    /// Generates the next random `u64` using the Xoshiro256+ algorithm.
+    ///
+    /// The low bits of the result are weak; see the module documentation.
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[0].wrapping_add(self.s[3]);
+        let t = self.s[1] << 17;
+
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+
+        result
+    }
+
+    /// Generates a random floating-point number in the range [0, 1).
+    ///
+    /// Uses only the upper 53 bits of the output, as recommended for this generator,
+    /// so the weak low bits never reach the result.
+    fn next_f64(&mut self) -> f64 {
+        let val = self.next_u64() >> 11;
+        (val as f64) * (1.0 / ((1u64 << 53) as f64))
+    }
+
+    /// Generates the next 32-bit unsigned integer from the strong upper bits.
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+}
+
+impl SeedableBackend for Xoshiro256Plus {
+    type Seed = [u8; 32];
+
+    /// Uses the seed bytes (little-endian words) as the state; an all-zero seed is replaced
+    /// by the state of `new(0)`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let s = words_from_le_bytes(&seed);
+        if s == [0; 4] {
+            return Self::new(0);
+        }
+        Self { s }
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl Jumpable for Xoshiro256Plus {
+    fn jump(&mut self) {
+        Xoshiro256Plus::jump(self);
+    }
+
+    fn long_jump(&mut self) {
+        Xoshiro256Plus::long_jump(self);
+    }
+}
+
+impl Reversible for Xoshiro256Plus {
+    fn prev_u64(&mut self) -> u64 {
+        xoshiro256starstar::retreat(&mut self.s);
+        self.s[0].wrapping_add(self.s[3])
+    }
+}
+
+/// Layout (32 bytes): `s` (4 x u64, not all zero).
+impl StateSnapshot for Xoshiro256Plus {
+    type Bytes = [u8; 32];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 32];
+        let mut w = StateWriter::new(&mut out);
+        w.u64s(&self.s);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 32)?;
+        let s = r.u64s();
+        if s == [0; 4] {
+            return Err(crate::AporiaError::InvalidState("Xoshiro256Plus state must not be all zero"));
+        }
+        Ok(Self { s })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro256plus_matches_reference() {
+        // Outputs of the reference C implementation with state {1, 2, 3, 4}.
+        let mut xo = Xoshiro256Plus { s: [1, 2, 3, 4] };
+        let expected = [
+            5,
+            211106232532999,
+            211106635186183,
+            9223759065350669058,
+            9250833439874351877,
+            13862484359527728515,
+        ];
+        for e in expected {
+            assert_eq!(xo.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn xoshiro256plus_f64_in_unit_interval() {
+        let mut xo = Xoshiro256Plus::new(42);
+        for _ in 0..1000 {
+            let x = xo.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+}
@@ -25,11 +25,70 @@
 //! - [Sebastiano Vigna (2018), "Xoshiro/Xoroshiro generators and the PRNG shootout"](http://xoshiro.di.unimi.it/)
 //! - [Wikipedia: xorshift](https://en.wikipedia.org/wiki/Xorshift#xorshift*)
 
-use super::RandomBackend;
+use super::snapshot::{StateReader, StateWriter};
+use super::{Jumpable, RandomBackend, Reversible, SeedableBackend, StateSnapshot, unxorshift_left, words_from_le_bytes};
 use crate::backend::SplitMix64;
 
+/// Jump polynomial advancing the xoshiro256 state by 2^128 steps.
+pub(super) const JUMP: [u64; 4] = [
+    0x180ec6d33cfd0aba,
+    0xd5a61266f0c9392c,
+    0xa9582618e03fc9aa,
+    0x39abdc4529b1661c,
+];
+
+/// Jump polynomial advancing the xoshiro256 state by 2^192 steps.
+pub(super) const LONG_JUMP: [u64; 4] = [
+    0x76e15d3efefdcbbf,
+    0xc5004e441c522fb3,
+    0x77710069854ee241,
+    0x39109bb02acbe635,
+];
+
+/// Advances the xoshiro256 linear engine by one step.
+///
+/// All xoshiro256 variants share this engine and differ only in their output scrambler.
+pub(super) fn advance(s: &mut [u64; 4]) {
+    let t = s[1] << 17;
+
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+
+    s[2] ^= t;
+    s[3] = s[3].rotate_left(45);
+}
+
+/// Steps the xoshiro256 linear engine back by one step, undoing [`advance`].
+pub(super) fn retreat(s: &mut [u64; 4]) {
+    // After a step, s1 ^ s2 = s1' ^ (s1' << 17) for the previous s1'.
+    let s3 = s[3].rotate_right(45);
+    let s1 = unxorshift_left(s[1] ^ s[2], 17);
+    let s0 = s[0] ^ s3;
+    let s2 = s[1] ^ s1 ^ s0;
+    *s = [s0, s1, s2, s3 ^ s1];
+}
+
+/// Replaces the xoshiro256 state `s` with `P(T) * s`, where `P` is the given jump polynomial.
+pub(super) fn apply_jump(s: &mut [u64; 4], poly: &[u64; 4]) {
+    let mut t = [0u64; 4];
+    for &word in poly {
+        for b in 0..64 {
+            if word & (1u64 << b) != 0 {
+                for (acc, x) in t.iter_mut().zip(s.iter()) {
+                    *acc ^= *x;
+                }
+            }
+            advance(s);
+        }
+    }
+    *s = t;
+}
+
 /// Xoshiro256\*\* random number generator struct.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Xoshiro256StarStar {
     s: [u64; 4],
 }
@@ -61,7 +120,23 @@ impl Xoshiro256StarStar {
     /// * `x` - The value to rotate.
     /// * `k` - The number of bits to rotate left.
     fn rotl(x: u64, k: u32) -> u64 {
-        (x << k) | (x >> (64 - k))
+        x.rotate_left(k)
+    }
+
+    /// Advances the state by 2<sup>128</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>128</sup> non-overlapping subsequences for
+    /// parallel computations.
+    pub fn jump(&mut self) {
+        apply_jump(&mut self.s, &JUMP);
+    }
+
+    /// Advances the state by 2<sup>192</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>64</sup> starting points, from each of which
+    /// [`Xoshiro256StarStar::jump`] will generate 2<sup>64</sup> non-overlapping subsequences.
+    pub fn long_jump(&mut self) {
+        apply_jump(&mut self.s, &LONG_JUMP);
     }
 }
 
@@ -83,6 +158,62 @@ impl RandomBackend for Xoshiro256StarStar {
     }
 } 
 
+impl SeedableBackend for Xoshiro256StarStar {
+    type Seed = [u8; 32];
+
+    /// Uses the seed bytes (little-endian words) as the state; an all-zero seed is replaced
+    /// by the state of `new(0)`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let s = words_from_le_bytes(&seed);
+        if s == [0; 4] {
+            return Self::new(0);
+        }
+        Self { s }
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl Jumpable for Xoshiro256StarStar {
+    fn jump(&mut self) {
+        Xoshiro256StarStar::jump(self);
+    }
+
+    fn long_jump(&mut self) {
+        Xoshiro256StarStar::long_jump(self);
+    }
+}
+
+impl Reversible for Xoshiro256StarStar {
+    fn prev_u64(&mut self) -> u64 {
+        retreat(&mut self.s);
+        Self::rotl(self.s[1].wrapping_mul(5), 7).wrapping_mul(9)
+    }
+}
+
+/// Layout (32 bytes): `s` (4 x u64, not all zero).
+impl StateSnapshot for Xoshiro256StarStar {
+    type Bytes = [u8; 32];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 32];
+        let mut w = StateWriter::new(&mut out);
+        w.u64s(&self.s);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 32)?;
+        let s = r.u64s();
+        if s == [0; 4] {
+            return Err(crate::AporiaError::InvalidState("Xoshiro256StarStar state must not be all zero"));
+        }
+        Ok(Self { s })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,4 +225,36 @@ mod tests {
         let b = xo.next_u64();
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn xoshiro256starstar_jump_known_state() {
+        let mut xo = Xoshiro256StarStar { s: [1, 2, 3, 4] };
+        xo.jump();
+        assert_eq!(xo.next_u64(), 13534147089533256664);
+    }
+
+    #[test]
+    fn xoshiro256_apply_jump_matches_sequential_steps() {
+        // The polynomial x^5 advances the state by exactly five steps.
+        let mut a = Xoshiro256StarStar::new(7);
+        let mut b = a.clone();
+        apply_jump(&mut a.s, &[1 << 5, 0, 0, 0]);
+        for _ in 0..5 {
+            let _ = b.next_u64();
+        }
+        assert_eq!(a.s, b.s);
+    }
+
+    #[test]
+    fn xoshiro256_retreat_undoes_advance() {
+        let start = Xoshiro256StarStar::new(5).s;
+        let mut s = start;
+        for _ in 0..10 {
+            advance(&mut s);
+        }
+        for _ in 0..10 {
+            retreat(&mut s);
+        }
+        assert_eq!(s, start);
+    }
 }
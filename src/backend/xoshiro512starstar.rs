@@ -0,0 +1,256 @@
+//! Xoshiro512\*\* random number generator implementation.
+//!
+//! Xoshiro512\*\* is the large-state sibling of Xoshiro256\*\*. Its eight-word state gives a
+//! period of 2<sup>512</sup>−1, and its jump functions advance the state by 2<sup>256</sup>
+//! or 2<sup>384</sup> steps, which leaves room for an enormous number of non-overlapping
+//! subsequences in massive parallel ensembles. Not suitable for cryptographic purposes.
+//!
+//! # Characteristics
+//!
+//! - State size: 64 bytes (8 * 8 bytes)
+//! - Period: 2<sup>512</sup>−1
+//! - Speed: Very Fast
+//! - Quality: Excellent
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Xoshiro512StarStar};
+//!
+//! let mut worker0 = Xoshiro512StarStar::new(13579);
+//! let mut worker1 = worker0.clone();
+//! worker1.jump(); // 2^256 draws ahead of worker0
+//!
+//! let mut rng = Rng::new(worker1);
+//! let random_number = rng.next_u64();
+//! ```
+//!
+//! # References
+//!
+//! - [David Blackman and Sebastiano Vigna (2021), "Scrambled Linear Pseudorandom Number Generators"](https://vigna.di.unimi.it/ftp/papers/ScrambledLinear.pdf)
+//! - [xoshiro512\*\* reference C implementation](https://prng.di.unimi.it/xoshiro512starstar.c)
+
+use super::snapshot::{StateReader, StateWriter};
+use super::{Jumpable, RandomBackend, Reversible, SeedableBackend, StateSnapshot, words_from_le_bytes};
+use crate::backend::SplitMix64;
+
+/// Jump polynomial advancing the state by 2^256 steps.
+const JUMP: [u64; 8] = [
+    0x33ed89b6e7a353f9,
+    0x760083d7955323be,
+    0x2837f2fbb5f22fae,
+    0x4b8c5674d309511c,
+    0xb11ac47a7ba28c25,
+    0xf1be7667092bcc1c,
+    0x53851efdb6df0aaf,
+    0x1ebbc8b23eaf25db,
+];
+
+/// Jump polynomial advancing the state by 2^384 steps.
+const LONG_JUMP: [u64; 8] = [
+    0x11467fef8f921d28,
+    0xa2a819f2e79c8ea8,
+    0xa8299fc284b3959a,
+    0xb4d347340ca63ee1,
+    0x1cb0940bedbff6ce,
+    0xd956c5c4fa1f8e17,
+    0x915e38fd4eda93bc,
+    0x5b3ccdfa5d7daca5,
+];
+
+/// Xoshiro512\*\* random number generator struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xoshiro512StarStar {
+    s: [u64; 8],
+}
+
+impl Xoshiro512StarStar {
+    /// Creates a new `Xoshiro512StarStar` instance with the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The initial seed value.
+    ///
+    /// The seed is expanded using `SplitMix64` to fill the state array.
+    pub fn new(seed: u64) -> Self {
+        let mut state = SplitMix64::new(seed);
+        let mut s = [0u64; 8];
+
+        for x in s.iter_mut() {
+            *x = state.next_u64();
+        }
+
+        Self { s }
+    }
+
+    /// Advances the state by 2<sup>256</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>256</sup> non-overlapping subsequences for
+    /// parallel computations.
+    pub fn jump(&mut self) {
+        self.apply_jump(&JUMP);
+    }
+
+    /// Advances the state by 2<sup>384</sup> steps.
+    ///
+    /// This can be used to generate 2<sup>128</sup> starting points, from each of which
+    /// [`Xoshiro512StarStar::jump`] will generate 2<sup>128</sup> non-overlapping subsequences.
+    pub fn long_jump(&mut self) {
+        self.apply_jump(&LONG_JUMP);
+    }
+
+    /// Replaces the state with `P(T) * s`, where `P` is the given jump polynomial.
+    fn apply_jump(&mut self, poly: &[u64; 8]) {
+        let mut t = [0u64; 8];
+        for &word in poly {
+            for b in 0..64 {
+                if word & (1u64 << b) != 0 {
+                    for (acc, s) in t.iter_mut().zip(self.s.iter()) {
+                        *acc ^= *s;
+                    }
+                }
+                let _ = self.next_u64();
+            }
+        }
+        self.s = t;
+    }
+}
+
+impl RandomBackend for Xoshiro512StarStar {
+    /// Generates the next random `u64` using the Xoshiro512\*\* algorithm.
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 11;
+
+        self.s[2] ^= self.s[0];
+        self.s[5] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[7] ^= self.s[3];
+        self.s[3] ^= self.s[4];
+        self.s[4] ^= self.s[5];
+        self.s[0] ^= self.s[6];
+        self.s[6] ^= self.s[7];
+
+        self.s[6] ^= t;
+        self.s[7] = self.s[7].rotate_left(21);
+
+        result
+    }
+}
+
+impl SeedableBackend for Xoshiro512StarStar {
+    type Seed = [u8; 64];
+
+    /// Uses the seed bytes (little-endian words) as the state; an all-zero seed is replaced
+    /// by the state of `new(0)`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let s = words_from_le_bytes(&seed);
+        if s == [0; 8] {
+            return Self::new(0);
+        }
+        Self { s }
+    }
+
+    fn from_seed_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl Jumpable for Xoshiro512StarStar {
+    fn jump(&mut self) {
+        Xoshiro512StarStar::jump(self);
+    }
+
+    fn long_jump(&mut self) {
+        Xoshiro512StarStar::long_jump(self);
+    }
+}
+
+impl Reversible for Xoshiro512StarStar {
+    fn prev_u64(&mut self) -> u64 {
+        let s = self.s;
+        // After a step, s1 ^ s2 = s1' and s6 carries s1' << 11 for the previous s1'.
+        let s7 = s[7].rotate_right(21);
+        let s1 = s[1] ^ s[2];
+        let s5 = s[5] ^ s1;
+        let s4 = s[4] ^ s[5];
+        let s3 = s[3] ^ s4;
+        let s6 = s[6] ^ s7 ^ (s1 << 11);
+        let s0 = s[0] ^ s6;
+        let s2 = s[2] ^ s0;
+        self.s = [s0, s1, s2, s3, s4, s5, s6, s7 ^ s3];
+        self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+}
+
+/// Layout (64 bytes): `s` (8 x u64, not all zero).
+impl StateSnapshot for Xoshiro512StarStar {
+    type Bytes = [u8; 64];
+
+    fn to_state_bytes(&self) -> Self::Bytes {
+        let mut out = [0u8; 64];
+        let mut w = StateWriter::new(&mut out);
+        w.u64s(&self.s);
+        out
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> core::result::Result<Self, crate::AporiaError> {
+        let mut r = StateReader::new(bytes, 64)?;
+        let s = r.u64s();
+        if s == [0; 8] {
+            return Err(crate::AporiaError::InvalidState("Xoshiro512StarStar state must not be all zero"));
+        }
+        Ok(Self { s })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro512starstar_matches_reference() {
+        // Outputs of the reference C implementation with state {1, 2, ..., 8}.
+        let mut xo = Xoshiro512StarStar { s: [1, 2, 3, 4, 5, 6, 7, 8] };
+        let expected = [11520, 0, 23040, 23667840, 144955163520, 303992986974289920];
+        for e in expected {
+            assert_eq!(xo.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn xoshiro512starstar_jump_known_state() {
+        let mut xo = Xoshiro512StarStar { s: [1, 2, 3, 4, 5, 6, 7, 8] };
+        xo.jump();
+        assert_eq!(xo.next_u64(), 9855632635473413185);
+    }
+
+    #[test]
+    fn xoshiro512starstar_jump_commutes_with_step() {
+        let mut a = Xoshiro512StarStar::new(99);
+        let mut b = a.clone();
+        a.jump();
+        let _ = a.next_u64();
+        let _ = b.next_u64();
+        b.jump();
+        assert_eq!(a.next_u64(), b.next_u64());
+
+        let mut c = Xoshiro512StarStar::new(99);
+        let mut d = c.clone();
+        c.long_jump();
+        d.jump();
+        assert_ne!(c.next_u64(), d.next_u64());
+    }
+
+    #[test]
+    fn xoshiro512starstar_prev_undoes_next() {
+        let mut xo = Xoshiro512StarStar::new(8);
+        let start = xo.s;
+        let forward: [u64; 5] = core::array::from_fn(|_| xo.next_u64());
+        for &v in forward.iter().rev() {
+            assert_eq!(xo.prev_u64(), v);
+        }
+        assert_eq!(xo.s, start);
+    }
+}
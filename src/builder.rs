@@ -0,0 +1,136 @@
+//! Single configuration entry point for constructing generators.
+//!
+//! [`RngBuilder`] collects an [`Algorithm`], a seed source and an optional stream, and
+//! builds an [`Rng<AnyBackend>`]. Applications that read their generator settings from a
+//! configuration file can map them onto the builder instead of matching on backend types.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Algorithm, Rng};
+//!
+//! let mut rng = Rng::builder()
+//!     .algorithm(Algorithm::PCG)
+//!     .seed(42)
+//!     .stream(3)
+//!     .build()
+//!     .unwrap();
+//! let random_number = rng.next_u64();
+//! ```
+
+use crate::backend::{Algorithm, AnyBackend};
+use crate::{AporiaError, Rng};
+
+/// Where the builder takes its seed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SeedSource {
+    /// A fixed seed passed to [`crate::SeedableBackend::from_seed_u64`].
+    Fixed(u64),
+    /// The full byte seed read from the operating system.
+    Os,
+}
+
+/// Builder for an [`Rng<AnyBackend>`], created by [`Rng::builder`].
+///
+/// The defaults are [`Algorithm::ChaCha12`], seeded from the operating system, on the
+/// backend's default stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RngBuilder {
+    algorithm: Algorithm,
+    seed: SeedSource,
+    stream: Option<u64>,
+}
+
+impl RngBuilder {
+    /// Creates a builder with the default settings.
+    pub fn new() -> Self {
+        Self {
+            algorithm: Algorithm::ChaCha12,
+            seed: SeedSource::Os,
+            stream: None,
+        }
+    }
+
+    /// Selects the backend algorithm.
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Seeds the backend deterministically from `seed`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = SeedSource::Fixed(seed);
+        self
+    }
+
+    /// Seeds the backend deterministically from a label, via [`crate::seed::seed_from_str`].
+    pub fn seed_from_str(self, label: &str) -> Self {
+        self.seed(crate::seed::seed_from_str(label))
+    }
+
+    /// Seeds the backend from the operating system, which is the default.
+    pub fn os_seed(mut self) -> Self {
+        self.seed = SeedSource::Os;
+        self
+    }
+
+    /// Selects the stream, for algorithms that implement [`crate::Streamable`].
+    pub fn stream(mut self, stream: u64) -> Self {
+        self.stream = Some(stream);
+        self
+    }
+
+    /// Builds the configured generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::StreamsUnsupported`] if a stream was set for an algorithm
+    /// without streams, and [`AporiaError::EntropyUnavailable`] if OS seeding fails or the
+    /// `getrandom` feature is disabled.
+    pub fn build(self) -> core::result::Result<Rng<AnyBackend>, AporiaError> {
+        let mut backend = match self.seed {
+            SeedSource::Fixed(seed) => AnyBackend::from_seed_u64(self.algorithm, seed),
+            #[cfg(feature = "getrandom")]
+            SeedSource::Os => AnyBackend::from_os_seed(self.algorithm)?,
+            #[cfg(not(feature = "getrandom"))]
+            SeedSource::Os => {
+                return Err(AporiaError::EntropyUnavailable(
+                    "OS seeding requires the getrandom feature",
+                ));
+            }
+        };
+        if let Some(stream) = self.stream {
+            backend.set_stream(stream)?;
+        }
+        Ok(Rng::new(backend))
+    }
+}
+
+impl Default for RngBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RandomBackend, Streamable};
+    use crate::backend::{Philox, Xoshiro256StarStar};
+
+    #[test]
+    fn builder_matches_concrete_backends() {
+        let mut rng = Rng::builder()
+            .algorithm(Algorithm::Xoshiro256StarStar)
+            .seed(42)
+            .build()
+            .unwrap();
+        assert_eq!(rng.next_u64(), Xoshiro256StarStar::new(42).next_u64());
+
+        let mut rng = Rng::builder().algorithm(Algorithm::Philox).seed(7).stream(3).build().unwrap();
+        assert_eq!(rng.next_u64(), Philox::new(7).with_stream(3).next_u64());
+
+        let err = Rng::builder().algorithm(Algorithm::SplitMix64).seed(1).stream(1).build();
+        assert_eq!(err.unwrap_err(), AporiaError::StreamsUnsupported);
+    }
+}
@@ -0,0 +1,152 @@
+//! Categorical distribution over indices using Vose's alias method.
+//!
+//! The weights are rescaled so that their mean is 1 and then split into `n` columns of
+//! height 1, each holding at most two categories: column `i` keeps category `i` with
+//! probability `prob[i]` and otherwise yields `alias[i]`. Construction pairs one
+//! under-full category with one over-full category per step, in O(n); a sample picks a
+//! column uniformly and flips one biased coin, in O(1) regardless of `n`.
+//!
+//! See Vose, "A Linear Algorithm for Generating Random Numbers with a Given
+//! Distribution", IEEE Transactions on Software Engineering 17(9), 1991.
+
+use alloc::vec::Vec;
+
+use super::{Distribution, Uniform, weighted::check_weights};
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Distribution over `0..weights.len()` with the same probabilities as
+/// [`WeightedIndex`](super::WeightedIndex), sampled in constant time.
+///
+/// Prefer it for many categories sampled many times; [`WeightedIndex`](super::WeightedIndex)
+/// is cheaper to build and is faster for a handful of categories.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::WeightedAliasIndex};
+///
+/// let mut rng = Rng::new(SplitMix64::new(10));
+/// let weights: Vec<f64> = (1..=10_000).map(|i| 1.0 / f64::from(i)).collect();
+/// let zipf = WeightedAliasIndex::new(&weights).unwrap();
+/// let word = rng.sample(&zipf);
+/// assert!(word < 10_000);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedAliasIndex {
+    /// Probability that column `i` yields `i` itself.
+    prob: Vec<f64>,
+    /// Category yielded by column `i` otherwise.
+    alias: Vec<usize>,
+    column: Uniform<usize>,
+}
+
+impl WeightedAliasIndex {
+    /// Creates a distribution from non-negative weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`WeightedIndex::new`](super::WeightedIndex::new).
+    pub fn new(weights: &[f64]) -> Result<Self, AporiaError> {
+        let total = check_weights(weights)?;
+        let n = weights.len();
+        let scale = n as f64 / total;
+        let mut scaled: Vec<f64> = weights.iter().map(|&w| w * scale).collect();
+        let mut prob = alloc::vec![1.0; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| scaled[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] = (scaled[l] + scaled[s]) - 1.0;
+            if scaled[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // Whatever remains is full up to rounding error and keeps `prob = 1`, except that a
+        // zero weight must never be returned, so its column always defers to the heaviest.
+        let heaviest = (0..n).fold(0, |best, i| if weights[i] > weights[best] { i } else { best });
+        for i in small.into_iter().filter(|&i| weights[i] == 0.0) {
+            prob[i] = 0.0;
+            alias[i] = heaviest;
+        }
+
+        Ok(Self { prob, alias, column: Uniform::new(0..n)? })
+    }
+
+    /// Returns the number of categories.
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Returns `false`; a `WeightedAliasIndex` always has at least one category.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl Distribution<usize> for WeightedAliasIndex {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> usize {
+        let i = self.column.sample(rng);
+        if rng.next_f64() < self.prob[i] { i } else { self.alias[i] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+    use crate::distributions::WeightedIndex;
+
+    #[test]
+    fn fuzz_matches_weighted_index() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(111));
+        for _ in 0..50 {
+            let n = rng.gen_range(1..40usize).unwrap();
+            // Mix zero, tiny and large weights, keeping at least one positive.
+            let mut weights: Vec<f64> = (0..n)
+                .map(|_| match rng.gen_range(0..4).unwrap() {
+                    0 => 0.0,
+                    1 => rng.next_f64() * 1e-3,
+                    _ => rng.next_f64() * 100.0,
+                })
+                .collect();
+            weights[rng.gen_range(0..n).unwrap()] += 1.0;
+
+            let alias = WeightedAliasIndex::new(&weights).unwrap();
+            let binary = WeightedIndex::new(&weights).unwrap();
+            let draws = 40_000;
+            let (mut a, mut b) = (alloc::vec![0u32; n], alloc::vec![0u32; n]);
+            for _ in 0..draws {
+                a[alias.sample(&mut rng)] += 1;
+                b[binary.sample(&mut rng)] += 1;
+            }
+            for i in 0..n {
+                if weights[i] == 0.0 {
+                    assert_eq!(a[i], 0, "zero-weight category {i} was sampled");
+                }
+                let (fa, fb) = (f64::from(a[i]) / f64::from(draws), f64::from(b[i]) / f64::from(draws));
+                assert!((fa - fb).abs() < 0.015, "category {i} of {n}: {fa} vs {fb}");
+            }
+        }
+    }
+
+    #[test]
+    fn zero_weight_columns_never_keep_their_own_index() {
+        let long: Vec<f64> = (0..100_000).map(|i| if i % 3 == 0 { 0.0 } else { 1.0 + (i % 7) as f64 }).collect();
+        let cases: [&[f64]; 4] = [&[0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0], &[0.0, 1e-300, 0.0, 1e300], &[3.0, 0.0], &long];
+        for weights in cases {
+            let alias = WeightedAliasIndex::new(weights).unwrap();
+            for (i, &w) in weights.iter().enumerate() {
+                if w == 0.0 {
+                    assert_eq!(alias.prob[i], 0.0, "column {i}");
+                    assert!(weights[alias.alias[i]] > 0.0, "column {i}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Bernoulli distribution.
+//!
+//! A draw is `true` when a 64-bit value falls below `p * 2^64`. The threshold is computed
+//! once, so each sample costs a single `next_u64` and a comparison. Probabilities are
+//! resolved to a multiple of `2^-64`, far below the precision of any `f64` in `(0, 1)`
+//! that is not tiny.
+
+use super::Distribution;
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Threshold used for `p == 1.0`, which cannot be represented as `p * 2^64` in a `u64`.
+const ALWAYS_TRUE: u64 = u64::MAX;
+
+/// `2^64` as an `f64`.
+const SCALE: f64 = 2.0 * (1u64 << 63) as f64;
+
+/// Distribution yielding `true` with probability `p`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::{Bernoulli, Distribution}};
+///
+/// let mut rng = Rng::new(SplitMix64::new(1));
+/// let never = Bernoulli::new(0.0).unwrap();
+/// let always = Bernoulli::new(1.0).unwrap();
+/// assert!(!never.sample(&mut rng));
+/// assert!(always.sample(&mut rng));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bernoulli {
+    /// `p * 2^64`, or [`ALWAYS_TRUE`] for `p == 1.0`.
+    threshold: u64,
+}
+
+impl Bernoulli {
+    /// Creates a distribution that is `true` with probability `p`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] unless `0.0 <= p <= 1.0`.
+    pub fn new(p: f64) -> Result<Self, AporiaError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(AporiaError::InvalidParameter("probability must be in [0, 1]"));
+        }
+        let threshold = if p == 1.0 { ALWAYS_TRUE } else { (p * SCALE) as u64 };
+        Ok(Self { threshold })
+    }
+}
+
+impl Distribution<bool> for Bernoulli {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> bool {
+        if self.threshold == ALWAYS_TRUE {
+            return true;
+        }
+        rng.next_u64() < self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn frequency_matches_probability() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(12));
+        let dist = Bernoulli::new(0.25).unwrap();
+        let hits = (0..100_000).filter(|_| dist.sample(&mut rng)).count();
+        assert!((24_000..26_000).contains(&hits), "hits = {hits}");
+
+        assert!(Bernoulli::new(-0.1).is_err());
+        assert!(Bernoulli::new(f64::NAN).is_err());
+        assert_eq!(Bernoulli::new(0.5).unwrap().threshold, 1 << 63);
+    }
+
+    #[test]
+    fn gen_bool_frequency_with_pcg() {
+        use crate::backend::PCG;
+
+        // PCG builds each `u64` from two 32-bit steps; the threshold compares all 64 bits.
+        let mut rng = Rng::new(PCG::new(42, 54));
+        for (p, range) in [(0.5, 49_000..51_000), (0.001, 70..131)] {
+            let hits = (0..100_000).filter(|_| rng.gen_bool(p).unwrap()).count();
+            assert!(range.contains(&hits), "p = {p}, hits = {hits}");
+        }
+    }
+}
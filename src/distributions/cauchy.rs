@@ -0,0 +1,96 @@
+//! Cauchy distribution.
+//!
+//! Samples use the tangent transform `median + scale * tan(π (u - 1/2))` with `u` drawn
+//! from the open interval (0, 1). Centring `u` keeps the argument strictly inside
+//! `(-π/2, π/2)`, where the tangent is finite and monotonic, so `u = 1/2` maps exactly to
+//! the median instead of to the pole that `tan(π u)` has there.
+
+use core::f64::consts::PI;
+
+use super::{Distribution, math};
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// The Cauchy (Lorentz) distribution with the given median and scale.
+///
+/// It has no mean or variance: occasional samples are very far from the median, which
+/// makes it useful as heavy-tailed noise in robustness tests.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::Cauchy};
+///
+/// let mut rng = Rng::new(SplitMix64::new(8));
+/// let noise = Cauchy::new(0.0, 0.1).unwrap();
+/// let x: f64 = rng.sample(noise);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cauchy {
+    median: f64,
+    scale: f64,
+}
+
+impl Cauchy {
+    /// Creates a Cauchy distribution centred on `median` with half-width `scale`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] unless `median` is finite and `scale` is
+    /// finite and positive.
+    pub fn new(median: f64, scale: f64) -> Result<Self, AporiaError> {
+        if !median.is_finite() {
+            return Err(AporiaError::InvalidParameter("median must be finite"));
+        }
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(AporiaError::InvalidParameter("scale must be finite and positive"));
+        }
+        Ok(Self { median, scale })
+    }
+
+    /// Returns the median.
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    /// Returns the scale, the half-width at half-maximum of the density.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Distribution<f64> for Cauchy {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> f64 {
+        let u = rng.next_f64_open();
+        self.median + self.scale * math::tan(PI * (u - 0.5))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn quartiles_match_median_and_scale() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(101));
+        let dist = Cauchy::new(2.0, 3.0).unwrap();
+        let n = 200_000;
+        let (mut below_lower, mut below_median) = (0, 0);
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            assert!(x.is_finite());
+            below_lower += usize::from(x < 2.0 - 3.0);
+            below_median += usize::from(x < 2.0);
+        }
+        // The quartiles of a Cauchy distribution are `median ± scale`.
+        let lower = below_lower as f64 / n as f64;
+        let half = below_median as f64 / n as f64;
+        assert!((lower - 0.25).abs() < 0.005, "lower = {lower}");
+        assert!((half - 0.5).abs() < 0.005, "half = {half}");
+
+        assert!(Cauchy::new(0.0, 0.0).is_err());
+        assert!(Cauchy::new(f64::INFINITY, 1.0).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Adapters that derive new distributions from existing ones.
+//!
+//! They are created by the provided methods [`Distribution::map`], [`Distribution::zip`]
+//! and [`Distribution::filter`], and are distributions themselves, so they nest and work
+//! with [`Distribution::sample_iter`] like any other distribution.
+
+use core::marker::PhantomData;
+
+use super::Distribution;
+use crate::Rng;
+use crate::backend::RandomBackend;
+
+/// Distribution of `f(x)` for `x` drawn from `D`, created by [`Distribution::map`].
+pub struct Map<D, F, T> {
+    pub(super) dist: D,
+    pub(super) f: F,
+    pub(super) _marker: PhantomData<fn(T)>,
+}
+
+impl<T, U, D: Distribution<T>, F: Fn(T) -> U> Distribution<U> for Map<D, F, T> {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> U {
+        (self.f)(self.dist.sample(rng))
+    }
+}
+
+impl<D: Clone, F: Clone, T> Clone for Map<D, F, T> {
+    fn clone(&self) -> Self {
+        Self { dist: self.dist.clone(), f: self.f.clone(), _marker: PhantomData }
+    }
+}
+
+impl<D: core::fmt::Debug, F, T> core::fmt::Debug for Map<D, F, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Map").field("dist", &self.dist).finish_non_exhaustive()
+    }
+}
+
+/// Distribution of pairs drawn from two distributions in turn, created by
+/// [`Distribution::zip`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Zip<D1, D2> {
+    pub(super) first: D1,
+    pub(super) second: D2,
+}
+
+impl<T, U, D1: Distribution<T>, D2: Distribution<U>> Distribution<(T, U)> for Zip<D1, D2> {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> (T, U) {
+        let a = self.first.sample(rng);
+        (a, self.second.sample(rng))
+    }
+}
+
+/// Distribution of the values of `D` that satisfy a predicate, created by
+/// [`Distribution::filter`].
+///
+/// Rejected values are drawn again, so the expected cost of a sample is that of `D`
+/// divided by the probability of acceptance.
+#[derive(Clone, Copy)]
+pub struct Filter<D, P> {
+    pub(super) dist: D,
+    pub(super) predicate: P,
+}
+
+impl<T, D: Distribution<T>, P: Fn(&T) -> bool> Distribution<T> for Filter<D, P> {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> T {
+        loop {
+            let x = self.dist.sample(rng);
+            if (self.predicate)(&x) {
+                return x;
+            }
+        }
+    }
+}
+
+impl<D: core::fmt::Debug, P> core::fmt::Debug for Filter<D, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Filter").field("dist", &self.dist).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+    use crate::distributions::Uniform;
+
+    #[test]
+    fn adapters_transform_underlying_samples() {
+        let die = Uniform::new(1u32..=6).unwrap();
+        let mut a = Rng::new(SplitMix64::new(114));
+        let mut b = Rng::new(SplitMix64::new(114));
+
+        let doubled = die.map(|x| x * 2);
+        let pair = die.zip(doubled);
+        for _ in 0..100 {
+            let (x, y) = a.sample(&pair);
+            assert_eq!(x, b.sample(die));
+            assert_eq!(y, 2 * b.sample(die));
+        }
+
+        let even = die.filter(|x| x.is_multiple_of(2));
+        for _ in 0..100 {
+            let x = a.sample(even);
+            let expected = loop {
+                let y = b.sample(die);
+                if y.is_multiple_of(2) {
+                    break y;
+                }
+            };
+            assert_eq!(x, expected);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Dirichlet distribution.
+//!
+//! A sample draws one gamma variable per concentration parameter and divides each by their
+//! sum. When some parameter is below 1 all of the gamma variables can underflow to zero, so
+//! those distributions draw logarithms instead and normalize with the log-sum-exp trick.
+//!
+//! [`Dirichlet`] takes any number of parameters and returns a `Vec` (requires the `alloc`
+//! feature); [`DirichletArray`] fixes the dimension at compile time and returns an array.
+
+use super::{Distribution, gamma, math};
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Checks the concentration parameters and reports whether any is below 1.
+fn check_alphas(alphas: &[f64]) -> Result<bool, AporiaError> {
+    if alphas.len() < 2 {
+        return Err(AporiaError::InvalidParameter("at least two concentration parameters are required"));
+    }
+    if !alphas.iter().all(|&a| a.is_finite() && a > 0.0) {
+        return Err(AporiaError::InvalidParameter("concentration parameters must be finite and positive"));
+    }
+    Ok(alphas.iter().any(|&a| a < 1.0))
+}
+
+/// Fills `out` with one probability vector.
+fn sample_into<B: RandomBackend>(rng: &mut Rng<B>, alphas: &[f64], log_space: bool, out: &mut [f64]) {
+    if log_space {
+        let mut max = f64::NEG_INFINITY;
+        for (x, &alpha) in out.iter_mut().zip(alphas) {
+            *x = gamma::sample_ln_gamma(rng, alpha);
+            max = max.max(*x);
+        }
+        for x in out.iter_mut() {
+            *x = math::exp(*x - max);
+        }
+    } else {
+        for (x, &alpha) in out.iter_mut().zip(alphas) {
+            *x = gamma::sample_gamma(rng, alpha);
+        }
+    }
+    let sum: f64 = out.iter().sum();
+    for x in out.iter_mut() {
+        *x /= sum;
+    }
+}
+
+/// The Dirichlet distribution over probability vectors of any length.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::Dirichlet};
+///
+/// let mut rng = Rng::new(SplitMix64::new(2));
+/// let topics = Dirichlet::new(&[0.5, 0.5, 2.0, 4.0]).unwrap();
+/// let mix: Vec<f64> = rng.sample(&topics);
+/// assert!((mix.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dirichlet {
+    alphas: alloc::vec::Vec<f64>,
+    log_space: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl Dirichlet {
+    /// Creates a Dirichlet distribution with the given concentration parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] for fewer than two parameters or unless
+    /// every parameter is finite and positive.
+    pub fn new(alphas: &[f64]) -> Result<Self, AporiaError> {
+        let log_space = check_alphas(alphas)?;
+        Ok(Self { alphas: alphas.to_vec(), log_space })
+    }
+
+    /// Returns the concentration parameters.
+    pub fn alphas(&self) -> &[f64] {
+        &self.alphas
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<alloc::vec::Vec<f64>> for Dirichlet {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> alloc::vec::Vec<f64> {
+        let mut out = alloc::vec![0.0; self.alphas.len()];
+        sample_into(rng, &self.alphas, self.log_space, &mut out);
+        out
+    }
+}
+
+/// The Dirichlet distribution over probability vectors of a fixed length `N`, without
+/// allocation.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::DirichletArray};
+///
+/// let mut rng = Rng::new(SplitMix64::new(2));
+/// let weights = DirichletArray::new([1.0, 1.0, 1.0]).unwrap();
+/// let [a, b, c] = rng.sample(weights);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirichletArray<const N: usize> {
+    alphas: [f64; N],
+    log_space: bool,
+}
+
+impl<const N: usize> DirichletArray<N> {
+    /// Creates a Dirichlet distribution with the given concentration parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] if `N < 2` or unless every parameter is
+    /// finite and positive.
+    pub fn new(alphas: [f64; N]) -> Result<Self, AporiaError> {
+        let log_space = check_alphas(&alphas)?;
+        Ok(Self { alphas, log_space })
+    }
+
+    /// Returns the concentration parameters.
+    pub fn alphas(&self) -> &[f64; N] {
+        &self.alphas
+    }
+}
+
+impl<const N: usize> Distribution<[f64; N]> for DirichletArray<N> {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> [f64; N] {
+        let mut out = [0.0; N];
+        sample_into(rng, &self.alphas, self.log_space, &mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn component_means_match_normalized_alphas() {
+        let alphas = [0.2, 1.0, 3.0, 5.8];
+        let total: f64 = alphas.iter().sum();
+        let dist = DirichletArray::new(alphas).unwrap();
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(108));
+        let n = 50_000;
+        let mut sums = [0.0; 4];
+        for _ in 0..n {
+            let p = dist.sample(&mut rng);
+            assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            for (s, x) in sums.iter_mut().zip(p) {
+                assert!((0.0..=1.0).contains(&x));
+                *s += x;
+            }
+        }
+        for (s, a) in sums.iter().zip(alphas) {
+            assert!((s / n as f64 - a / total).abs() < 0.005, "mean = {}", s / n as f64);
+        }
+
+        // Tiny parameters underflow every gamma draw unless handled in log space.
+        let sparse = DirichletArray::new([1e-3; 3]).unwrap();
+        assert!(sparse.sample(&mut rng).iter().all(|x| x.is_finite()));
+
+        assert!(DirichletArray::new([1.0]).is_err());
+        assert!(DirichletArray::new([1.0, 0.0]).is_err());
+        #[cfg(feature = "alloc")]
+        {
+            assert!(Dirichlet::new(&[1.0, f64::NAN]).is_err());
+            assert_eq!(Dirichlet::new(&alphas).unwrap().sample(&mut rng).len(), 4);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Exponential distribution.
+
+use super::{Distribution, ziggurat};
+use crate::Rng;
+use crate::backend::RandomBackend;
+
+/// The exponential distribution with rate 1, sampled with the ziggurat method.
+///
+/// Divide a sample by `λ` for rate `λ`. Values are non-negative and have mean 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::Exp1};
+///
+/// let mut rng = Rng::new(SplitMix64::new(6));
+/// let wait: f64 = rng.sample(Exp1) / 0.25;
+/// assert!(wait >= 0.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Exp1;
+
+impl Distribution<f64> for Exp1 {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> f64 {
+        ziggurat::exp1(rng)
+    }
+}
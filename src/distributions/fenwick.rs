@@ -0,0 +1,283 @@
+//! Categorical distribution over indices whose weights can change between samples.
+//!
+//! The weights are kept in a Fenwick (binary indexed) tree, where node `j` stores the sum
+//! of the weights in `(j - lowbit(j), j]`. Changing one weight touches O(log n) nodes, and
+//! a sample walks down the tree from the largest power of two to find the first prefix sum
+//! above a uniform target, also in O(log n).
+//!
+//! Floating-point updates accumulate rounding error in the node sums, so the tree is
+//! rebuilt from the exact weights after every `n` updates, which keeps the amortized cost
+//! of an update at O(log n). An update that removes far more weight than remains is also
+//! followed by a rebuild, since subtracting it from the node sums would cancel away the
+//! smaller weights entirely.
+
+use alloc::vec::Vec;
+
+use super::Distribution;
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// An update that lowers the total by more than this multiple of the new total rebuilds the
+/// tree instead of adjusting it.
+const CANCELLATION_RATIO: f64 = 1024.0;
+
+/// Draws that land on no positive weight before sampling falls back to a linear scan.
+const MAX_TREE_DRAWS: usize = 64;
+
+/// Checks that `weight` is finite and non-negative.
+fn check_weight(weight: f64) -> Result<(), AporiaError> {
+    if weight.is_finite() && weight >= 0.0 {
+        Ok(())
+    } else {
+        Err(AporiaError::InvalidParameter("weights must be finite and non-negative"))
+    }
+}
+
+/// Distribution over `0..len` picking index `i` with probability proportional to its
+/// current weight, with weights that can be updated in place.
+///
+/// Sampling yields `None` once every weight is zero, which makes sampling without
+/// replacement a loop that zeroes each picked weight.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::DynamicWeightedIndex};
+///
+/// let mut rng = Rng::new(SplitMix64::new(12));
+/// let mut pool = DynamicWeightedIndex::new(&[5.0, 1.0, 3.0, 1.0]).unwrap();
+/// let mut order = Vec::new();
+/// while let Some(i) = rng.sample(&pool) {
+///     order.push(i);
+///     pool.update_weight(i, 0.0).unwrap();
+/// }
+/// assert_eq!(order.len(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicWeightedIndex {
+    weights: Vec<f64>,
+    /// One-based Fenwick tree over `weights`; `tree[0]` is unused.
+    tree: Vec<f64>,
+    total: f64,
+    /// Number of weights that are currently positive.
+    positive: usize,
+    /// Updates since the tree was last rebuilt.
+    updates: usize,
+}
+
+impl DynamicWeightedIndex {
+    /// Creates a distribution from non-negative weights.
+    ///
+    /// The weights may all be zero, or empty, in which case sampling yields `None` until a
+    /// weight is raised.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] if any weight is negative, NaN or
+    /// infinite, or if the total overflows.
+    pub fn new(weights: &[f64]) -> Result<Self, AporiaError> {
+        for &w in weights {
+            check_weight(w)?;
+        }
+        let mut dist = Self {
+            weights: weights.to_vec(),
+            tree: Vec::new(),
+            total: 0.0,
+            positive: 0,
+            updates: 0,
+        };
+        dist.rebuild();
+        if !dist.total.is_finite() {
+            return Err(AporiaError::InvalidParameter("total weight must be finite"));
+        }
+        Ok(dist)
+    }
+
+    /// Recomputes the tree, total and positive count from the weights in O(n).
+    fn rebuild(&mut self) {
+        let n = self.weights.len();
+        self.tree.clear();
+        self.tree.push(0.0);
+        self.tree.extend_from_slice(&self.weights);
+        for j in 1..=n {
+            let parent = j + (j & j.wrapping_neg());
+            if parent <= n {
+                self.tree[parent] += self.tree[j];
+            }
+        }
+        self.total = self.weights.iter().sum();
+        self.positive = self.weights.iter().filter(|&&w| w > 0.0).count();
+        self.updates = 0;
+    }
+
+    /// Sets the weight of index `i` to `weight`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] if `weight` is negative, NaN or infinite,
+    /// or if the new total would overflow; the distribution is unchanged in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.len()`.
+    pub fn update_weight(&mut self, i: usize, weight: f64) -> Result<(), AporiaError> {
+        check_weight(weight)?;
+        let old = self.weights[i];
+        let delta = weight - old;
+        if !(self.total + delta).is_finite() {
+            return Err(AporiaError::InvalidParameter("total weight must be finite"));
+        }
+        self.weights[i] = weight;
+        self.positive = self.positive + usize::from(weight > 0.0) - usize::from(old > 0.0);
+
+        self.updates += 1;
+        let total = self.total + delta;
+        if self.updates >= self.weights.len() || total <= 0.0 || -delta > CANCELLATION_RATIO * total {
+            self.rebuild();
+            return Ok(());
+        }
+        self.total = total;
+        let mut j = i + 1;
+        while j < self.tree.len() {
+            self.tree[j] += delta;
+            j += j & j.wrapping_neg();
+        }
+        Ok(())
+    }
+
+    /// Returns the current weight of index `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.len()`.
+    pub fn weight(&self, i: usize) -> f64 {
+        self.weights[i]
+    }
+
+    /// Returns the number of categories, including those with zero weight.
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Returns `true` if there are no categories.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Returns the sum of the current weights.
+    pub fn total_weight(&self) -> f64 {
+        self.total
+    }
+
+    /// Returns the first index whose prefix sum exceeds `target`, or `len` if none does.
+    fn find(&self, target: f64) -> usize {
+        let n = self.weights.len();
+        let mut pos = 0;
+        let mut rem = target;
+        let mut step = if n == 0 { 0 } else { 1 << n.ilog2() };
+        while step > 0 {
+            let next = pos + step;
+            if next <= n && self.tree[next] <= rem {
+                pos = next;
+                rem -= self.tree[next];
+            }
+            step >>= 1;
+        }
+        pos
+    }
+
+    /// Picks an index by scanning the exact weights, for when the tree sums have drifted.
+    fn scan<B: RandomBackend>(&self, rng: &mut Rng<B>) -> usize {
+        let total: f64 = self.weights.iter().sum();
+        let mut rem = rng.next_f64() * total;
+        let mut last = 0;
+        for (i, &w) in self.weights.iter().enumerate() {
+            if w > 0.0 {
+                if rem < w {
+                    return i;
+                }
+                rem -= w;
+                last = i;
+            }
+        }
+        last
+    }
+}
+
+impl Distribution<Option<usize>> for DynamicWeightedIndex {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> Option<usize> {
+        if self.positive == 0 {
+            return None;
+        }
+        // Rounding in the node sums can land a target past the end or on a zero weight;
+        // such draws are repeated a bounded number of times.
+        for _ in 0..MAX_TREE_DRAWS {
+            let i = self.find(rng.next_f64() * self.total);
+            if i < self.weights.len() && self.weights[i] > 0.0 {
+                return Some(i);
+            }
+        }
+        Some(self.scan(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn updates_change_frequencies() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(112));
+        let mut dist = DynamicWeightedIndex::new(&[1.0; 7]).unwrap();
+        dist.update_weight(2, 0.0).unwrap();
+        dist.update_weight(6, 4.0).unwrap();
+        dist.update_weight(0, 0.5).unwrap();
+        let weights = [0.5, 1.0, 0.0, 1.0, 1.0, 1.0, 4.0];
+        let total: f64 = weights.iter().sum();
+        assert_eq!(dist.total_weight(), total);
+
+        let n = 200_000;
+        let mut counts = [0u32; 7];
+        for _ in 0..n {
+            counts[dist.sample(&mut rng).unwrap()] += 1;
+        }
+        for (count, w) in counts.iter().zip(weights) {
+            let freq = f64::from(*count) / f64::from(n);
+            assert!((freq - w / total).abs() < 0.005, "freq = {freq}, weight = {w}");
+        }
+    }
+
+    #[test]
+    fn zeroing_picked_weights_samples_without_replacement() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(12));
+        let weights: Vec<f64> = (0..100).map(|i| 0.1 + f64::from(i) * 0.37).collect();
+        let mut dist = DynamicWeightedIndex::new(&weights).unwrap();
+        let mut seen = [false; 100];
+        while let Some(i) = dist.sample(&mut rng) {
+            assert!(!seen[i]);
+            seen[i] = true;
+            dist.update_weight(i, 0.0).unwrap();
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        assert_eq!(DynamicWeightedIndex::new(&[]).unwrap().sample(&mut rng), None);
+        assert!(dist.update_weight(0, -1.0).is_err());
+        assert!(DynamicWeightedIndex::new(&[f64::INFINITY]).is_err());
+    }
+
+    #[test]
+    fn removing_a_dominant_weight_keeps_the_rest() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(13));
+        let mut dist = DynamicWeightedIndex::new(&[1e20, 1.0]).unwrap();
+        dist.update_weight(0, 0.0).unwrap();
+        assert_eq!(dist.total_weight(), 1.0);
+        for _ in 0..100 {
+            assert_eq!(dist.sample(&mut rng), Some(1));
+        }
+
+        // Drifted node sums fall back to scanning the weights.
+        dist.tree.iter_mut().for_each(|s| *s = 0.0);
+        assert_eq!(dist.sample(&mut rng), Some(1));
+    }
+}
@@ -0,0 +1,81 @@
+//! Gamma sampling shared by the distributions built on it.
+//!
+//! Uses the squeeze method of Marsaglia and Tsang, "A Simple Method for Generating Gamma
+//! Variables", ACM TOMS 26(3), 2000: a cubed, shifted standard normal is accepted with
+//! probability above 98% for every shape of at least 1. Smaller shapes sample `shape + 1`
+//! and multiply by `u^(1 / shape)`.
+
+use super::{math, ziggurat};
+use crate::Rng;
+use crate::backend::RandomBackend;
+
+/// Draws from the gamma distribution with the given shape and scale 1.
+///
+/// `shape` must be finite and positive; callers validate it in their constructors.
+pub(super) fn sample_gamma<B: RandomBackend>(rng: &mut Rng<B>, shape: f64) -> f64 {
+    if shape < 1.0 {
+        let boost = math::powf(rng.next_f64_open(), 1.0 / shape);
+        return sample_gamma(rng, shape + 1.0) * boost;
+    }
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / math::sqrt(9.0 * d);
+    loop {
+        let x = ziggurat::standard_normal(rng);
+        let v = 1.0 + c * x;
+        if v <= 0.0 {
+            continue;
+        }
+        let v = v * v * v;
+        let u = rng.next_f64_open();
+        let x2 = x * x;
+        if u < 1.0 - 0.0331 * x2 * x2 || math::ln(u) < 0.5 * x2 + d * (1.0 - v + math::ln(v)) {
+            return d * v;
+        }
+    }
+}
+
+/// Draws the natural logarithm of a gamma variable with the given shape and scale 1.
+///
+/// For shapes below 1 the boost factor is applied in log space, so the result stays finite
+/// even when the variable itself would underflow to zero.
+pub(super) fn sample_ln_gamma<B: RandomBackend>(rng: &mut Rng<B>, shape: f64) -> f64 {
+    if shape < 1.0 {
+        let ln_boost = math::ln(rng.next_f64_open()) / shape;
+        return math::ln(sample_gamma(rng, shape + 1.0)) + ln_boost;
+    }
+    math::ln(sample_gamma(rng, shape))
+}
+
+/// Draws from the beta distribution with shapes `alpha` and `beta`, as `X / (X + Y)` for
+/// independent gamma variables.
+pub(super) fn sample_beta<B: RandomBackend>(rng: &mut Rng<B>, alpha: f64, beta: f64) -> f64 {
+    let x = sample_gamma(rng, alpha);
+    let y = sample_gamma(rng, beta);
+    x / (x + y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn gamma_moments_match_shape() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(104));
+        for shape in [0.3, 1.0, 2.5, 30.0] {
+            let n = 200_000;
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..n {
+                let x = sample_gamma(&mut rng, shape);
+                assert!(x >= 0.0);
+                sum += x;
+                sum_sq += x * x;
+            }
+            // Mean and variance of Gamma(k, 1) are both k.
+            let mean = sum / n as f64;
+            let var = sum_sq / n as f64 - mean * mean;
+            assert!((mean - shape).abs() < 0.02 * shape.max(1.0), "shape {shape}: mean = {mean}");
+            assert!((var - shape).abs() < 0.05 * shape.max(1.0), "shape {shape}: var = {var}");
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Uniform points on and inside the unit circle and sphere, and uniform rotations.
+//!
+//! All four samplers reject points of the enclosing square or cube that fall outside the
+//! unit disc, which accepts `π/4` of the proposals in two dimensions and `π/6` in three.
+//! [`UnitCircle`] then maps the accepted point onto the circle with von Neumann's
+//! squaring trick and [`UnitSphere`] with Marsaglia's method, so only the sphere needs a
+//! square root.
+//!
+//! [`UnitQuaternion`] uses Shoemake's subgroup algorithm, which needs no rejection.
+
+use super::Distribution;
+use crate::Rng;
+use crate::backend::RandomBackend;
+
+/// Draws a point uniformly from the unit disc, returning it with its squared norm.
+fn disc_point<B: RandomBackend>(rng: &mut Rng<B>) -> (f64, f64, f64) {
+    loop {
+        let (x, y) = (rng.next_f64_signed(), rng.next_f64_signed());
+        let r2 = x * x + y * y;
+        if r2 < 1.0 {
+            return (x, y, r2);
+        }
+    }
+}
+
+/// Uniform points on the unit circle, as `[x, y]` with `x² + y² = 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::UnitCircle};
+///
+/// let mut rng = Rng::new(SplitMix64::new(7));
+/// let [dx, dy] = rng.sample(UnitCircle);
+/// assert!((dx * dx + dy * dy - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitCircle;
+
+impl Distribution<[f64; 2]> for UnitCircle {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> [f64; 2] {
+        loop {
+            let (x, y, r2) = disc_point(rng);
+            // Squaring `x + iy` doubles its angle, which stays uniform, and dividing by the
+            // squared norm puts it on the circle. The origin has no direction.
+            if r2 > 0.0 {
+                return [(x * x - y * y) / r2, 2.0 * x * y / r2];
+            }
+        }
+    }
+}
+
+/// Uniform points inside the unit disc, as `[x, y]` with `x² + y² < 1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitDisc;
+
+impl Distribution<[f64; 2]> for UnitDisc {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> [f64; 2] {
+        let (x, y, _) = disc_point(rng);
+        [x, y]
+    }
+}
+
+/// Uniform points on the surface of the unit sphere, as `[x, y, z]` with
+/// `x² + y² + z² = 1`. Requires the `std` or `libm` feature for `sqrt`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::{Distribution, UnitSphere}};
+///
+/// let mut rng = Rng::new(SplitMix64::new(7));
+/// let directions: Vec<[f64; 3]> = UnitSphere.sample_iter(&mut rng).take(100).collect();
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitSphere;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Distribution<[f64; 3]> for UnitSphere {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> [f64; 3] {
+        let (x, y, r2) = disc_point(rng);
+        let scale = 2.0 * super::math::sqrt(1.0 - r2);
+        [x * scale, y * scale, 1.0 - 2.0 * r2]
+    }
+}
+
+/// Uniform random rotations in 3D, as unit quaternions `[x, y, z, w]` with the scalar part
+/// last. Requires the `std` or `libm` feature for `sqrt` and `sin_cos`.
+///
+/// The rotations are uniform with respect to the Haar measure on SO(3), i.e. no orientation
+/// is more likely than another. Since `q` and `-q` describe the same rotation, the sign of
+/// the result carries no meaning.
+///
+/// Shoemake, "Uniform Random Rotations", Graphics Gems III, 1992: for uniforms `u1`, `u2`
+/// and `u3`, the quaternion is `(sqrt(1 - u1) sin 2πu2, sqrt(1 - u1) cos 2πu2,
+/// sqrt(u1) sin 2πu3, sqrt(u1) cos 2πu3)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::UnitQuaternion};
+///
+/// let mut rng = Rng::new(SplitMix64::new(7));
+/// let [x, y, z, w] = rng.sample(UnitQuaternion);
+/// assert!((x * x + y * y + z * z + w * w - 1.0).abs() < 1e-12);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitQuaternion;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Distribution<[f64; 4]> for UnitQuaternion {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> [f64; 4] {
+        use core::f64::consts::TAU;
+        use super::math;
+
+        let u1 = rng.next_f64();
+        let (s2, c2) = math::sin_cos(TAU * rng.next_f64());
+        let (s3, c3) = math::sin_cos(TAU * rng.next_f64());
+        let (a, b) = (math::sqrt(1.0 - u1), math::sqrt(u1));
+        [a * s2, a * c2, b * s3, b * c3]
+    }
+}
+
+/// Uniform points inside the unit ball, as `[x, y, z]` with `x² + y² + z² < 1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitBall;
+
+impl Distribution<[f64; 3]> for UnitBall {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> [f64; 3] {
+        loop {
+            let p = [rng.next_f64_signed(), rng.next_f64_signed(), rng.next_f64_signed()];
+            if p.iter().map(|c| c * c).sum::<f64>() < 1.0 {
+                return p;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    /// Checks that `n` samples have squared norms accepted by `on_shape` and that each
+    /// coordinate averages to zero with the given second moment.
+    fn check<const N: usize, D: Distribution<[f64; N]>>(dist: D, on_shape: impl Fn(f64) -> bool, moment: f64) {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(117));
+        let n = 100_000;
+        let (mut sum, mut sum_sq) = ([0.0; N], [0.0; N]);
+        for _ in 0..n {
+            let p = dist.sample(&mut rng);
+            assert!(on_shape(p.iter().map(|c| c * c).sum()), "p = {p:?}");
+            for k in 0..N {
+                sum[k] += p[k];
+                sum_sq[k] += p[k] * p[k];
+            }
+        }
+        for k in 0..N {
+            assert!((sum[k] / n as f64).abs() < 0.01, "mean = {}", sum[k] / n as f64);
+            assert!((sum_sq[k] / n as f64 - moment).abs() < 0.01, "second moment = {}", sum_sq[k] / n as f64);
+        }
+    }
+
+    #[test]
+    fn points_are_uniform_on_each_shape() {
+        // E[x²] is 1/2 on the circle, 1/4 in the disc, 1/3 on the sphere and 1/5 in the ball.
+        check(UnitCircle, |r2| (r2 - 1.0).abs() < 1e-12, 0.5);
+        check(UnitDisc, |r2| r2 < 1.0, 0.25);
+        #[cfg(any(feature = "std", feature = "libm"))]
+        check(UnitSphere, |r2| (r2 - 1.0).abs() < 1e-12, 1.0 / 3.0);
+        check(UnitBall, |r2| r2 < 1.0, 0.2);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn quaternions_are_haar_uniform() {
+        // Every component of a uniform unit quaternion has E[q²] = 1/4, and the rotated
+        // image of a fixed axis is uniform on the sphere, with E[z'] = 0 and E[z'²] = 1/3.
+        check(UnitQuaternion, |n2| (n2 - 1.0).abs() < 1e-12, 0.25);
+
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(118));
+        let n = 100_000;
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..n {
+            let [x, y, _, _] = UnitQuaternion.sample(&mut rng);
+            // z component of the unit z axis rotated by the quaternion.
+            let z = 1.0 - 2.0 * (x * x + y * y);
+            sum += z;
+            sum_sq += z * z;
+        }
+        assert!((sum / n as f64).abs() < 0.01);
+        assert!((sum_sq / n as f64 - 1.0 / 3.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,34 @@
+//! Floating-point functions that `core` does not provide.
+//!
+//! Samplers (and the weighted reservoir in `seq`) call these wrappers instead of the
+//! inherent `f64` methods so that the source of `ln`, `sqrt` and friends is decided in one
+//! place: the standard library when the `std` feature is enabled, and the pure-Rust `libm`
+//! crate otherwise.
+
+/// Defines `$name`, calling the `f64` method of the same name with `std` and `libm::$libm`
+/// without it.
+macro_rules! float_fn {
+    ($name:ident($($arg:ident),*) -> $ret:ty, $libm:ident) => {
+        #[inline]
+        pub(crate) fn $name(x: f64 $(, $arg: f64)*) -> $ret {
+            #[cfg(feature = "std")]
+            {
+                x.$name($($arg),*)
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                libm::$libm(x $(, $arg)*)
+            }
+        }
+    };
+}
+
+float_fn!(ln() -> f64, log);
+float_fn!(exp() -> f64, exp);
+float_fn!(sqrt() -> f64, sqrt);
+float_fn!(powf(y) -> f64, pow);
+float_fn!(hypot(y) -> f64, hypot);
+float_fn!(tan() -> f64, tan);
+float_fn!(cos() -> f64, cos);
+float_fn!(acos() -> f64, acos);
+float_fn!(sin_cos() -> (f64, f64), sincos);
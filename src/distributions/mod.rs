@@ -0,0 +1,248 @@
+//! Probability distributions over values drawn from an [`Rng`](crate::Rng).
+//!
+//! Each distribution validates its parameters once in its constructor, returning
+//! [`crate::AporiaError::InvalidParameter`] for values outside its domain, and then samples
+//! repeatedly through `sample(&mut rng)` without further checks.
+//!
+//! Every distribution implements the [`Distribution`] trait, so generic code can accept any
+//! of them: [`crate::Rng::sample`] draws one value and [`Distribution::sample_iter`] or
+//! [`crate::Rng::sample_iter`] yields an endless stream of values.
+//!
+//! New distributions can also be derived from existing ones with the [`Distribution::map`],
+//! [`Distribution::zip`] and [`Distribution::filter`] adapters.
+//!
+//! # Available Distributions
+//!
+//! - [`Bernoulli`]: `true` with probability `p`, via a precomputed 64-bit threshold
+//! - [`Uniform`]: Any range accepted by [`crate::Rng::gen_range`], with its parameters
+//!   precomputed
+//! - [`WeightedIndex`]: Indices picked in proportion to a table of weights
+//! - [`WeightedAliasIndex`]: The same choice in constant time per sample, via Vose's alias
+//!   method
+//! - [`DynamicWeightedIndex`]: Weighted choice with weights updatable between samples, via
+//!   a Fenwick tree
+//! - [`PiecewiseConstant`] and [`PiecewiseLinear`]: Arbitrary densities given as a
+//!   histogram or as values at interval edges
+//! - [`UnitCircle`], [`UnitDisc`], [`UnitSphere`] and [`UnitBall`]: Uniform directions and
+//!   points for graphics and physics
+//! - [`UnitQuaternion`]: Uniform 3D rotations via Shoemake's method
+//! - [`Normal`]: Gaussian values via the Box-Muller transform
+//! - [`SkewNormal`]: Asymmetric Gaussian noise via Azzalini's conditioning representation
+//! - [`Cauchy`]: Heavy-tailed values via the tangent transform
+//! - [`VonMises`]: Angles in (-π, π] for circular data, via the Best-Fisher algorithm
+//! - [`Dirichlet`] and [`DirichletArray`]: Random probability vectors via normalized gamma
+//!   draws
+//! - [`Simplex`]: Uniformly random probability vectors of any length, via sorted uniform
+//!   spacings
+//! - [`Triangular`] and [`Pert`]: Three-point estimates (minimum, most likely, maximum)
+//!   for project-risk Monte Carlo
+//! - [`StandardNormal`] and [`Exp1`]: Standard normal and exponential values via the
+//!   ziggurat method
+//!
+//! The distributions that need `ln`, `sqrt` or trigonometric functions require the `std`
+//! feature, or the `libm` feature under `no_std`, which takes these functions from the
+//! pure-Rust [`libm`](https://crates.io/crates/libm) crate instead.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Xoshiro256PlusPlus};
+//! use aporia::distributions::{Bernoulli, Distribution, Uniform};
+//!
+//! let mut rng = Rng::new(Xoshiro256PlusPlus::new(3));
+//! let coin = Bernoulli::new(0.3).unwrap();
+//! let heads = (0..1000).filter(|_| coin.sample(&mut rng)).count();
+//!
+//! let die = Uniform::new(1..=6).unwrap();
+//! let rolls: Vec<u32> = die.sample_iter(&mut rng).take(10).collect();
+//! let total: u32 = rng.sample(&die) + rng.sample(&die);
+//! ```
+
+#[cfg(feature = "alloc")]
+mod alias;
+mod bernoulli;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod cauchy;
+mod combinators;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod dirichlet;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod exponential;
+#[cfg(feature = "alloc")]
+mod fenwick;
+mod geometry;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod gamma;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) mod math;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod normal;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod pert;
+#[cfg(feature = "alloc")]
+mod piecewise;
+#[cfg(feature = "alloc")]
+mod simplex;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod skew_normal;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod triangular;
+mod uniform;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod von_mises;
+#[cfg(feature = "alloc")]
+mod weighted;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod ziggurat;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod ziggurat_tables;
+
+#[cfg(feature = "alloc")]
+pub use self::alias::WeightedAliasIndex;
+pub use self::bernoulli::Bernoulli;
+pub use self::combinators::{Filter, Map, Zip};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::cauchy::Cauchy;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use self::dirichlet::Dirichlet;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::dirichlet::DirichletArray;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::exponential::Exp1;
+#[cfg(feature = "alloc")]
+pub use self::fenwick::DynamicWeightedIndex;
+pub use self::geometry::{UnitBall, UnitCircle, UnitDisc};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::geometry::{UnitQuaternion, UnitSphere};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::normal::{Normal, StandardNormal};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::pert::Pert;
+#[cfg(feature = "alloc")]
+pub use self::piecewise::PiecewiseConstant;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use self::piecewise::PiecewiseLinear;
+#[cfg(feature = "alloc")]
+pub use self::simplex::Simplex;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::skew_normal::SkewNormal;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::triangular::Triangular;
+pub use self::uniform::Uniform;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::von_mises::VonMises;
+#[cfg(feature = "alloc")]
+pub use self::weighted::WeightedIndex;
+
+use core::marker::PhantomData;
+
+use crate::backend::RandomBackend;
+use crate::Rng;
+
+/// A distribution producing values of type `T`.
+///
+/// Implementations validate their parameters up front, so [`Distribution::sample`] cannot
+/// fail. A reference to a distribution is itself a distribution, which lets one instance
+/// drive several iterators.
+pub trait Distribution<T> {
+    /// Draws one value.
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> T;
+
+    /// Returns an iterator that yields values of this distribution from `rng` indefinitely.
+    fn sample_iter<B: RandomBackend>(self, rng: &mut Rng<B>) -> SampleIter<'_, B, Self, T>
+    where
+        Self: Sized,
+    {
+        SampleIter { rng, dist: self, _marker: PhantomData }
+    }
+
+    /// Returns the distribution of `f(x)` for `x` drawn from this distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64, distributions::{Distribution, Uniform}};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(1));
+    /// let cents = Uniform::new(0u32..10_000).unwrap();
+    /// let price = cents.map(|c| f64::from(c) / 100.0);
+    /// let x: f64 = rng.sample(&price);
+    /// ```
+    fn map<U, F: Fn(T) -> U>(self, f: F) -> Map<Self, F, T>
+    where
+        Self: Sized,
+    {
+        Map { dist: self, f, _marker: PhantomData }
+    }
+
+    /// Returns the distribution of pairs `(x, y)`, drawing `x` from this distribution and
+    /// then `y` from `other`.
+    fn zip<U, D: Distribution<U>>(self, other: D) -> Zip<Self, D>
+    where
+        Self: Sized,
+    {
+        Zip { first: self, second: other }
+    }
+
+    /// Returns the distribution of the values of this distribution for which `predicate`
+    /// holds, drawing again until one does.
+    ///
+    /// Sampling never returns if `predicate` rejects every value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64, distributions::{Distribution, Uniform}};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(1));
+    /// let odd = Uniform::new(0u32..100).unwrap().filter(|x| x % 2 == 1);
+    /// assert_eq!(rng.sample(&odd) % 2, 1);
+    /// ```
+    fn filter<P: Fn(&T) -> bool>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+    {
+        Filter { dist: self, predicate }
+    }
+}
+
+impl<T, D: Distribution<T> + ?Sized> Distribution<T> for &D {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> T {
+        (**self).sample(rng)
+    }
+}
+
+/// Iterator over samples of a distribution from a mutable `Rng` reference, created by
+/// [`Distribution::sample_iter`], [`Rng::sample_iter`] and [`Rng::iter_range`].
+#[derive(Debug)]
+pub struct SampleIter<'a, B: RandomBackend, D, T> {
+    rng: &'a mut Rng<B>,
+    dist: D,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<B: RandomBackend, D: Distribution<T>, T> Iterator for SampleIter<'_, B, D, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.dist.sample(self.rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn every_entry_point_draws_the_same_values() {
+        let die = Uniform::new(1u32..=6).unwrap();
+        let mut a = Rng::new(SplitMix64::new(21));
+        let mut b = Rng::new(SplitMix64::new(21));
+        let mut c = Rng::new(SplitMix64::new(21));
+        for (x, y) in (&die).sample_iter(&mut a).zip(b.sample_iter(die)).take(100) {
+            assert_eq!(x, y);
+            assert_eq!(x, c.sample(die));
+        }
+    }
+}
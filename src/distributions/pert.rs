@@ -0,0 +1,105 @@
+//! PERT distribution.
+//!
+//! A PERT distribution is a beta distribution rescaled to `[min, max]`, with shapes
+//! `1 + shape * (mode - min) / (max - min)` and `1 + shape * (max - mode) / (max - min)`.
+//! With the standard `shape` of 4 its mean is `(min + 4 mode + max) / 6`, the classic
+//! PERT estimate. Compared with [`super::Triangular`] it puts less weight on the extremes.
+
+use super::{Distribution, gamma, triangular::check_estimate};
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Shape used by [`Pert::new`].
+const DEFAULT_SHAPE: f64 = 4.0;
+
+/// The PERT distribution on `[min, max]` with its peak at `mode`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::{Distribution, Pert}};
+///
+/// let mut rng = Rng::new(SplitMix64::new(5));
+/// let cost = Pert::new(80.0, 100.0, 180.0).unwrap();
+/// let mut runs: Vec<f64> = cost.sample_iter(&mut rng).take(1000).collect();
+/// runs.sort_by(f64::total_cmp);
+/// let p90 = runs[900];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pert {
+    min: f64,
+    width: f64,
+    alpha: f64,
+    beta: f64,
+}
+
+impl Pert {
+    /// Creates a PERT distribution from its minimum, mode and maximum, with shape 4.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] unless all three are finite with
+    /// `min <= mode <= max`, `min < max` and a finite `max - min`.
+    pub fn new(min: f64, mode: f64, max: f64) -> Result<Self, AporiaError> {
+        Self::new_with_shape(min, mode, max, DEFAULT_SHAPE)
+    }
+
+    /// Creates a PERT distribution with a custom `shape`; larger values concentrate the
+    /// samples around the mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] for the cases of [`Pert::new`], or unless
+    /// `shape` is finite and non-negative.
+    pub fn new_with_shape(min: f64, mode: f64, max: f64, shape: f64) -> Result<Self, AporiaError> {
+        check_estimate(min, mode, max)?;
+        if !(shape.is_finite() && shape >= 0.0) {
+            return Err(AporiaError::InvalidParameter("shape must be finite and non-negative"));
+        }
+        let width = max - min;
+        Ok(Self {
+            min,
+            width,
+            alpha: 1.0 + shape * (mode - min) / width,
+            beta: 1.0 + shape * (max - mode) / width,
+        })
+    }
+
+    /// Returns the mean, `min + (max - min) * alpha / (alpha + beta)`.
+    pub fn mean(&self) -> f64 {
+        self.min + self.width * self.alpha / (self.alpha + self.beta)
+    }
+}
+
+impl Distribution<f64> for Pert {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> f64 {
+        self.min + self.width * gamma::sample_beta(rng, self.alpha, self.beta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn mean_matches_pert_estimate() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(104));
+        let dist = Pert::new(2.0, 3.0, 10.0).unwrap();
+        assert!((dist.mean() - (2.0 + 4.0 * 3.0 + 10.0) / 6.0).abs() < 1e-12);
+
+        let n = 100_000;
+        let mut sum = 0.0;
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            assert!((2.0..=10.0).contains(&x));
+            sum += x;
+        }
+        let mean = sum / n as f64;
+        assert!((mean - dist.mean()).abs() < 0.02, "mean = {mean}");
+
+        assert!(Pert::new(1.0, 1.0, 1.0).is_err());
+        assert!(Pert::new_with_shape(0.0, 0.5, 1.0, -1.0).is_err());
+    }
+}
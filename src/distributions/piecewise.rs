@@ -0,0 +1,223 @@
+//! Distributions defined by a density over a sequence of intervals, mirroring C++'s
+//! `std::piecewise_constant_distribution` and `std::piecewise_linear_distribution`.
+//!
+//! Both pick an interval with probability proportional to its area under the density,
+//! using a [`WeightedIndex`], and then sample a point within it. The density needs no
+//! normalization: only the ratios between weights matter.
+
+use alloc::vec::Vec;
+
+use super::{Distribution, WeightedIndex};
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Checks that there are at least two finite, strictly increasing edges.
+fn check_edges(edges: &[f64]) -> Result<(), AporiaError> {
+    if edges.len() < 2 {
+        return Err(AporiaError::InvalidParameter("at least two interval edges are required"));
+    }
+    if !edges.iter().all(|e| e.is_finite()) {
+        return Err(AporiaError::InvalidParameter("interval edges must be finite"));
+    }
+    if !edges.windows(2).all(|w| w[0] < w[1]) {
+        return Err(AporiaError::InvalidParameter("interval edges must be strictly increasing"));
+    }
+    Ok(())
+}
+
+/// Distribution with a constant density on each interval, i.e. a histogram.
+///
+/// `weights[i]` is the density on `[edges[i], edges[i + 1])`, so an interval is chosen
+/// with probability proportional to `weights[i] * (edges[i + 1] - edges[i])`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::PiecewiseConstant};
+///
+/// let mut rng = Rng::new(SplitMix64::new(4));
+/// // Arrivals per hour of a day, as measured.
+/// let hours: Vec<f64> = (0..=24).map(f64::from).collect();
+/// let mut rate = vec![1.0; 24];
+/// rate[8] = 12.0;
+/// rate[17] = 9.0;
+/// let arrival = PiecewiseConstant::new(&hours, &rate).unwrap();
+/// let t: f64 = rng.sample(&arrival);
+/// assert!((0.0..24.0).contains(&t));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PiecewiseConstant {
+    edges: Vec<f64>,
+    interval: WeightedIndex,
+}
+
+impl PiecewiseConstant {
+    /// Creates a distribution from `n + 1` interval edges and `n` densities.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] unless there are at least two finite,
+    /// strictly increasing edges and one finite, non-negative density per interval with a
+    /// positive total area.
+    pub fn new(edges: &[f64], weights: &[f64]) -> Result<Self, AporiaError> {
+        check_edges(edges)?;
+        if weights.len() != edges.len() - 1 {
+            return Err(AporiaError::InvalidParameter("expected one weight per interval"));
+        }
+        if !weights.iter().all(|&w| w.is_finite() && w >= 0.0) {
+            return Err(AporiaError::InvalidParameter("weights must be finite and non-negative"));
+        }
+        let areas: Vec<f64> = edges.windows(2).zip(weights).map(|(e, &w)| w * (e[1] - e[0])).collect();
+        Ok(Self { edges: edges.to_vec(), interval: WeightedIndex::new(&areas)? })
+    }
+
+    /// Returns the interval edges.
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+}
+
+impl Distribution<f64> for PiecewiseConstant {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> f64 {
+        let i = self.interval.sample(rng);
+        let (lo, hi) = (self.edges[i], self.edges[i + 1]);
+        // Rounding can reach `hi` for very wide intervals; keep the interval half-open.
+        let x = lo + (hi - lo) * rng.next_f64();
+        if x < hi { x } else { lo }
+    }
+}
+
+/// Distribution whose density is linear between given values at the interval edges.
+///
+/// `densities[i]` is the density at `edges[i]`, so each interval is a trapezoid chosen
+/// with probability proportional to its area. Requires the `std` or `libm` feature for
+/// `sqrt`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::PiecewiseLinear};
+///
+/// let mut rng = Rng::new(SplitMix64::new(4));
+/// // A tent rising from 0 to a peak at 1 and falling back to 0 at 4.
+/// let tent = PiecewiseLinear::new(&[0.0, 1.0, 4.0], &[0.0, 1.0, 0.0]).unwrap();
+/// let x: f64 = rng.sample(&tent);
+/// assert!((0.0..=4.0).contains(&x));
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PiecewiseLinear {
+    edges: Vec<f64>,
+    densities: Vec<f64>,
+    interval: WeightedIndex,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl PiecewiseLinear {
+    /// Creates a distribution from `n + 1` interval edges and the density at each edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] unless there are at least two finite,
+    /// strictly increasing edges and one finite, non-negative density per edge with a
+    /// positive total area.
+    pub fn new(edges: &[f64], densities: &[f64]) -> Result<Self, AporiaError> {
+        check_edges(edges)?;
+        if densities.len() != edges.len() {
+            return Err(AporiaError::InvalidParameter("expected one density per edge"));
+        }
+        if !densities.iter().all(|&d| d.is_finite() && d >= 0.0) {
+            return Err(AporiaError::InvalidParameter("densities must be finite and non-negative"));
+        }
+        let areas: Vec<f64> = edges
+            .windows(2)
+            .zip(densities.windows(2))
+            .map(|(e, d)| (d[0] + d[1]) * (e[1] - e[0]) / 2.0)
+            .collect();
+        Ok(Self {
+            edges: edges.to_vec(),
+            densities: densities.to_vec(),
+            interval: WeightedIndex::new(&areas)?,
+        })
+    }
+
+    /// Returns the interval edges.
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// Returns the density at each edge.
+    pub fn densities(&self) -> &[f64] {
+        &self.densities
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Distribution<f64> for PiecewiseLinear {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> f64 {
+        let i = self.interval.sample(rng);
+        let (lo, hi) = (self.edges[i], self.edges[i + 1]);
+        let (a, b) = (self.densities[i], self.densities[i + 1]);
+        // Inverse CDF of the density `a + (b - a) t` on [0, 1], written as
+        // `u (a + b) / (a + sqrt(a² + u (b² - a²)))` so it stays exact when `a == b`.
+        let u = rng.next_f64();
+        let denom = a + super::math::sqrt(a * a + u * (b * b - a * a));
+        let t = if denom > 0.0 { (u * (a + b) / denom).min(1.0) } else { 0.0 };
+        lo + (hi - lo) * t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn constant_density_fills_intervals_by_area() {
+        let dist = PiecewiseConstant::new(&[0.0, 1.0, 3.0, 4.0], &[2.0, 1.0, 0.0]).unwrap();
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(116));
+        let n = 100_000;
+        let mut below_one = 0;
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            assert!((0.0..3.0).contains(&x), "x = {x}");
+            below_one += usize::from(x < 1.0);
+        }
+        // Areas are 2 and 2, so half of the samples fall in [0, 1).
+        let freq = below_one as f64 / n as f64;
+        assert!((freq - 0.5).abs() < 0.01, "freq = {freq}");
+
+        assert!(PiecewiseConstant::new(&[0.0], &[]).is_err());
+        assert!(PiecewiseConstant::new(&[0.0, 0.0], &[1.0]).is_err());
+        assert!(PiecewiseConstant::new(&[0.0, 1.0], &[1.0, 1.0]).is_err());
+        assert!(PiecewiseConstant::new(&[0.0, 1.0], &[0.0]).is_err());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn linear_density_matches_cdf() {
+        // Density rising linearly from 0 at x = 0 to 1 at x = 2, so the CDF is x² / 4.
+        let dist = PiecewiseLinear::new(&[0.0, 2.0], &[0.0, 1.0]).unwrap();
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(116));
+        let n = 100_000;
+        let mut below_one = 0;
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            assert!((0.0..=2.0).contains(&x));
+            below_one += usize::from(x < 1.0);
+        }
+        let freq = below_one as f64 / n as f64;
+        assert!((freq - 0.25).abs() < 0.01, "freq = {freq}");
+
+        let flat = PiecewiseLinear::new(&[0.0, 1.0], &[3.0, 3.0]).unwrap();
+        let mut a = Rng::new(Xoshiro256PlusPlus::new(5));
+        let mut b = Rng::new(Xoshiro256PlusPlus::new(5));
+        let x = flat.sample(&mut a);
+        let _ = b.next_f64();
+        assert_eq!(x, b.next_f64());
+
+        assert!(PiecewiseLinear::new(&[0.0, 1.0], &[0.0, 0.0]).is_err());
+        assert!(PiecewiseLinear::new(&[0.0, 1.0], &[1.0]).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Uniform distribution on the probability simplex.
+//!
+//! The `k - 1` order statistics of uniform draws split [0, 1] into `k` spacings whose joint
+//! distribution is uniform on the simplex, i.e. Dirichlet(1, ..., 1). Sorting costs
+//! O(k log k) but needs no `ln`, so this works without the `std` feature.
+
+use alloc::vec::Vec;
+
+use super::Distribution;
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Uniformly random vectors of `k` non-negative values summing to 1.
+///
+/// For a length fixed at compile time, [`DirichletArray`](super::DirichletArray) with all
+/// parameters 1 samples the same distribution without allocating.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::Simplex};
+///
+/// let mut rng = Rng::new(SplitMix64::new(9));
+/// let portfolio = Simplex::new(5).unwrap();
+/// let weights: Vec<f64> = rng.sample(portfolio);
+/// assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Simplex {
+    k: usize,
+}
+
+impl Simplex {
+    /// Creates the uniform distribution over vectors of length `k`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] if `k` is zero.
+    pub fn new(k: usize) -> Result<Self, AporiaError> {
+        if k == 0 {
+            return Err(AporiaError::InvalidParameter("simplex dimension must be positive"));
+        }
+        Ok(Self { k })
+    }
+
+    /// Returns the length of the sampled vectors.
+    pub fn dimension(&self) -> usize {
+        self.k
+    }
+}
+
+impl Distribution<Vec<f64>> for Simplex {
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> Vec<f64> {
+        let mut cuts: Vec<f64> = (1..self.k).map(|_| rng.next_f64()).collect();
+        cuts.sort_unstable_by(f64::total_cmp);
+        cuts.push(1.0);
+        let mut prev = 0.0;
+        for cut in &mut cuts {
+            let spacing = *cut - prev;
+            prev = *cut;
+            *cut = spacing;
+        }
+        cuts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn vectors_are_uniform_on_the_simplex() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(119));
+        let dist = Simplex::new(4).unwrap();
+        let n = 100_000;
+        let (mut sum, mut sum_sq) = ([0.0; 4], [0.0; 4]);
+        for _ in 0..n {
+            let p = dist.sample(&mut rng);
+            assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            for k in 0..4 {
+                assert!(p[k] >= 0.0);
+                sum[k] += p[k];
+                sum_sq[k] += p[k] * p[k];
+            }
+        }
+        // Each component of Dirichlet(1, 1, 1, 1) has mean 1/4 and E[p²] = 2 / (4 * 5).
+        for k in 0..4 {
+            assert!((sum[k] / n as f64 - 0.25).abs() < 0.005);
+            assert!((sum_sq[k] / n as f64 - 0.1).abs() < 0.005);
+        }
+
+        assert_eq!(Simplex::new(1).unwrap().sample(&mut rng), [1.0]);
+        assert!(Simplex::new(0).is_err());
+    }
+}
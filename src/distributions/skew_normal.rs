@@ -0,0 +1,123 @@
+//! Skew-normal distribution.
+//!
+//! Samples use the conditioning representation of Azzalini: for independent standard
+//! normals `u0` and `v`, the pair `(u0, u1)` with `u1 = δ u0 + sqrt(1 - δ²) v` is a
+//! correlated bivariate normal, and `u1` conditioned on `u0 >= 0` is skew-normal with
+//! `δ = shape / sqrt(1 + shape²)`. Flipping the sign of `u1` when `u0 < 0` gives the same
+//! conditional distribution without rejecting any draw.
+
+use super::{Distribution, math, ziggurat};
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// The skew-normal distribution with the given location, scale and shape.
+///
+/// A `shape` of zero is the normal distribution `N(location, scale²)`; positive values skew
+/// the distribution to the right and negative values to the left.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::SkewNormal};
+///
+/// let mut rng = Rng::new(SplitMix64::new(3));
+/// let noise = SkewNormal::new(0.0, 1.5, 4.0).unwrap();
+/// let reading: f64 = 20.0 + rng.sample(noise);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SkewNormal {
+    location: f64,
+    scale: f64,
+    shape: f64,
+    /// `shape / sqrt(1 + shape²)`.
+    delta: f64,
+    /// `sqrt(1 - delta²) = 1 / sqrt(1 + shape²)`.
+    complement: f64,
+}
+
+impl SkewNormal {
+    /// Creates a skew-normal distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] unless `location` and `shape` are finite
+    /// and `scale` is finite and positive.
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Self, AporiaError> {
+        if !(location.is_finite() && shape.is_finite()) {
+            return Err(AporiaError::InvalidParameter("location and shape must be finite"));
+        }
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(AporiaError::InvalidParameter("scale must be finite and positive"));
+        }
+        // hypot avoids overflowing `shape²`, so huge shapes still reach the half-normal limit.
+        let norm = math::hypot(1.0, shape);
+        Ok(Self {
+            location,
+            scale,
+            shape,
+            delta: shape / norm,
+            complement: 1.0 / norm,
+        })
+    }
+
+    /// Returns the location.
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+impl Distribution<f64> for SkewNormal {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> f64 {
+        let u0 = ziggurat::standard_normal(rng);
+        let v = ziggurat::standard_normal(rng);
+        let u1 = self.delta * u0 + self.complement * v;
+        let z = if u0 >= 0.0 { u1 } else { -u1 };
+        self.location + self.scale * z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn moments_match_parameters() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(106));
+        for shape in [-5.0, 0.0, 2.0] {
+            let dist = SkewNormal::new(1.0, 2.0, shape).unwrap();
+            let n = 200_000;
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..n {
+                let x = dist.sample(&mut rng);
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / n as f64;
+            let var = sum_sq / n as f64 - mean * mean;
+            // Mean `location + scale δ sqrt(2/π)`, variance `scale² (1 - 2δ²/π)`.
+            let b = dist.delta * (2.0 / core::f64::consts::PI).sqrt();
+            assert!((mean - (1.0 + 2.0 * b)).abs() < 0.02, "shape {shape}: mean = {mean}");
+            assert!((var - 4.0 * (1.0 - b * b)).abs() < 0.05, "shape {shape}: var = {var}");
+        }
+
+        // Shapes whose square overflows are the half-normal limit, not a plain normal.
+        let half = SkewNormal::new(0.0, 1.0, 1e200).unwrap();
+        assert_eq!((half.delta, half.complement), (1.0, 1e-200));
+        assert!((0..1000).all(|_| half.sample(&mut rng) >= 0.0));
+
+        assert!(SkewNormal::new(0.0, 0.0, 1.0).is_err());
+        assert!(SkewNormal::new(0.0, 1.0, f64::NAN).is_err());
+    }
+}
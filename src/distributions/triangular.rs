@@ -0,0 +1,115 @@
+//! Triangular distribution.
+//!
+//! Samples invert the CDF, which is piecewise quadratic, so one uniform and one `sqrt`
+//! give each value.
+
+use super::{Distribution, math};
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Checks the `min <= mode <= max`, `min < max` parameters shared with [`super::Pert`],
+/// and that `max - min` does not overflow.
+pub(super) fn check_estimate(min: f64, mode: f64, max: f64) -> Result<(), AporiaError> {
+    if !(min.is_finite() && mode.is_finite() && max.is_finite()) {
+        return Err(AporiaError::InvalidParameter("min, mode and max must be finite"));
+    }
+    if !(min <= mode && mode <= max && min < max) {
+        return Err(AporiaError::InvalidParameter("expected min <= mode <= max with min < max"));
+    }
+    if !(max - min).is_finite() {
+        return Err(AporiaError::InvalidParameter("max - min must be finite"));
+    }
+    Ok(())
+}
+
+/// The triangular distribution on `[min, max]` with its peak at `mode`.
+///
+/// The usual three-point estimate (optimistic, most likely, pessimistic) maps directly onto
+/// its parameters.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::Triangular};
+///
+/// let mut rng = Rng::new(SplitMix64::new(5));
+/// let days = Triangular::new(3.0, 5.0, 12.0).unwrap();
+/// let estimate: f64 = rng.sample(days);
+/// assert!((3.0..=12.0).contains(&estimate));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangular {
+    min: f64,
+    mode: f64,
+    max: f64,
+    /// CDF at the mode, `(mode - min) / (max - min)`.
+    split: f64,
+}
+
+impl Triangular {
+    /// Creates a triangular distribution from its minimum, mode and maximum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] unless all three are finite with
+    /// `min <= mode <= max`, `min < max` and a finite `max - min`.
+    pub fn new(min: f64, mode: f64, max: f64) -> Result<Self, AporiaError> {
+        check_estimate(min, mode, max)?;
+        Ok(Self { min, mode, max, split: (mode - min) / (max - min) })
+    }
+}
+
+impl Distribution<f64> for Triangular {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> f64 {
+        let u = rng.next_f64();
+        let width = self.max - self.min;
+        let x = if u < self.split {
+            // Square roots taken separately so the product cannot overflow for wide ranges.
+            self.min + math::sqrt(u * width) * math::sqrt(self.mode - self.min)
+        } else {
+            self.max - math::sqrt((1.0 - u) * width) * math::sqrt(self.max - self.mode)
+        };
+        x.clamp(self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn mean_and_bounds_match_parameters() {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(104));
+        for (min, mode, max) in [(0.0, 0.0, 1.0), (2.0, 3.0, 10.0), (-1.0, 1.0, 1.0)] {
+            let dist = Triangular::new(min, mode, max).unwrap();
+            let n = 100_000;
+            let mut sum = 0.0;
+            for _ in 0..n {
+                let x = dist.sample(&mut rng);
+                assert!((min..=max).contains(&x));
+                sum += x;
+            }
+            let mean = sum / n as f64;
+            let expected = (min + mode + max) / 3.0;
+            assert!((mean - expected).abs() < 0.01 * (max - min), "mean = {mean}");
+        }
+
+        assert!(Triangular::new(1.0, 1.0, 1.0).is_err());
+        assert!(Triangular::new(0.0, 2.0, 1.0).is_err());
+        assert!(Triangular::new(0.0, f64::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn wide_ranges_stay_finite() {
+        assert!(Triangular::new(-f64::MAX, 0.0, f64::MAX).is_err());
+
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(105));
+        let dist = Triangular::new(-1e300, 0.0, 1e300).unwrap();
+        for _ in 0..1000 {
+            let x = dist.sample(&mut rng);
+            assert!(x.is_finite() && (-1e300..=1e300).contains(&x), "x = {x}");
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Uniform distribution over a fixed range.
+//!
+//! [`Uniform`] validates a range once and keeps the values [`crate::Rng::gen_range`] would
+//! otherwise recompute on every call, such as the rejection threshold of an integer range.
+//! Each sample yields exactly the value `gen_range` would yield from the same generator
+//! state, so switching between the two does not change a seeded sequence.
+
+use core::ops::RangeBounds;
+
+use super::Distribution;
+use crate::backend::RandomBackend;
+use crate::uniform::range_bounds;
+use crate::{AporiaError, Rng, SampleUniform};
+
+/// Distribution sampling uniformly from a range of any [`SampleUniform`] type.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::Xoshiro256PlusPlus, distributions::{Distribution, Uniform}};
+///
+/// let mut rng = Rng::new(Xoshiro256PlusPlus::new(9));
+/// let die = Uniform::new(1..=6).unwrap();
+/// let total: u32 = (0..1000).map(|_| die.sample(&mut rng)).sum();
+/// ```
+pub struct Uniform<T: SampleUniform> {
+    sampler: T::Sampler,
+}
+
+impl<T: SampleUniform> Uniform<T> {
+    /// Creates a distribution over `range`, which is `low..high` or `low..=high` as for
+    /// [`Rng::gen_range`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Rng::gen_range`] for an empty or open-ended range.
+    pub fn new<R: RangeBounds<T>>(range: R) -> Result<Self, AporiaError> {
+        let (low, high, inclusive) = range_bounds(&range)?;
+        Ok(Self { sampler: T::new_sampler(low, high, inclusive)? })
+    }
+}
+
+impl<T: SampleUniform> Distribution<T> for Uniform<T> {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> T {
+        T::sample_with(&self.sampler, &mut rng.backend)
+    }
+}
+
+impl<T: SampleUniform> Clone for Uniform<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: SampleUniform> Copy for Uniform<T> {}
+
+impl<T: SampleUniform> PartialEq for Uniform<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.sampler == other.sampler
+    }
+}
+
+impl<T: SampleUniform> core::fmt::Debug for Uniform<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Uniform").field("sampler", &self.sampler).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn samples_match_gen_range() {
+        let mut a = Rng::new(SplitMix64::new(14));
+        let mut b = Rng::new(SplitMix64::new(14));
+        let small = Uniform::new(0u64..6).unwrap();
+        let signed = Uniform::new(-50i32..=50).unwrap();
+        let full = Uniform::new(0..=u64::MAX).unwrap();
+        let wide = Uniform::new(0..(1u128 << 100) + 7).unwrap();
+        let float = Uniform::new(-2.0..3.0).unwrap();
+        let letter = Uniform::new('a'..='z').unwrap();
+        for _ in 0..1000 {
+            assert_eq!(small.sample(&mut a), b.gen_range(0..6).unwrap());
+            assert_eq!(signed.sample(&mut a), b.gen_range(-50..=50).unwrap());
+            assert_eq!(full.sample(&mut a), b.gen_range(0..=u64::MAX).unwrap());
+            assert_eq!(wide.sample(&mut a), b.gen_range(0..(1u128 << 100) + 7).unwrap());
+            assert_eq!(float.sample(&mut a), b.gen_range(-2.0..3.0).unwrap());
+            assert_eq!(letter.sample(&mut a), b.gen_range('a'..='z').unwrap());
+        }
+
+        assert!(Uniform::new(3u8..3).is_err());
+        assert!(Uniform::new(3u8..=3).is_ok());
+        assert!(Uniform::new(..5u8).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! Categorical distribution over indices, from a table of cumulative weights.
+
+use alloc::vec::Vec;
+
+use super::Distribution;
+use crate::backend::RandomBackend;
+use crate::{AporiaError, Rng};
+
+/// Checks that `weights` is non-empty, every weight is finite and non-negative, and the
+/// total is finite and positive, returning [`AporiaError::AllWeightsZero`] if it is zero.
+pub(super) fn check_weights(weights: &[f64]) -> Result<f64, AporiaError> {
+    if weights.is_empty() {
+        return Err(AporiaError::InvalidParameter("weights must not be empty"));
+    }
+    if !weights.iter().all(|&w| w.is_finite() && w >= 0.0) {
+        return Err(AporiaError::InvalidParameter("weights must be finite and non-negative"));
+    }
+    let total: f64 = weights.iter().sum();
+    if !total.is_finite() {
+        return Err(AporiaError::InvalidParameter("total weight must be finite"));
+    }
+    if total <= 0.0 {
+        return Err(AporiaError::AllWeightsZero);
+    }
+    Ok(total)
+}
+
+/// Distribution over `0..weights.len()` picking index `i` with probability proportional to
+/// `weights[i]`.
+///
+/// Construction builds the running totals of the weights in O(k); each sample draws one
+/// `f64` and binary-searches them in O(log k). Indices with zero weight are never returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, distributions::WeightedIndex};
+///
+/// let mut rng = Rng::new(SplitMix64::new(10));
+/// let items = ["common", "uncommon", "rare"];
+/// let odds = WeightedIndex::new(&[70.0, 25.0, 5.0]).unwrap();
+/// let drop = items[rng.sample(&odds)];
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedIndex {
+    /// `cumulative[i]` is the sum of `weights[..=i]`.
+    cumulative: Vec<f64>,
+    /// Largest index with a positive weight, returned if rounding pushes a draw past the end.
+    last_positive: usize,
+}
+
+impl WeightedIndex {
+    /// Creates a distribution from non-negative weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] if `weights` is empty, if any weight is
+    /// negative, NaN or infinite, or if the total overflows, and
+    /// [`AporiaError::AllWeightsZero`] if every weight is zero.
+    pub fn new(weights: &[f64]) -> Result<Self, AporiaError> {
+        check_weights(weights)?;
+        let mut total = 0.0;
+        let cumulative = weights
+            .iter()
+            .map(|&w| {
+                total += w;
+                total
+            })
+            .collect();
+        let last_positive = weights.iter().rposition(|&w| w > 0.0).unwrap_or(0);
+        Ok(Self { cumulative, last_positive })
+    }
+
+    /// Returns the number of categories.
+    pub fn len(&self) -> usize {
+        self.cumulative.len()
+    }
+
+    /// Returns `false`; a `WeightedIndex` always has at least one category.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the sum of all weights.
+    pub fn total_weight(&self) -> f64 {
+        self.cumulative[self.cumulative.len() - 1]
+    }
+}
+
+impl Distribution<usize> for WeightedIndex {
+    #[inline]
+    fn sample<B: RandomBackend>(&self, rng: &mut Rng<B>) -> usize {
+        let target = rng.next_f64() * self.total_weight();
+        self.cumulative.partition_point(|&c| c <= target).min(self.last_positive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    #[test]
+    fn frequencies_follow_weights() {
+        let weights = [0.0, 1.0, 0.0, 3.0, 6.0, 0.0];
+        let dist = WeightedIndex::new(&weights).unwrap();
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(110));
+        let n = 200_000;
+        let mut counts = [0u32; 6];
+        for _ in 0..n {
+            counts[dist.sample(&mut rng)] += 1;
+        }
+        for (count, w) in counts.iter().zip(weights) {
+            let freq = f64::from(*count) / f64::from(n);
+            assert!((freq - w / 10.0).abs() < 0.005, "freq = {freq}, weight = {w}");
+        }
+        assert_eq!(dist.total_weight(), 10.0);
+    }
+
+    #[test]
+    fn invalid_weights_are_rejected() {
+        assert!(WeightedIndex::new(&[]).is_err());
+        assert!(WeightedIndex::new(&[0.0, 0.0]).is_err());
+        assert!(WeightedIndex::new(&[1.0, -0.5]).is_err());
+        assert!(WeightedIndex::new(&[1.0, f64::NAN]).is_err());
+        assert!(WeightedIndex::new(&[f64::MAX, f64::MAX]).is_err());
+        assert!(WeightedIndex::new(&[0.0, 2.0]).is_ok());
+    }
+}
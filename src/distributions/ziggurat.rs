@@ -0,0 +1,115 @@
+//! Ziggurat sampling of the standard normal and standard exponential distributions.
+//!
+//! The area under the density is covered by 256 horizontal layers of equal area (see
+//! [`ziggurat_tables`](super::ziggurat_tables)). One `next_u64` picks a layer from its low
+//! 8 bits and a position from its top 52 bits; about 99% of draws land inside the part of
+//! the layer that lies wholly under the curve and are returned after one multiply and
+//! compare. Only the rest evaluate the density, and draws from the base layer beyond the
+//! tail start `R` are handled by a separate tail sampler.
+//!
+//! See Marsaglia and Tsang, "The Ziggurat Method for Generating Random Variables",
+//! Journal of Statistical Software 5(8), 2000.
+
+use super::ziggurat_tables::{EXP_F, EXP_R, EXP_X, NORM_F, NORM_R, NORM_X};
+use super::math;
+use crate::Rng;
+use crate::backend::RandomBackend;
+
+/// Runs the ziggurat loop over the given tables.
+///
+/// For a `symmetric` density the position is drawn in [-1, 1) so the sign comes from the
+/// same draw. `tail` samples beyond `x_tab[1]` and receives the signed position.
+#[inline(always)]
+fn ziggurat<B, P, T>(
+    rng: &mut Rng<B>,
+    symmetric: bool,
+    x_tab: &[f64; 257],
+    f_tab: &[f64; 257],
+    pdf: P,
+    tail: T,
+) -> f64
+where
+    B: RandomBackend,
+    P: Fn(f64) -> f64,
+    T: Fn(&mut Rng<B>, f64) -> f64,
+{
+    loop {
+        let bits = rng.next_u64();
+        let i = (bits & 0xff) as usize;
+        // The top 52 bits as a float in [0, 1), independent of the layer bits.
+        let unit = f64::from_bits((bits >> 12) | 0x3ff0_0000_0000_0000) - 1.0;
+        let u = if symmetric { 2.0 * unit - 1.0 } else { unit };
+        let x = u * x_tab[i];
+
+        if x.abs() < x_tab[i + 1] {
+            return x;
+        }
+        if i == 0 {
+            return tail(rng, u);
+        }
+        if f_tab[i + 1] + (f_tab[i] - f_tab[i + 1]) * rng.next_f64() < pdf(x) {
+            return x;
+        }
+    }
+}
+
+/// Draws from `N(0, 1)`.
+#[inline]
+pub(super) fn standard_normal<B: RandomBackend>(rng: &mut Rng<B>) -> f64 {
+    ziggurat(
+        rng,
+        true,
+        &NORM_X,
+        &NORM_F,
+        |x| math::exp(-x * x / 2.0),
+        |rng, u| {
+            // Marsaglia's tail method: exponential proposals accepted under the normal tail.
+            let (mut x, mut y) = (1.0, 0.0);
+            while -2.0 * y < x * x {
+                x = math::ln(rng.next_f64_open()) / NORM_R;
+                y = math::ln(rng.next_f64_open());
+            }
+            if u < 0.0 { x - NORM_R } else { NORM_R - x }
+        },
+    )
+}
+
+/// Draws from the exponential distribution with rate 1.
+#[inline]
+pub(super) fn exp1<B: RandomBackend>(rng: &mut Rng<B>) -> f64 {
+    ziggurat(
+        rng,
+        false,
+        &EXP_X,
+        &EXP_F,
+        |x| math::exp(-x),
+        // The exponential tail beyond `R` is `R` plus another exponential.
+        |rng, _| EXP_R - math::ln(rng.next_f64_open_closed()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256PlusPlus;
+
+    /// Fraction of `n` draws of `sample` that fall below `x`.
+    fn empirical_cdf(sample: impl Fn(&mut Rng<Xoshiro256PlusPlus>) -> f64, x: f64, n: u32) -> f64 {
+        let mut rng = Rng::new(Xoshiro256PlusPlus::new(91));
+        (0..n).filter(|_| sample(&mut rng) < x).count() as f64 / f64::from(n)
+    }
+
+    #[test]
+    fn samplers_match_reference_cdfs() {
+        // Standard normal CDF at -2, -0.5, 1 and 3.
+        for (x, cdf) in [(-2.0, 0.022750), (-0.5, 0.308538), (1.0, 0.841345), (3.0, 0.998650)] {
+            let got = empirical_cdf(standard_normal, x, 400_000);
+            assert!((got - cdf).abs() < 0.003, "normal cdf({x}) = {got}");
+        }
+        for x in [0.1, 1.0, 3.0, 8.0] {
+            let got = empirical_cdf(exp1, x, 400_000);
+            let cdf = 1.0 - (-x).exp();
+            assert!((got - cdf).abs() < 0.003, "exp cdf({x}) = {got}");
+        }
+    }
+}
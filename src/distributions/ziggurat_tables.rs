@@ -0,0 +1,288 @@
+//! Precomputed ziggurat tables with 256 layers, following Marsaglia and Tsang (2000).
+//!
+//! `X[0]` is the width of a rectangle with the area of one layer and the height of the base
+//! layer, `X[1]` is the tail start `R`, and `X[256]` is zero. `F[i]` is the unnormalized
+//! density at `X[i]`. The values were generated in double precision from `R` and the layer
+//! area `V` with `X[i + 1] = f⁻¹(V / X[i] + f(X[i]))`.
+
+/// Start of the normal tail.
+pub(super) const NORM_R: f64 = 3.654152885361009;
+
+/// Layer edges for the standard normal, `f(x) = exp(-x² / 2)`.
+pub(super) const NORM_X: [f64; 257] = [
+    3.91075795953709, 3.654152885361009, 3.4492782985609645, 3.320244733839166,
+    3.224575052047029, 3.14788928951715, 3.083526132001233, 3.0278377917686354,
+    2.978603279880845, 2.9343668672078542, 2.894121053612348, 2.8571387308721325,
+    2.822877396825325, 2.7909211740007858, 2.7609440052788226, 2.732685359042827,
+    2.705933656121858, 2.680514643284522, 2.6562830375755024, 2.6331163936303246,
+    2.6109105184875485, 2.589575986706995, 2.5690354526805366, 2.5492215503234608,
+    2.530075232158517, 2.5115444416253423, 2.4935830412696807, 2.4761499396691433,
+    2.4592083743333113, 2.4427253181989568, 2.426670984935726, 2.4110184138996855,
+    2.3957431197804806, 2.380822795170626, 2.3662370567158186, 2.35196722737766,
+    2.3379961487950314, 2.324308018869623, 2.31088825059985, 2.2977233489013296,
+    2.284800802722946, 2.272108990226824, 2.259637095172218, 2.2473750329458078,
+    2.235313384928328, 2.2234433400909057, 2.2117566428825444, 2.200245546609648,
+    2.1889027716247207, 2.1777214677386416, 2.166695180352646, 2.1558178198750633,
+    2.1450836340462036, 2.13448718284432, 2.1240233156878157, 2.113687150684934,
+    2.103474055713147, 2.0933796311370503, 2.083399693996552, 2.0735302635169788,
+    2.0637675478099564, 2.054107931648865, 2.044547965215733, 2.0350843537278087,
+    2.025713947862033, 2.0164337349043717, 2.007240830558685, 1.9981324713565642,
+    1.9891060076155713, 1.9801588968985984, 1.9712886979317696, 1.962493064942462,
+    1.953769742382734, 1.945116560006754, 1.936531428273759, 1.9280123340507183,
+    1.9195573365912288, 1.9111645637692822, 1.9028322085484464, 1.89455852566871,
+    1.8863418285347764, 1.8781804862909777, 1.8700729210692368, 1.8620176053976323,
+    1.8540130597581481, 1.8460578502831198, 1.8381505865807286, 1.8302899196806666,
+    1.8224745400917832, 1.8147031759641676, 1.8069745913486934, 1.7992875845475802,
+    1.79164098655001, 1.7840336595472763, 1.776464495522345, 1.768932414909078,
+    1.7614363653167067, 1.753975320315455, 1.746548278279493, 1.739154261283669,
+    1.7317923140507072, 1.7244615029457757, 1.7171609150155407, 1.709889657069006,
+    1.702646854797614, 1.6954316519322385, 1.6882432094348587, 1.6810807047228233,
+    1.6739433309237604, 1.6668302961592867, 1.6597408228557895, 1.6526741470806485,
+    1.6456295179023603, 1.6386061967731111, 1.631603456932422, 1.6246205828305684,
+    1.6176568695705342, 1.6107116223673337, 1.603784156023583, 1.5968737944202613,
+    1.5899798700216485, 1.5831017233934714, 1.5762387027333329, 1.5693901634125345,
+    1.5625554675284397, 1.555733983466555, 1.5489250854715355, 1.5421281532263476,
+    1.5353425714388431, 1.5285677294350246, 1.521803020758293, 1.5150478427739924,
+    1.508301596278572, 1.5015636851127065, 1.4948335157777184, 1.4881104970546544,
+    1.4813940396253757, 1.4746835556950255, 1.467978458615231, 1.4612781625074078,
+    1.4545820818855233, 1.4478896312776697, 1.441200224845798, 1.4345132760029464,
+    1.4278281970272904, 1.4211443986723231, 1.4144612897724647, 1.4077782768433715,
+    1.4010947636762026, 1.3944101509250713, 1.3877238356868846, 1.381035211072742,
+    1.3743436657700305, 1.367648583594318, 1.3609493430301018, 1.3542453167594306,
+    1.3475358711773593, 1.3408203658931521, 1.3340981532160836, 1.3273685776246247,
+    1.32063097521773, 1.313884673146869, 1.3071289890273539, 1.3003632303274337,
+    1.2935866937335176, 1.2867986644897864, 1.2799984157103332, 1.2731852076618437,
+    1.2663582870146883, 1.2595168860601442, 1.2526602218912979, 1.245787495544998,
+    1.2388978911020274, 1.231990574742445, 1.225064693752808, 1.2181193754817266,
+    1.2111537262399112, 1.2041668301405601, 1.197157747875586, 1.1901255154228016,
+    1.1830691426787607, 1.1759876120114898, 1.1688798767268338, 1.1617448594415742,
+    1.1545814503558518, 1.1473885054167339, 1.1401648443639958, 1.132909248648337,
+    1.1256204592112944, 1.118297174115063, 1.1109380460092495, 1.1035416794202682,
+    1.0961066278476035, 1.0886313906495142, 1.0811144096988894, 1.0735540657878717,
+    1.0659486747575067, 1.0582964833260065, 1.0505956645862071, 1.0428443131393705,
+    1.0350404398286053, 1.0271819660307513, 1.0192667174605292, 1.0112924174349784,
+    1.0032566795395914, 0.9951569996299431, 0.9869907470938463, 0.9787551552889378,
+    0.9704473110588646, 0.9620641432176052, 0.9536024098755727, 0.9450586844625711,
+    0.9364293402808969, 0.9277105333962348, 0.918898183643735, 0.909987953490769,
+    0.9009752244551745, 0.8918550707267924, 0.8826222295789101, 0.8732710680824946,
+    0.8637955455468269, 0.8541891710015606, 0.8444449549024237, 0.8345553540795188,
+    0.8245122087452886, 0.8143066701280643, 0.8039291169826649, 0.7933690588331528,
+    0.7826150232995888, 0.7716544242167394, 0.7604734064220832, 0.7490566620095817,
+    0.7373872114258386, 0.7254461409013035, 0.7132122851820227, 0.7006618410975844,
+    0.6877678927862577, 0.6744998228274365, 0.660822574234206, 0.6466957148843889,
+    0.6320722363750246, 0.6168969899962355, 0.6011046177439404, 0.5846167660937223,
+    0.567338257040473, 0.5491517023130268, 0.5299097206464951, 0.5094233295859334,
+    0.48744396612175434, 0.46363433677176324, 0.43751840218666266, 0.40838913458800075,
+    0.3751213328504657, 0.33573751918045946, 0.2861745917472605, 0.2152418959132738,
+    0.0,
+];
+
+/// `exp(-x² / 2)` at each entry of [`NORM_X`].
+pub(super) const NORM_F: [f64; 257] = [
+    0.0004774677645866553, 0.001260285930498598, 0.002609072746106363, 0.0040379725933718715,
+    0.005522403299264754, 0.00705087547139211, 0.008616582769422917, 0.0102149714397311,
+    0.011842757857943104, 0.013497450601780807, 0.015177088307982072, 0.01688008315259584,
+    0.01860512127578335, 0.020351096230109354, 0.022117062707379922, 0.023902203305873237,
+    0.025705804008632656, 0.027527235669693315, 0.02936593975823011, 0.03122141719202369,
+    0.0330932194586887, 0.03498094146183307, 0.03688421568869115, 0.03880270740465692,
+    0.04073611065607875, 0.04268414491661938, 0.044646552251446536, 0.046623094902089664,
+    0.048613553216035145, 0.05061772386112179, 0.05263541827697365, 0.054666461325077916,
+    0.05671069010639947, 0.058767952921137984, 0.060838108349751806, 0.06292102443797785,
+    0.06501657797147044, 0.06712465382802399, 0.06924514439725027, 0.07137794905914197,
+    0.07352297371424099, 0.07568013035919496, 0.07784933670237221, 0.08003051581494751,
+    0.08222359581349568, 0.08442850957065466, 0.08664519445086778, 0.08887359206859423,
+    0.09111364806670073, 0.09336531191302662, 0.09562853671335333, 0.09790327903921563,
+    0.10018949876917202, 0.10248715894230627, 0.10479622562286706, 0.10711666777507288,
+    0.10944845714721002, 0.11179156816424558, 0.11414597782825521, 0.11651166562603701,
+    0.1188886134433457, 0.12127680548523544, 0.1236762282020514, 0.12608687022065035,
+    0.12850872228047364, 0.13094177717412817, 0.13338602969216284, 0.13584147657175735,
+    0.13830811644906432, 0.1407859498149683, 0.14327497897404712, 0.14577520800653793,
+    0.14828664273312872, 0.15080929068241017, 0.15334316106083767, 0.15588826472506456,
+    0.15844461415652022, 0.16101222343811766, 0.16359110823298295, 0.16618128576511007,
+    0.16878277480185033, 0.17139559563815562, 0.17401977008249936, 0.17665532144440665,
+    0.1793022745235304, 0.1819606556002165, 0.18463049242750454, 0.18731181422451693,
+    0.19000465167119307, 0.1927090369043288, 0.1954250035148856, 0.1981525865465381,
+    0.20089182249543133, 0.2036427493111215, 0.20640540639867933, 0.20917983462193565,
+    0.21196607630785294, 0.2147641752520085, 0.21757417672517837, 0.2203961274810116,
+    0.2232300757647896, 0.22607607132326488, 0.22893416541557748, 0.23180441082524852,
+    0.2346868618732527, 0.23758157443217368, 0.2404886059414491, 0.243408015423712,
+    0.24633986350223877, 0.2492842124195167, 0.25224112605694377, 0.25521066995567715,
+    0.258192911338648, 0.2611879191337637, 0.26419576399831757, 0.26721651834463184,
+    0.27025025636696, 0.2732970540696758, 0.27635698929678126, 0.2794301417627653,
+    0.2825165930848494, 0.2856164268166581, 0.28872972848335393, 0.291856585618281,
+    0.29499708780116257, 0.29815132669790134, 0.3013193961020341, 0.3045013919778963,
+    0.30769741250555377, 0.3109075581275637, 0.31413193159763014, 0.3173706380312224,
+    0.32062378495823013, 0.323891482377732, 0.3271738428149586, 0.3304709813805371,
+    0.3337830158321085, 0.3371100666384128, 0.34045225704594545, 0.34380971314829134,
+    0.3471825639582515, 0.3505709414828812, 0.35397498080156925, 0.3573948201472905,
+    0.36083060099117575, 0.3642824681305496, 0.3677505697805962, 0.37123505766982134,
+    0.3747360871394914, 0.3782538172472381, 0.38178841087503135, 0.38534003484173396,
+    0.3889088600204646, 0.39249506146101076, 0.3960988185175471, 0.39972031498193167,
+    0.4033597392228689, 0.40701728433124795, 0.4106931482719832, 0.4143875340427068,
+    0.4181006498396846, 0.4218327092313533, 0.4255839313399006, 0.4293545410313415,
+    0.43314476911457406, 0.4369548525499293, 0.4407850346677699, 0.44463556539772775,
+    0.44850670150921407, 0.4523987068638825, 0.45631185268077357, 0.4602464178149235,
+    0.46420268905027884, 0.46818096140782217, 0.47218153846988326, 0.4762047327216838,
+    0.4802508659112497, 0.4843202694289116, 0.48841328470771206, 0.49253026364614866,
+    0.4966715690547963, 0.5008375751284821, 0.5050286679458288, 0.5092452459981361,
+    0.513487720749743, 0.5177565172322006, 0.5220520746747949, 0.5263748471741867,
+    0.5307253044061939, 0.5351039323830196, 0.5395112342595446, 0.5439477311926499,
+    0.5484139632579211, 0.5529104904285199, 0.5574378936214863, 0.5619967758172779,
+    0.5665877632589518, 0.571211506738075, 0.5758686829752105, 0.5805599961036835,
+    0.5852861792663003, 0.590047996335792, 0.5948462437709913, 0.5996817526221677,
+    0.6045553907005495, 0.6094680649288954, 0.6144207238920768, 0.6194143606090392,
+    0.6244500155502742, 0.6295287799281283, 0.63465179929096, 0.639820277456439,
+    0.6450354808242519, 0.6502987431142946, 0.6556114705832247, 0.6609751477802414,
+    0.6663913439123806, 0.6718617199007664, 0.6773880362225131, 0.6829721616487914,
+    0.6886160830085271, 0.6943219161300326, 0.7000919181404901, 0.7059285013367974,
+    0.7118342488823585, 0.7178119326349014, 0.7238645334728816, 0.7299952645658024,
+    0.7362075981312667, 0.7425052963446362, 0.7488924472237267, 0.7553735065117545,
+    0.7619533468415465, 0.7686373158033348, 0.7754313049861383, 0.7823418326598619,
+    0.7893761435711986, 0.7965423304282546, 0.8038494831763895, 0.8113078743182199,
+    0.8189291916094148, 0.8267268339520942, 0.8347162929929304, 0.8429156531184411,
+    0.8513462584651237, 0.8600336212030086, 0.8690086880437932, 0.8783096558161468,
+    0.8879846607633999, 0.898095921906304, 0.9087264400605629, 0.9199915050483602,
+    0.9320600759689902, 0.945198953453078, 0.9598790918124159, 0.9771017012827313,
+    1.0,
+];
+
+/// Start of the exponential tail.
+pub(super) const EXP_R: f64 = 7.697117470131487;
+
+/// Layer edges for the standard exponential, `f(x) = exp(-x)`.
+pub(super) const EXP_X: [f64; 257] = [
+    8.697117470134852, 7.697117470131487, 6.941033629377448, 6.4783784938327305,
+    6.144164665772595, 5.8821443157954985, 5.666410167454116, 5.482890627526134,
+    5.323090505754461, 5.181487281301557, 5.054288489981356, 4.938777085901298,
+    4.832939741025155, 4.73524299660178, 4.644491885420122, 4.559737061707385,
+    4.480211746528454, 4.4052876934736025, 4.3344436803173005, 4.2672424802773925,
+    4.203313713735209, 4.142340865664075, 4.08405131040832, 4.028208544647958,
+    3.9746060666738092, 3.9230625001355097, 3.8734176703995282, 3.8255294185223554,
+    3.779270992411686, 3.734528894039815, 3.6912010902374357, 3.64919551576087,
+    3.6084288131289255, 3.5688252656483526, 3.530315889129359, 3.4928376547740747,
+    3.456332821132775, 3.420748357251134, 3.386035442460315, 3.3521490309001227,
+    3.319047470970761, 3.286692171599081, 3.255047308570462, 3.224079565286276,
+    3.1937579032122523, 3.164053358025985, 3.134938858084452, 3.1063890623398356,
+    3.0783802152541013, 3.050890016615466, 3.0238975044556873, 2.9973829495161413,
+    2.9713277599211, 2.945714394895056, 2.9205262865127506, 2.895747768600151,
+    2.8713640120155457, 2.847360965635198, 2.8237253024500446, 2.800444370250747,
+    2.777506146439766, 2.754899196562354, 2.7326126361947094, 2.7106360958679376,
+    2.688959688741812, 2.667573980773275, 2.646469963151817, 2.6256390267977965,
+    2.6050729387408436, 2.5847638202141487, 2.5647041263169132, 2.544886627111878,
+    2.5253043900378356, 2.5059507635286016, 2.4868193617402174, 2.467904050297373,
+    2.4491989329782577, 2.4306983392644277, 2.4123968126888786, 2.394289099921466,
+    2.376370140536148, 2.358635057409345, 2.341079147703042, 2.3236978743902035,
+    2.306486858283587, 2.2894418705322765, 2.272558825553162, 2.2558337743672263,
+    2.2392628983129157, 2.222842503111043, 2.20656901325767, 2.1904389667232262,
+    2.174449009937781, 2.158595893043892, 2.142876465399848, 2.1272876713173745,
+    2.1118265460190484, 2.0964902118017212, 2.0812758743932314, 2.066180819490582,
+    2.0512024094685914, 2.036338080248776, 2.0215853383189324, 2.0069417578945243,
+    1.9924049782135824, 1.9779727009573662, 1.9636426877895539, 1.9494127580071905,
+    1.935280786297057, 1.9212447005915334, 1.9073024800183926, 1.8934521529393133,
+    1.8796917950722163, 1.866019527692833, 1.8524335159111807, 1.8389319670188848,
+    1.8255131289035245, 1.8121752885263953, 1.7989167704602955, 1.7857359354841305,
+    1.7726311792313099, 1.759600930889079, 1.7466436519460786, 1.733757834985576,
+    1.7209420025219397, 1.7081947058780622, 1.6955145241015424, 1.6829000629175583,
+    1.6703499537164566, 1.6578628525741772, 1.645437439303728, 1.633072416535996,
+    1.6207665088282623, 1.6085184617988628, 1.5963270412864878, 1.5841910325326933,
+    1.5721092393862341, 1.5600804835278925, 1.548103603714518, 1.5361774550410365,
+    1.5243009082192307, 1.5124728488721215, 1.5006921768428212, 1.4889578055167503,
+    1.477268661156138, 1.4656236822457496, 1.4540218188487977, 1.4424620319720167,
+    1.430943292938884, 1.4194645827699874, 1.40802489156954, 1.3966232179170464,
+    1.3852585682631264, 1.373929956328495, 1.3626364025050912, 1.3513769332583396,
+    1.340150580529509, 1.328956381137121, 1.3177933761763292, 1.3066606104151786,
+    1.2955571316866052, 1.284481990275017, 1.2734342382962456, 1.2624129290696198,
+    1.251417116480857, 1.240445854334411, 1.2294981956938538, 1.2185731922087948,
+    1.2076698934267658, 1.1967873460884078, 1.185924593404207, 1.1750806743109163,
+    1.1642546227056838, 1.1534454666557794, 1.1426522275816775, 1.1318739194110834,
+    1.1211095477013353, 1.110358108727416, 1.0996185885326022, 1.0888899619385517,
+    1.078171191511377, 1.0674612264799723, 1.056759001602556, 1.0460634359770489,
+    1.0353734317905332, 1.024687873002622, 1.0140056239571014, 1.0033255279157016,
+    0.9926464055072807, 0.9819670530850673, 0.9712862409839081, 0.9606027116686713,
+    0.9499151777640809, 0.9392223199552673, 0.9285227847472154, 0.9178151820700491,
+    0.9070980827156953, 0.8963700155898949, 0.8856294647617564, 0.8748748662910301,
+    0.8641046048110094, 0.8533170098423782, 0.8425103518103736, 0.8316828377342782,
+    0.8208326065544168, 0.8099577240574233, 0.7990561773554922, 0.7881258688694976,
+    0.7771646097591348, 0.7661701127354397, 0.7551399841819872, 0.7440717155005131,
+    0.7329626735843704, 0.7218100903087613, 0.7106110509096601, 0.6993624811032372,
+    0.6880611327737531, 0.6767035680295279, 0.665286141392683, 0.6538049798476703,
+    0.6422559604245417, 0.6306346849334957, 0.6189364513948814, 0.6071562216203056,
+    0.5952885842915083, 0.5833277127487749, 0.5712673165325937, 0.5591005855115458,
+    0.5468201251633157, 0.5344178812371709, 0.5218850515921405, 0.5092119824436598,
+    0.49638804551867644, 0.4834014916534671, 0.47023927508217434, 0.4568868409314256,
+    0.44332786607355795, 0.4295439402254163, 0.41551416960036214, 0.4012146788962836,
+    0.38661797794112546, 0.3716921453299232, 0.3563997602583998, 0.3406964810648554,
+    0.3245291170169159, 0.30783295467493893, 0.29052795549123756, 0.27251318547847214,
+    0.2536583633859198, 0.23379048305968286, 0.21267151063097528, 0.18995868962244125,
+    0.16512762256419763, 0.1373049809400244, 0.10483850756583295, 0.06385216381502062,
+    0.0,
+];
+
+/// `exp(-x)` at each entry of [`EXP_X`].
+pub(super) const EXP_F: [f64; 257] = [
+    0.00016706669230732857, 0.00045413435384129814, 0.0009672692823269466, 0.0015362997803013256,
+    0.002145967743718644, 0.0027887987935738007, 0.0034602647778366183, 0.004157295120833498,
+    0.004877655983542088, 0.005619642207205169, 0.006381905937318855, 0.007163353183634653,
+    0.007963077438016703, 0.008780314985808634, 0.009614413642501863, 0.010464810181029629,
+    0.011331013597834238, 0.01221259242625502, 0.01310916493125463, 0.014020391403181568,
+    0.014945968011690777, 0.015885621839972792, 0.016839106826039566, 0.017806200410910977,
+    0.01878670074469564, 0.019780424338009344, 0.020787204072577718, 0.021806887504283175,
+    0.022839335406384824, 0.02388442051155775, 0.024942026419731367, 0.026012046645133794,
+    0.02709438378095537, 0.028188948763978205, 0.029295660224636953, 0.030414443910466164,
+    0.031545232172893165, 0.03268796350895909, 0.03384258215087388, 0.035009037697396966,
+    0.03618728478193098, 0.03737728277295892, 0.038578995503074406, 0.03979239102337366,
+    0.04101744138041435, 0.04225412241331575, 0.0435024135688877, 0.04476229773294279,
+    0.04603376107617467, 0.04731679291318104, 0.04861138557337898, 0.049917534282705844,
+    0.051235237055125754, 0.05256449459307115, 0.05390531019604555, 0.05525768967669652,
+    0.05662164128374234, 0.05799717563120012, 0.059384305633419725, 0.06078304644547909,
+    0.06219341540854045, 0.06361543199980678, 0.0650491177867532, 0.06649449638533923,
+    0.06795159342193606, 0.0694204364987282, 0.07090105516237127, 0.07239348087570817,
+    0.07389774699236415, 0.0754138887340578, 0.0769419431704799, 0.07848194920160581,
+    0.0800339475423193, 0.0815979807092368, 0.08317409300963173, 0.08476233053236747,
+    0.08636274114075623, 0.08797537446726954, 0.08960028191003216, 0.09123751663103948,
+    0.09288713355604286, 0.09454918937605515, 0.0962237425504321, 0.0979108533114915,
+    0.09961058367063642, 0.10132299742595291, 0.10304816017125697, 0.10478613930656941,
+    0.10653700405000092, 0.10830082545103308, 0.11007767640518468, 0.11186763167005559,
+    0.11367076788274358, 0.11548716357863278, 0.1173168992115548, 0.1191600571753269,
+    0.12101672182667404, 0.12288697950954434, 0.12477091858083016, 0.1266686294375098,
+    0.12858020454522734, 0.13050573846832997, 0.1324453279013867, 0.13439907170221282,
+    0.13636707092642805, 0.1383494288635794, 0.14034625107486162, 0.14235764543247137,
+    0.14438372216063391, 0.1464245938783441, 0.14848037564386596, 0.15055118500103906,
+    0.15263714202744202, 0.15473836938446722, 0.15685499236936437, 0.15898713896931335,
+    0.16113493991759117, 0.16329852875190096, 0.16547804187493517, 0.16767361861724936,
+    0.16988540130252683, 0.17211353531531923, 0.17435816917135266, 0.17661945459049405,
+    0.17889754657247747, 0.18119260347549546, 0.1835047870977666, 0.18583426276219625,
+    0.18818119940425343, 0.1905457696631945, 0.19292814997677044, 0.1953285206795623,
+    0.19774706610509796, 0.20018397469191032, 0.20263943909370807, 0.20511365629383677,
+    0.20760682772422107, 0.2101191593889873, 0.21265086199297728, 0.21520215107537768,
+    0.2177732471486995, 0.22036437584335844, 0.22297576805811914, 0.225607660116683,
+    0.22826029393071565, 0.23093391716962638, 0.2336287834374323, 0.2363451524570586,
+    0.2390832902624481, 0.2418434693988761, 0.244625969131891, 0.2474310756653265,
+    0.25025908236886113, 0.2531102900156283, 0.25598500703041416, 0.258883549749015,
+    0.26180624268936176, 0.264753418835061, 0.2677254199320436, 0.2707225967990588,
+    0.27374530965280175, 0.2767939284485161, 0.27986883323697165, 0.2829704145387795,
+    0.28609907373707555, 0.2892552234896764, 0.29243928816189124, 0.29565170428125986,
+    0.2988929210155804, 0.30216340067569214, 0.3054636192445888, 0.30879406693455874,
+    0.3121552487741781, 0.3155476852271275, 0.31897191284495574, 0.3224284849560876,
+    0.3259179723935547, 0.32944096426413483, 0.33299806876180743, 0.3365899140286761,
+    0.3402171490667786, 0.343880444704501, 0.3475804946216355, 0.35131801643748184,
+    0.35509375286678596, 0.3589084729487482, 0.3627629733548162, 0.3666580797815126,
+    0.37059464843514445, 0.3745735676159006, 0.37859575940957924, 0.3826621814960082,
+    0.38677382908413604, 0.39093173698479544, 0.3951369818332885, 0.3993906844752294,
+    0.40369401253052856, 0.4080481831520307, 0.41245446599715946, 0.41691418643300115,
+    0.42142872899761485, 0.42599954114303257, 0.430628137288457, 0.43531610321563474,
+    0.440065100842352, 0.4448768734145466, 0.44975325116275305, 0.4546961574746135,
+    0.4597076156421357, 0.4647897562504242, 0.469944825283958, 0.4751751930373753,
+    0.48048336393045216, 0.4858719873418828, 0.49134386959403037, 0.4969019872415473,
+    0.5025495018413454, 0.5082897764106405, 0.5141263938147462, 0.5200631773682312,
+    0.5261042139836173, 0.5322538802630408, 0.5385168720028594, 0.5448982376724371,
+    0.5514034165406387, 0.5580382822625849, 0.5648091929123976, 0.5717230486648232,
+    0.5787873586028424, 0.5860103184772653, 0.5934009016917305, 0.6009689663652293,
+    0.6087253820796191, 0.6166821809152047, 0.6248527387036629, 0.633251994214363,
+    0.6418967164272629, 0.6508058334145678, 0.6600008410789964, 0.6695063167319213,
+    0.6793505722647618, 0.6895664961170743, 0.7001926550827844, 0.711274760805072,
+    0.7228676595935678, 0.735038092431419, 0.7478686219851903, 0.7614633888498912,
+    0.7759568520401102, 0.7915276369724898, 0.808421651523002, 0.8269932966430433,
+    0.8477855006239817, 0.8717043323811944, 0.9004699299257349, 0.9381436808621586,
+    1.0,
+];
@@ -0,0 +1,148 @@
+//! Filling typed buffers with random values.
+//!
+//! [`Fill`] extends [`crate::Rng::fill_bytes`] to slices, arrays and vectors of other
+//! primitive types. Integers narrower than 64 bits are packed several to a draw: one
+//! `next_u64` supplies eight bytes, four `u16`s or two `u32`s, split in little-endian order
+//! so the result is the same on every platform. `usize` and `isize` take a whole draw per
+//! element whatever their width, so on 32-bit targets they hold the low half of the values
+//! a 64-bit target produces. `f64` uses one draw per element like
+//! [`crate::Rng::next_f64`], and `f32` takes two 24-bit lanes from each draw.
+
+use crate::backend::RandomBackend;
+
+/// Buffers that can be overwritten with random values.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64};
+///
+/// let mut rng = Rng::new(SplitMix64::new(8));
+/// let mut table = [0u32; 256];
+/// rng.fill(&mut table);
+/// let mut weights = [0.0f64; 100];
+/// rng.fill(&mut weights);
+/// assert!(weights.iter().all(|w| (0.0..1.0).contains(w)));
+/// ```
+pub trait Fill {
+    /// Overwrites every element with a value drawn from `backend`.
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B);
+}
+
+/// Implements [`Fill`] for integer slices by splitting each `next_u64` into
+/// little-endian lanes.
+macro_rules! impl_fill_packed {
+    ($($t:ty),*) => {$(
+        impl Fill for [$t] {
+            fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+                const SIZE: usize = core::mem::size_of::<$t>();
+                for chunk in self.chunks_mut(8 / SIZE) {
+                    let word = backend.next_u64().to_le_bytes();
+                    for (dst, lane) in chunk.iter_mut().zip(word.chunks_exact(SIZE)) {
+                        let mut bytes = [0u8; SIZE];
+                        bytes.copy_from_slice(lane);
+                        *dst = <$t>::from_le_bytes(bytes);
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+impl_fill_packed!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+impl Fill for [usize] {
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        self.iter_mut().for_each(|x| *x = backend.next_u64() as usize);
+    }
+}
+
+impl Fill for [isize] {
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        self.iter_mut().for_each(|x| *x = backend.next_u64() as isize);
+    }
+}
+
+impl Fill for [u128] {
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        self.iter_mut().for_each(|x| *x = backend.next_u128());
+    }
+}
+
+impl Fill for [i128] {
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        self.iter_mut().for_each(|x| *x = backend.next_i128());
+    }
+}
+
+impl Fill for [f64] {
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        self.iter_mut().for_each(|x| *x = backend.next_f64());
+    }
+}
+
+impl Fill for [f32] {
+    /// Uses the top 24 bits of each 32-bit half of a draw, high half first.
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        const SCALE: f32 = 1.0 / ((1u32 << 24) as f32);
+        for chunk in self.chunks_mut(2) {
+            let word = backend.next_u64();
+            let lanes = [(word >> 40) as u32, ((word >> 8) & 0xFF_FFFF) as u32];
+            for (dst, lane) in chunk.iter_mut().zip(lanes) {
+                *dst = lane as f32 * SCALE;
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Fill for [T; N]
+where
+    [T]: Fill,
+{
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        self[..].fill_from(backend)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Fill for alloc::vec::Vec<T>
+where
+    [T]: Fill,
+{
+    fn fill_from<B: RandomBackend + ?Sized>(&mut self, backend: &mut B) {
+        self[..].fill_from(backend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn lanes_are_packed_little_endian() {
+        let mut rng = Rng::new(SplitMix64::new(6));
+        let mut plain = SplitMix64::new(6);
+
+        let mut halves = [0u32; 3];
+        rng.fill(&mut halves);
+        let word = plain.next_u64();
+        assert_eq!(halves[..2], [word as u32, (word >> 32) as u32]);
+        assert_eq!(halves[2], plain.next_u64() as u32);
+
+        let mut floats = [0.0f32; 2];
+        rng.fill(&mut floats);
+        let word = plain.next_u64();
+        assert_eq!(floats[0], (word >> 40) as f32 / (1u32 << 24) as f32);
+        assert!(floats.iter().all(|f| (0.0..1.0).contains(f)));
+
+        let mut bytes = [0u8; 8];
+        rng.fill(&mut bytes);
+        assert_eq!(bytes, plain.next_u64().to_le_bytes());
+
+        let mut sizes = [0usize; 2];
+        rng.fill(&mut sizes);
+        assert_eq!(sizes, [plain.next_u64() as usize, plain.next_u64() as usize]);
+    }
+}
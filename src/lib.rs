@@ -35,11 +35,61 @@
 //! # Available Backends
 //!
 //! - `XorShift`: Fast and simple algorithm
+//! - `XorShift128Plus`: JavaScript-engine compatible xorshift variant
+//! - `XorWow`: cuRAND-compatible XORWOW generator
 //! - `PCG`: High-quality permuted congruential generator
+//! - `Pcg64Dxsm`: 128-bit PCG matching NumPy's default bit generator
+//! - `Pcg32`, `Pcg32Fast`: PCG32 variants with native `u32` output
 //! - `LCG`: Linear congruential generator
 //! - `MT19937_64`: 64-bit Mersenne Twister
+//! - `MT19937`: 32-bit Mersenne Twister compatible with C++ `std::mt19937`
+//! - `Well19937a`: WELL generator with Mersenne Twister period and better equidistribution
 //! - `SplitMix64`: Fast, simple generator good for initialization
 //! - `Xoshiro256StarStar`: Modern, high-quality generator
+//! - `Xoshiro256PlusPlus`: Recommended general-purpose xoshiro variant
+//! - `Xoshiro256Plus`: Fastest xoshiro variant for floating-point output
+//! - `Xoshiro512StarStar`: Large-state xoshiro with `jump`/`long_jump`
+//! - `RomuTrio`, `RomuDuoJr`: Very fast nonlinear generators with probabilistic periods
+//! - `Cmwc`: Complementary multiply-with-carry with a very long period
+//! - `Isaac64`: Fast generator with cryptographic heritage
+//! - `ChaCha20`: Cryptographically secure stream-cipher generator
+//! - `Philox`: Counter-based generator with random access for parallel work
+//! - `Squares`: Lightweight counter-based generator with a 64-bit key and counter
+//! - `HmacDrbg`: NIST SP 800-90A HMAC_DRBG (HMAC-SHA256) deterministic CSPRNG
+//! - `CtrDrbg`: NIST SP 800-90A CTR_DRBG (AES-256) deterministic CSPRNG
+//! - `Fortuna`: CSPRNG with entropy pools and automatic reseeding
+//! - `OsRandom`: Operating system entropy (requires the `getrandom` feature)
+//! - `RdRand`, `RdSeed`: x86_64 hardware entropy instructions (requires the `rdrand` feature)
+//!
+//! # Quasi-Random Sequences
+//!
+//! The [`quasi`] module provides low-discrepancy sequences (`Halton`, `R2` and `Sobol`) through the
+//! [`quasi::QuasiSequence`] trait.
+//!
+//! # Sampling
+//!
+//! The [`sampling`] module provides design-of-experiments helpers such as Latin hypercube
+//! sampling.
+//!
+//! # Sequences
+//!
+//! The [`seq`] module provides the [`SliceRandom`] extension trait, with an unbiased
+//! in-place shuffle and sampling for slices, and [`IteratorRandom`] for choosing from
+//! iterators.
+//!
+//! # Distributions
+//!
+//! The [`distributions`] module provides the [`distributions::Distribution`] trait and
+//! distributions such as [`distributions::Bernoulli`] and [`distributions::Uniform`].
+//!
+//! # Thread-Local Generator
+//!
+//! With the `std` and `getrandom` features, [`thread_rng`] returns a per-thread generator
+//! seeded from the OS, and [`random`] and [`random_range`] draw single values from it.
+//!
+//! # Seeding
+//!
+//! The [`seed`] module derives reproducible seeds from labels such as `"experiment-42"`.
 //!
 //! # Implementing Custom Backends
 //!
@@ -60,11 +110,41 @@
 //! }
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod backend;
+mod builder;
+pub mod distributions;
+mod fill;
+pub mod quasi;
 mod rng;
+pub mod sampling;
+pub mod seed;
+pub mod seq;
+#[cfg(feature = "serde")]
+mod serde_array;
+mod standard;
+#[cfg(feature = "std")]
+mod sync;
+#[cfg(all(feature = "std", feature = "getrandom"))]
+mod thread;
+mod uniform;
 
-pub use backend::RandomBackend;
+pub use backend::{
+    Algorithm, AtomicBackend, BackendInfo, BlockBackend, Jumpable, RandomBackend, Reversible, SeedBytes, SeedableBackend, Skippable,
+    Splittable, StateSnapshot, Streamable,
+};
+pub use builder::RngBuilder;
+pub use fill::Fill;
 pub use rng::Rng;
+pub use seq::{IteratorRandom, SliceRandom};
+pub use standard::Random;
+pub use uniform::SampleUniform;
+#[cfg(feature = "std")]
+pub use sync::SyncRng;
+#[cfg(all(feature = "std", feature = "getrandom"))]
+pub use thread::{ThreadRng, random, random_range, thread_rng};
 
 /// Errors produced by this crate.
 #[derive(Debug, Clone, PartialEq)]
@@ -73,8 +153,25 @@ pub enum AporiaError {
     InvalidRangeU64 { min: u64, max: u64 },
     /// The provided floating-point range is invalid (min >= max).
     InvalidRangeF64 { min: f64, max: f64 },
+    /// The provided range of a signed or 128-bit integer type is invalid (min >= max), or
+    /// the range has bounds that cannot be sampled (e.g. `..10`).
+    InvalidRange { ty: &'static str },
     /// The provided seed is invalid for the backend (e.g., zero for XorShift).
     InvalidSeed(&'static str),
+    /// A hardware or operating system entropy source is unavailable or failed.
+    EntropyUnavailable(&'static str),
+    /// A deterministic random bit generator request was rejected (e.g., reseed required).
+    Drbg(&'static str),
+    /// No built-in backend has the requested name.
+    UnknownBackend,
+    /// A state snapshot has the wrong length or describes an invalid state.
+    InvalidState(&'static str),
+    /// A stream was requested from a backend that does not implement `Streamable`.
+    StreamsUnsupported,
+    /// A distribution parameter is outside its domain (e.g., a probability above 1).
+    InvalidParameter(&'static str),
+    /// Every weight of a weighted choice is zero, so no item can be chosen.
+    AllWeightsZero,
 }
 
 impl core::fmt::Display for AporiaError {
@@ -86,7 +183,19 @@ impl core::fmt::Display for AporiaError {
             AporiaError::InvalidRangeF64 { min, max } => {
                 write!(f, "invalid f64 range: min ({}) must be < max ({})", min, max)
             }
+            AporiaError::InvalidRange { ty } => {
+                write!(f, "invalid {} range: expected `min..max` with min < max or `min..=max`", ty)
+            }
             AporiaError::InvalidSeed(reason) => write!(f, "invalid seed: {}", reason),
+            AporiaError::EntropyUnavailable(reason) => {
+                write!(f, "entropy unavailable: {}", reason)
+            }
+            AporiaError::Drbg(reason) => write!(f, "DRBG error: {}", reason),
+            AporiaError::UnknownBackend => write!(f, "unknown backend name"),
+            AporiaError::InvalidState(reason) => write!(f, "invalid state snapshot: {}", reason),
+            AporiaError::StreamsUnsupported => write!(f, "backend does not support streams"),
+            AporiaError::InvalidParameter(reason) => write!(f, "invalid parameter: {}", reason),
+            AporiaError::AllWeightsZero => write!(f, "all weights are zero"),
         }
     }
 }
@@ -109,4 +218,29 @@ mod tests {
         let float = rng.next_f64();
         assert!((0.0..1.0).contains(&float));
     }
+
+    #[test]
+    fn backend_info_reports_metadata() {
+        use backend::{ChaCha8, Xoshiro256StarStar};
+        assert_eq!(Xoshiro256StarStar::name(), "xoshiro256**");
+        assert_eq!(Xoshiro256StarStar::period_log2(), Some(256));
+        assert_eq!(backend::XorWow::period_log2(), Some(191));
+        assert_eq!(Xoshiro256StarStar::state_bytes(), 32);
+        assert_eq!(ChaCha8::name(), "chacha8");
+        assert_eq!(XorShift::state_bytes(), 8);
+    }
+
+    #[test]
+    fn const_constructors_initialize_statics() {
+        use backend::{LCG, PCG, SplitMix64};
+        static XORSHIFT: XorShift = XorShift::new(7);
+        static LCG_STATE: LCG = LCG::new(1);
+        static SPLITMIX: SplitMix64 = SplitMix64::new(3);
+        static PCG_STATE: PCG = PCG::new(42, 54);
+
+        // First output of the reference pcg32 demo, which steps once after seeding.
+        assert_eq!(PCG_STATE.clone().next_u32(), 2707161783);
+        let draws = [XORSHIFT.clone().next_u64(), LCG_STATE.clone().next_u64(), SPLITMIX.clone().next_u64()];
+        assert!(draws.iter().all(|&d| d != 0));
+    }
 }
@@ -0,0 +1,88 @@
+//! Quasi-random (low-discrepancy) sequences.
+//!
+//! Quasi-random sequences fill the unit hypercube `[0, 1)^D` more evenly than independent
+//! random points. Quasi-Monte Carlo integration with them typically converges close to
+//! `O(1/N)` instead of the `O(1/sqrt(N))` of plain Monte Carlo, which makes them useful for
+//! numerical integration, rendering and design of experiments.
+//!
+//! These generators are deterministic and are not [`crate::RandomBackend`]s: consecutive
+//! values are strongly correlated by design. Randomized variants (such as scrambled
+//! sequences) take a backend to draw their scrambling parameters from.
+//!
+//! Each sequence implements [`QuasiSequence`] with the dimension fixed at compile time, so
+//! points are returned as `[f64; D]` arrays without allocation.
+//!
+//! # Available Sequences
+//!
+//! - [`Halton`]: Radical-inverse sequence in the first `D` prime bases, with optional digit
+//!   scrambling and leaping
+//! - [`R2`]: Roberts' generalized golden ratio additive recurrence, very cheap per point
+//! - [`Sobol`]: Base-2 digital sequence with Joe-Kuo direction numbers and optional Owen
+//!   scrambling for randomized quasi-Monte Carlo
+//!
+//! # Examples
+//!
+//! ```rust
+//! use aporia::quasi::{Halton, QuasiSequence};
+//!
+//! let mut halton = Halton::<2>::new();
+//! let [x, y] = halton.next_point();
+//! assert_eq!((x, y), (0.5, 1.0 / 3.0));
+//!
+//! // Iterate over points, like `Rng::iter_f64`.
+//! let inside = halton
+//!     .iter_points()
+//!     .take(1000)
+//!     .filter(|[x, y]| x * x + y * y < 1.0)
+//!     .count();
+//! let pi_estimate = 4.0 * inside as f64 / 1000.0;
+//! ```
+
+pub use self::halton::Halton;
+pub use self::r2::R2;
+pub use self::sobol::Sobol;
+
+mod halton;
+mod r2;
+mod sobol;
+
+/// Trait for `D`-dimensional quasi-random sequences over the unit hypercube `[0, 1)^D`.
+pub trait QuasiSequence<const D: usize> {
+    /// Returns the next point of the sequence.
+    fn next_point(&mut self) -> [f64; D];
+
+    /// Skips the next `n` points.
+    ///
+    /// The default implementation draws and discards points; sequences with random access
+    /// override it.
+    fn skip(&mut self, n: u64) {
+        for _ in 0..n {
+            self.next_point();
+        }
+    }
+
+    /// Returns an iterator over the following points of the sequence.
+    fn iter_points(&mut self) -> Points<'_, Self, D>
+    where
+        Self: Sized,
+    {
+        Points { seq: self }
+    }
+}
+
+/// Iterator over points of a [`QuasiSequence`], created by [`QuasiSequence::iter_points`].
+pub struct Points<'a, Q, const D: usize> {
+    seq: &'a mut Q,
+}
+
+impl<Q: QuasiSequence<D>, const D: usize> Iterator for Points<'_, Q, D> {
+    type Item = [f64; D];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.seq.next_point())
+    }
+}
+
+/// The largest `f64` strictly below 1.0, used to keep scrambled values in `[0, 1)`.
+const BELOW_ONE: f64 = 1.0 - f64::EPSILON / 2.0;
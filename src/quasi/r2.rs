@@ -0,0 +1,159 @@
+//! R2 ("generalized golden ratio") additive-recurrence sequence.
+//!
+//! Roberts' R_d sequence uses one additive recurrence per dimension:
+//! `x_n = (s + n * alpha) mod 1` with `alpha_j = phi_d^-(j + 1)`, where `phi_d` is the
+//! unique positive root of `x^(d+1) = x + 1` (the golden ratio for `d = 1` and the plastic
+//! number for `d = 2`, hence the name R2). It is extremely cheap to evaluate, has no
+//! preferred number of points, and gives good blue-noise-like coverage in low dimensions,
+//! which makes it popular for graphics sampling.
+//!
+//! The recurrence is evaluated in 64-bit fixed point, so any index can be computed
+//! directly and the sequence does not drift over long runs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::quasi::{QuasiSequence, R2};
+//!
+//! let mut r2 = R2::<2>::new();
+//! for [u, v] in r2.iter_points().take(64) {
+//!     assert!((0.0..1.0).contains(&u) && (0.0..1.0).contains(&v));
+//! }
+//! ```
+//!
+//! # References
+//!
+//! - [Roberts (2018), "The Unreasonable Effectiveness of Quasirandom Sequences"](https://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/)
+
+use super::QuasiSequence;
+use crate::backend::RandomBackend;
+
+/// R_d additive-recurrence sequence generator in `D` dimensions.
+#[derive(Clone, Debug)]
+pub struct R2<const D: usize> {
+    /// Per-dimension increment as a 64-bit binary fraction.
+    alpha: [u64; D],
+    /// Per-dimension starting offset `s` as a 64-bit binary fraction.
+    offset: [u64; D],
+    index: u64,
+}
+
+impl<const D: usize> R2<D> {
+    /// Creates the sequence with the customary starting offset `s = 0.5` in every dimension.
+    pub fn new() -> Self {
+        let phi = generalized_golden_ratio(D);
+        let mut alpha = [0u64; D];
+        let mut power = 1.0;
+        for a in alpha.iter_mut() {
+            power /= phi;
+            *a = to_fixed(power);
+        }
+        Self {
+            alpha,
+            offset: [1 << 63; D],
+            index: 0,
+        }
+    }
+
+    /// Creates the sequence with a uniformly random offset per dimension drawn from
+    /// `backend` (a Cranley-Patterson rotation), for randomized quasi-Monte Carlo.
+    pub fn randomized<B: RandomBackend>(backend: &mut B) -> Self {
+        let mut r2 = Self::new();
+        for offset in r2.offset.iter_mut() {
+            *offset = backend.next_u64();
+        }
+        r2
+    }
+
+    /// Returns the per-dimension increments `alpha`.
+    pub fn alpha(&self) -> [f64; D] {
+        self.alpha.map(from_fixed)
+    }
+
+    /// Returns the sequence index of the next point.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the point at sequence index `index` without changing the current position.
+    pub fn point_at(&self, index: u64) -> [f64; D] {
+        core::array::from_fn(|j| {
+            from_fixed(self.offset[j].wrapping_add(self.alpha[j].wrapping_mul(index)))
+        })
+    }
+}
+
+/// Returns the unique positive root of `x^(d+1) = x + 1`, found with Newton's method.
+fn generalized_golden_ratio(d: usize) -> f64 {
+    let mut x = 2.0f64;
+    for _ in 0..64 {
+        let mut pow_d = 1.0;
+        for _ in 0..d {
+            pow_d *= x;
+        }
+        let f = pow_d * x - x - 1.0;
+        let df = (d as f64 + 1.0) * pow_d - 1.0;
+        let next = x - f / df;
+        if next == x {
+            break;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Converts a value in `[0, 1)` to a 64-bit binary fraction.
+fn to_fixed(x: f64) -> u64 {
+    (x * 18_446_744_073_709_551_616.0) as u64
+}
+
+/// Converts a 64-bit binary fraction to `f64` in `[0, 1)` using its top 53 bits.
+fn from_fixed(x: u64) -> f64 {
+    (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+impl<const D: usize> Default for R2<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const D: usize> QuasiSequence<D> for R2<D> {
+    fn next_point(&mut self) -> [f64; D] {
+        let point = self.point_at(self.index);
+        self.index = self.index.wrapping_add(1);
+        point
+    }
+
+    fn skip(&mut self, n: u64) {
+        self.index = self.index.wrapping_add(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn r2_uses_generalized_golden_ratios() {
+        assert!((generalized_golden_ratio(1) - 1.618_033_988_749_895).abs() < 1e-15);
+        assert!((generalized_golden_ratio(2) - 1.324_717_957_244_746).abs() < 1e-15);
+
+        let [a1, a2] = R2::<2>::new().alpha();
+        assert!((a1 - 0.754_877_666_246_692_7).abs() < 1e-15);
+        assert!((a2 - 0.569_840_290_998_053_3).abs() < 1e-15);
+    }
+
+    #[test]
+    fn r2_recurrence_and_random_access() {
+        let mut r2 = R2::<2>::new();
+        let [a1, a2] = r2.alpha();
+        assert_eq!(r2.next_point(), [0.5, 0.5]);
+        let [x, y] = r2.next_point();
+        assert!((x - (0.5 + a1 - 1.0)).abs() < 1e-15);
+        assert!((y - (0.5 + a2 - 1.0)).abs() < 1e-15);
+
+        r2.skip(100);
+        assert_eq!(r2.next_point(), R2::<2>::new().point_at(102));
+    }
+}
@@ -3,11 +3,14 @@
 //! This type offers convenience methods on top of backends that implement
 //! [`crate::backend::RandomBackend`], including `next_u64`, `next_u32`, `next_f64`,
 //! `next_f32`, `next_bool`, unbiased `gen_range`, and byte-filling utilities.
-//! It also provides lightweight iterators over `u64` and `f64` values.
+//! It also provides lightweight iterators over raw values, ranges and distributions.
 //!
-//! Note: These generators are not intended for cryptographic purposes.
+//! Note: Apart from the ChaCha20, DRBG, Fortuna and OS/hardware entropy backends, these
+//! generators are not intended for cryptographic purposes.
 
-use crate::backend::RandomBackend;
+use crate::backend::{RandomBackend, SeedBytes, SeedableBackend, Splittable, Streamable, mix64};
+use crate::distributions::{Distribution, SampleIter, Uniform};
+use crate::SampleUniform;
 
 /// A random number generator that works with any backend implementing [`RandomBackend`].
 ///
@@ -46,9 +49,14 @@ use crate::backend::RandomBackend;
 ///     if n == 4 { break; }
 /// }
 /// assert_eq!(n, 4);
+///
+/// // Constrained values straight into a collection
+/// let rolls: Vec<u8> = rng.iter_range(1..=6).unwrap().take(10).collect();
+/// assert!(rolls.iter().all(|r| (1..=6).contains(r)));
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rng<B: RandomBackend> {
-    backend: B,
+    pub(crate) backend: B,
 }
 
 impl<B: RandomBackend> Rng<B> {
@@ -84,6 +92,59 @@ impl<B: RandomBackend> Rng<B> {
         self.backend.next_f64()
     }
 
+    /// Generates the next floating-point number in the range (0, 1].
+    ///
+    /// Uses the same 53 bits as [`Rng::next_f64`], shifted up by one step of `2^-53`, so
+    /// the result is never zero and `-u.ln()` is always finite.
+    #[inline]
+    #[must_use]
+    pub fn next_f64_open_closed(&mut self) -> f64 {
+        let val = (self.backend.next_u64() >> 11) + 1;
+        (val as f64) * (1.0 / ((1u64 << 53) as f64))
+    }
+
+    /// Generates the next floating-point number in the closed range [0, 1].
+    ///
+    /// Divides the top 53 bits by `2^53 - 1`, so both endpoints can occur.
+    #[inline]
+    #[must_use]
+    pub fn next_f64_closed(&mut self) -> f64 {
+        let val = self.backend.next_u64() >> 11;
+        (val as f64) / (((1u64 << 53) - 1) as f64)
+    }
+
+    /// Generates the next floating-point number in the open range (0, 1).
+    ///
+    /// Takes the top 52 bits and adds half a step, giving values from `2^-53` to
+    /// `1 - 2^-53`, so neither `ln(u)` nor `ln(1 - u)` can be infinite.
+    #[inline]
+    #[must_use]
+    pub fn next_f64_open(&mut self) -> f64 {
+        let val = self.backend.next_u64() >> 12;
+        (val as f64 + 0.5) * (1.0 / ((1u64 << 52) as f64))
+    }
+
+    /// Generates the next floating-point number in the symmetric range [-1, 1).
+    ///
+    /// Reinterprets the draw as signed and keeps its top 54 bits with an arithmetic shift,
+    /// giving a uniform integer in `[-2^53, 2^53)` that is scaled exactly by `2^-53`.
+    #[inline]
+    #[must_use]
+    pub fn next_f64_signed(&mut self) -> f64 {
+        let val = (self.backend.next_u64() as i64) >> 10;
+        (val as f64) * (1.0 / ((1u64 << 53) as f64))
+    }
+
+    /// Generates the next 32-bit floating point number in [-1, 1).
+    ///
+    /// Like [`Rng::next_f64_signed`] with the top 25 bits, the `f32` mantissa width plus sign.
+    #[inline]
+    #[must_use]
+    pub fn next_f32_signed(&mut self) -> f32 {
+        let val = ((self.backend.next_u64() as i64) >> 39) as i32;
+        (val as f32) * (1.0 / ((1u32 << 24) as f32))
+    }
+
     /// Generates the next 32-bit unsigned integer.
     #[inline]
     #[must_use]
@@ -91,6 +152,65 @@ impl<B: RandomBackend> Rng<B> {
         self.backend.next_u32()
     }
 
+    /// Generates the next 128-bit unsigned integer, e.g. for nonces and identifiers.
+    #[inline]
+    #[must_use]
+    pub fn next_u128(&mut self) -> u128 {
+        self.backend.next_u128()
+    }
+
+    /// Generates the next 32-bit signed integer, uniform over all of `i32`.
+    #[inline]
+    #[must_use]
+    pub fn next_i32(&mut self) -> i32 {
+        self.backend.next_i32()
+    }
+
+    /// Generates the next 64-bit signed integer, uniform over all of `i64`.
+    #[inline]
+    #[must_use]
+    pub fn next_i64(&mut self) -> i64 {
+        self.backend.next_i64()
+    }
+
+    /// Generates the next 128-bit signed integer, uniform over all of `i128`.
+    #[inline]
+    #[must_use]
+    pub fn next_i128(&mut self) -> i128 {
+        self.backend.next_i128()
+    }
+
+    /// Generates a `char` uniformly distributed over all Unicode scalar values.
+    ///
+    /// Surrogate code points (`U+D800..=U+DFFF`) are never produced.
+    #[inline]
+    #[must_use]
+    pub fn next_char(&mut self) -> char {
+        crate::Random::random(&mut self.backend)
+    }
+
+    /// Generates a `char` uniformly from the scalar values in `range`, such as `'a'..='z'`.
+    ///
+    /// Same as [`Rng::gen_range`] with a `char` range; surrogates inside the range are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::InvalidRange`] if the range holds no scalar value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(2));
+    /// let letter = rng.gen_range_char('a'..='z').unwrap();
+    /// assert!(letter.is_ascii_lowercase());
+    /// ```
+    #[inline]
+    pub fn gen_range_char<R: core::ops::RangeBounds<char>>(&mut self, range: R) -> core::result::Result<char, crate::AporiaError> {
+        self.gen_range(range)
+    }
+
     /// Generates the next 32-bit floating point number in [0, 1).
     ///
     /// Uses the upper 24 bits of a `u64` sample to match `f32` mantissa width.
@@ -108,42 +228,158 @@ impl<B: RandomBackend> Rng<B> {
         (self.backend.next_u64() & 1) != 0
     }
 
+    /// Returns `true` with probability `p`.
+    ///
+    /// Builds a [`Bernoulli`](crate::distributions::Bernoulli) for a single draw; construct
+    /// the distribution once instead when sampling the same `p` repeatedly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::InvalidParameter`] unless `0.0 <= p <= 1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(4));
+    /// if rng.gen_bool(0.1).unwrap() {
+    ///     // rare event
+    /// }
+    /// ```
+    #[inline]
+    pub fn gen_bool(&mut self, p: f64) -> core::result::Result<bool, crate::AporiaError> {
+        Ok(self.sample(crate::distributions::Bernoulli::new(p)?))
+    }
+
+    /// Returns `true` with probability exactly `numerator / denominator`.
+    ///
+    /// Draws an unbiased integer in `[0, denominator)` and compares it with `numerator`, so
+    /// no floating-point rounding is involved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::InvalidParameter`] if `denominator` is zero or
+    /// `numerator > denominator`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(4));
+    /// let infected = rng.gen_ratio(3, 7).unwrap();
+    /// ```
+    #[inline]
+    pub fn gen_ratio(&mut self, numerator: u32, denominator: u32) -> core::result::Result<bool, crate::AporiaError> {
+        if denominator == 0 || numerator > denominator {
+            return Err(crate::AporiaError::InvalidParameter(
+                "ratio must satisfy numerator <= denominator and denominator > 0",
+            ));
+        }
+        Ok(self.gen_range(0..denominator)? < numerator)
+    }
+
     /// Generates a random number within the given range.
     ///
+    /// Works for every primitive integer type and for `f32`/`f64`; see [`SampleUniform`].
+    ///
     /// # Arguments
     ///
-    /// * `min` - The inclusive lower bound
-    /// * `max` - The exclusive upper bound
+    /// * `range` - A half-open `min..max` or inclusive `min..=max` range
     ///
     /// # Returns
     ///
-    /// A randomly generated number within the range [min, max)
+    /// A randomly generated number within `range`
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if `min >= max`
+    /// Returns an invalid-range [`crate::AporiaError`] if the range is empty, and
+    /// [`crate::AporiaError::InvalidRange`] for open-ended ranges such as `..10`.
     ///
     /// # Notes
     ///
-    /// Uses the unbiased "zone" rejection method to avoid modulo bias.
-    /// Let `range = max - min`. Compute `zone = u64::MAX - (u64::MAX % range)`,
-    /// which is the largest multiple of `range` that fits in a `u64`.
-    /// Draw 64-bit values until `v < zone`, then return `min + (v % range)`.
-    /// Because `zone` is an exact multiple of `range`, the modulo is uniform.
+    /// Integers use Lemire's widening-multiply method, which is unbiased and usually needs
+    /// no division. Let `range = max - min`. A 64-bit draw `v` is multiplied by `range` as
+    /// a 128-bit product whose high half is in `[0, range)`. Draws whose low half is below
+    /// `2^64 mod range` are rejected, so every result is equally likely.
+    /// Types of 32 bits or fewer instead use one `next_u32` per attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(3));
+    /// let offset: i16 = rng.gen_range(-100..100).unwrap();
+    /// let die = rng.gen_range(1..=6).unwrap();
+    /// let x = rng.gen_range(-1.0..1.0).unwrap();
+    /// ```
     #[inline]
-    pub fn gen_range(&mut self, min: u64, max: u64) -> core::result::Result<u64, crate::AporiaError> {
-        if min >= max {
-            return Err(crate::AporiaError::InvalidRangeU64 { min, max });
-        }
-        let range = max - min;
-        // Unbiased zone rejection method
-        let zone = u64::MAX - (u64::MAX % range);
-        loop {
-            let v = self.next_u64();
-            if v < zone {
-                break Ok(min + (v % range));
-            }
+    pub fn gen_range<T, R>(&mut self, range: R) -> core::result::Result<T, crate::AporiaError>
+    where
+        T: SampleUniform,
+        R: core::ops::RangeBounds<T>,
+    {
+        crate::uniform::sample_range(&mut self.backend, range)
+    }
+
+    /// Fills `dest` with values drawn uniformly from `range`.
+    ///
+    /// The range is validated and its parameters computed once for the whole slice, as with
+    /// [`Uniform`](crate::distributions::Uniform), and the values are the ones repeated
+    /// [`Rng::gen_range`] calls would produce.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Rng::gen_range`]; `dest` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Xoshiro256PlusPlus};
+    ///
+    /// let mut rng = Rng::new(Xoshiro256PlusPlus::new(2));
+    /// let mut indices = [0u64; 1024];
+    /// rng.fill_range(&mut indices, 0..100).unwrap();
+    /// assert!(indices.iter().all(|&i| i < 100));
+    /// ```
+    pub fn fill_range<T, R>(&mut self, dest: &mut [T], range: R) -> core::result::Result<(), crate::AporiaError>
+    where
+        T: SampleUniform,
+        R: core::ops::RangeBounds<T>,
+    {
+        let (low, high, inclusive) = crate::uniform::range_bounds(&range)?;
+        let sampler = T::new_sampler(low, high, inclusive)?;
+        for x in dest.iter_mut() {
+            *x = T::sample_with(&sampler, &mut self.backend);
         }
+        Ok(())
+    }
+
+    /// Generates a random number in the closed interval `[min, max]`.
+    ///
+    /// Equivalent to `gen_range(min..=max)`. The interval may span the whole domain of
+    /// `T`, e.g. `gen_range_inclusive(0, u64::MAX)`, where `max - min + 1` would overflow;
+    /// that case returns a plain full-width draw.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-range [`crate::AporiaError`] if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(3));
+    /// let any = rng.gen_range_inclusive(0, u64::MAX).unwrap();
+    /// let level = rng.gen_range_inclusive(1u8, 99).unwrap();
+    /// assert!((1..=99).contains(&level));
+    /// ```
+    #[inline]
+    pub fn gen_range_inclusive<T: SampleUniform>(&mut self, min: T, max: T) -> core::result::Result<T, crate::AporiaError> {
+        T::sample_uniform_inclusive(&mut self.backend, min, max)
     }
 
     /// Generates a random floating-point number within the given range.
@@ -157,16 +393,12 @@ impl<B: RandomBackend> Rng<B> {
     ///
     /// A randomly generated f64 value within the range [min, max)
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if `min >= max`
+    /// Returns [`crate::AporiaError::InvalidRangeF64`] if `min >= max` or either bound is NaN.
     #[inline]
     pub fn gen_range_f64(&mut self, min: f64, max: f64) -> core::result::Result<f64, crate::AporiaError> {
-        if min >= max {
-            return Err(crate::AporiaError::InvalidRangeF64 { min, max });
-        }
-        let rand = self.next_f64();
-        Ok(min + (rand * (max - min)))
+        self.gen_range(min..max)
     }
 
     /// Fills `buf` with random bytes from the backend.
@@ -184,6 +416,283 @@ impl<B: RandomBackend> Rng<B> {
     pub fn fill_bytes(&mut self, buf: &mut [u8]) {
         self.backend.fill_bytes(buf)
     }
+
+    /// Fills a typed buffer, such as `[u32; N]`, `&mut [f64]` or `Vec<u16>`, with random values.
+    ///
+    /// See [`Fill`](crate::Fill) for how values are packed into draws.
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::XorShift};
+    /// let mut rng = Rng::new(XorShift::new(1));
+    /// let mut noise = [0i16; 64];
+    /// rng.fill(&mut noise);
+    /// ```
+    #[inline]
+    pub fn fill<T: crate::Fill + ?Sized>(&mut self, dest: &mut T) {
+        dest.fill_from(&mut self.backend)
+    }
+
+    /// Generates a random value of any type implementing [`Random`](crate::Random).
+    ///
+    /// See [`Random`](crate::Random) for the distribution used for each type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Xoshiro256PlusPlus};
+    ///
+    /// let mut rng = Rng::new(Xoshiro256PlusPlus::new(5));
+    /// let point: (f64, f64) = rng.random();
+    /// let key: [u8; 16] = rng.random();
+    /// let byte = rng.random::<u8>();
+    /// ```
+    pub fn random<T: crate::Random>(&mut self) -> T {
+        T::random(&mut self.backend)
+    }
+
+    /// Alias of [`Rng::random`] for crates on editions before 2024, where `gen` is not
+    /// a reserved keyword and `rng.gen::<T>()` can be written directly.
+    pub fn r#gen<T: crate::Random>(&mut self) -> T {
+        self.random()
+    }
+
+    /// Draws one value from `dist`; the same as [`Distribution::sample`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64, distributions::Bernoulli};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(2));
+    /// let coin = Bernoulli::new(0.5).unwrap();
+    /// let heads: bool = rng.sample(coin);
+    /// ```
+    #[inline]
+    pub fn sample<T, D: Distribution<T>>(&mut self, dist: D) -> T {
+        dist.sample(self)
+    }
+
+    /// Swaps in a new backend and returns the old one.
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::XorShift};
+    /// let mut rng = Rng::new(XorShift::new(1));
+    /// let old = rng.replace_backend(XorShift::new(2));
+    /// assert_eq!(old, XorShift::new(1));
+    /// ```
+    pub fn replace_backend(&mut self, backend: B) -> B {
+        core::mem::replace(&mut self.backend, backend)
+    }
+}
+
+impl<B: SeedableBackend> Rng<B> {
+    /// Creates a new RNG whose backend is constructed from a byte seed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Xoshiro256StarStar};
+    ///
+    /// let mut rng = Rng::<Xoshiro256StarStar>::from_seed([7u8; 32]);
+    /// let _ = rng.next_u64();
+    /// ```
+    pub fn from_seed(seed: B::Seed) -> Self {
+        Rng::new(B::from_seed(seed))
+    }
+
+    /// Creates a new RNG whose backend is constructed from a `u64` seed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Pcg64Dxsm};
+    ///
+    /// let mut rng = Rng::<Pcg64Dxsm>::from_seed_u64(42);
+    /// let _ = rng.next_u64();
+    /// ```
+    pub fn from_seed_u64(seed: u64) -> Self {
+        Rng::new(B::from_seed_u64(seed))
+    }
+
+    /// Creates a new RNG seeded deterministically from any hashable value.
+    ///
+    /// The value is hashed with [`crate::seed::SeedHasher`] and the result passed to
+    /// [`Rng::from_seed_u64`], so composite keys need no hand-rolled mixing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Xoshiro256PlusPlus};
+    ///
+    /// let user_id = 1234u64;
+    /// let mut a = Rng::<Xoshiro256PlusPlus>::seeded_from(&(user_id, "2024-05-01"));
+    /// let mut b = Rng::<Xoshiro256PlusPlus>::seeded_from(&(user_id, "2024-05-01"));
+    /// assert_eq!(a.next_u64(), b.next_u64());
+    /// ```
+    pub fn seeded_from<T: core::hash::Hash + ?Sized>(value: &T) -> Self {
+        Self::from_seed_u64(crate::seed::seed_from_hash(value))
+    }
+
+    /// Reseeds the backend in place from a byte seed.
+    ///
+    /// Afterwards the generator produces the same sequence as `Rng::from_seed(seed)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::ChaCha20};
+    ///
+    /// let mut rng = Rng::<ChaCha20>::from_seed([1u8; 32]);
+    /// let _ = rng.next_u64();
+    /// rng.reseed([2u8; 32]);
+    /// assert_eq!(rng, Rng::from_seed([2u8; 32]));
+    /// ```
+    pub fn reseed(&mut self, seed: B::Seed) {
+        self.backend = B::from_seed(seed);
+    }
+
+    /// Reseeds the backend in place from a `u64` seed, like [`Rng::from_seed_u64`].
+    pub fn reseed_u64(&mut self, seed: u64) {
+        self.backend = B::from_seed_u64(seed);
+    }
+
+    /// Derives a child RNG seeded with words drawn from this one.
+    ///
+    /// The child depends only on the parent's state, so a recursive algorithm that forks
+    /// once per branch gives every branch its own reproducible stream. The parent advances
+    /// by one `next_u64` per 8 seed bytes. Each word is passed through the SplitMix64
+    /// finalizer and written little-endian, so the seed is the same on every platform and
+    /// is never the parent's own next state, which for single-word backends such as
+    /// [`XorShift`](crate::backend::XorShift) would make the child replay the parent. Unlike [`Rng::split`], this works for any seedable
+    /// backend, but the child is an ordinary reseed rather than a provably independent
+    /// stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Xoshiro256PlusPlus};
+    ///
+    /// let mut root = Rng::<Xoshiro256PlusPlus>::from_seed_u64(1);
+    /// let mut left = root.fork();
+    /// let mut right = root.fork();
+    /// assert_ne!(left.next_u64(), right.next_u64());
+    ///
+    /// let mut again = Rng::<Xoshiro256PlusPlus>::from_seed_u64(1);
+    /// assert_eq!(again.fork(), Rng::<Xoshiro256PlusPlus>::from_seed_u64(1).fork());
+    /// ```
+    pub fn fork(&mut self) -> Rng<B> {
+        let mut seed = B::Seed::zeroed();
+        for chunk in seed.as_mut().chunks_mut(8) {
+            let word = mix64(self.backend.next_u64()).to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+        Rng::from_seed(seed)
+    }
+}
+
+impl Rng<crate::backend::AnyBackend> {
+    /// Returns a [`RngBuilder`](crate::RngBuilder) for configuring a generator at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Algorithm, Rng};
+    ///
+    /// let mut rng = Rng::builder()
+    ///     .algorithm(Algorithm::Xoshiro256StarStar)
+    ///     .seed(42)
+    ///     .build()
+    ///     .unwrap();
+    /// let _ = rng.next_u64();
+    /// ```
+    pub fn builder() -> crate::RngBuilder {
+        crate::RngBuilder::new()
+    }
+}
+
+impl<B: Splittable> Rng<B> {
+    /// Splits off a child RNG whose backend is derived deterministically from this one.
+    ///
+    /// See [`Splittable`] for how each backend derives its children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(7));
+    /// let mut child = rng.split();
+    /// let _ = child.next_u64();
+    /// ```
+    pub fn split(&mut self) -> Rng<B> {
+        Rng::new(self.backend.split())
+    }
+}
+
+impl<B: SeedableBackend + Streamable> Rng<B> {
+    /// Returns an iterator over `count` generators that share `root_seed` and use streams
+    /// `0..count`, one per parallel worker.
+    ///
+    /// The `i`-th generator is `B::from_seed_u64(root_seed).with_stream(i)`, so each worker
+    /// can also rebuild its own generator from the root seed and its index alone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Philox};
+    ///
+    /// for (worker, mut rng) in Rng::<Philox>::streams(2024, 4).enumerate() {
+    ///     let _ = (worker, rng.next_u64());
+    /// }
+    /// ```
+    pub fn streams(root_seed: u64, count: u64) -> Streams<B> {
+        Streams {
+            root_seed,
+            next: 0,
+            count,
+            _backend: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<B: RandomBackend + Clone> Rng<B> {
+    /// Returns the value the next [`Rng::next_u64`] call will produce, without consuming it.
+    ///
+    /// The draw runs on a copy of the backend, so the cost is one clone of its state; for
+    /// large-state backends in hot loops prefer [`Rng::peek`] to inspect several values at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Pcg64Dxsm};
+    ///
+    /// let mut rng = Rng::new(Pcg64Dxsm::new(1, 2));
+    /// let next = rng.peek_u64();
+    /// assert_eq!(rng.next_u64(), next);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn peek_u64(&self) -> u64 {
+        self.clone().next_u64()
+    }
+
+    /// Runs `f` on a copy of this generator and returns its result, leaving `self` untouched.
+    ///
+    /// This lets speculative code look ahead through any sequence of draws, e.g. to check
+    /// which branch a simulation step would take before committing to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::Xoshiro256PlusPlus};
+    ///
+    /// let mut rng = Rng::new(Xoshiro256PlusPlus::new(5));
+    /// let ahead = rng.peek(|r| (r.gen_range(0..10).unwrap(), r.next_f64()));
+    /// assert_eq!(ahead, (rng.gen_range(0..10).unwrap(), rng.next_f64()));
+    /// ```
+    pub fn peek<T>(&self, f: impl FnOnce(&mut Rng<B>) -> T) -> T {
+        f(&mut self.clone())
+    }
 }
 
 impl<B> Clone for Rng<B>
@@ -197,6 +706,17 @@ where
     }
 }
 
+impl<B> PartialEq for Rng<B>
+where
+    B: RandomBackend + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.backend == other.backend
+    }
+}
+
+impl<B> Eq for Rng<B> where B: RandomBackend + Eq {}
+
 impl<B> core::fmt::Debug for Rng<B>
 where
     B: RandomBackend + core::fmt::Debug,
@@ -206,6 +726,34 @@ where
     }
 }
 
+/// Iterator over stream-separated generators, created by [`Rng::streams`].
+#[derive(Debug)]
+pub struct Streams<B> {
+    root_seed: u64,
+    next: u64,
+    count: u64,
+    _backend: core::marker::PhantomData<B>,
+}
+
+impl<B: SeedableBackend + Streamable> Iterator for Streams<B> {
+    type Item = Rng<B>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.count {
+            return None;
+        }
+        let stream = self.next;
+        self.next += 1;
+        Some(Rng::new(B::from_seed_u64(self.root_seed).with_stream(stream)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.count - self.next) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
 /// Iterator over `u64` values from a mutable `Rng` reference.
 #[derive(Debug)]
 pub struct U64Iter<'a, B: RandomBackend> {
@@ -232,6 +780,32 @@ impl<B: RandomBackend> Iterator for F64Iter<'_, B> {
     }
 }
 
+/// Iterator over `f32` values in [0, 1) from a mutable `Rng` reference.
+#[derive(Debug)]
+pub struct F32Iter<'a, B: RandomBackend> {
+    rng: &'a mut Rng<B>,
+}
+
+impl<B: RandomBackend> Iterator for F32Iter<'_, B> {
+    type Item = f32;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rng.next_f32())
+    }
+}
+
+/// Iterator over fair `bool` values from a mutable `Rng` reference.
+#[derive(Debug)]
+pub struct BoolIter<'a, B: RandomBackend> {
+    rng: &'a mut Rng<B>,
+}
+
+impl<B: RandomBackend> Iterator for BoolIter<'_, B> {
+    type Item = bool;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rng.next_bool())
+    }
+}
+
 impl<B: RandomBackend> Rng<B> {
     /// Returns an iterator that yields `u64` values indefinitely.
     #[inline]
@@ -244,6 +818,114 @@ impl<B: RandomBackend> Rng<B> {
     pub fn iter_f64(&mut self) -> F64Iter<'_, B> {
         F64Iter { rng: self }
     }
+
+    /// Returns an iterator that yields `f32` values in [0, 1) indefinitely.
+    #[inline]
+    pub fn iter_f32(&mut self) -> F32Iter<'_, B> {
+        F32Iter { rng: self }
+    }
+
+    /// Returns an iterator that yields fair `bool` values indefinitely.
+    #[inline]
+    pub fn iter_bool(&mut self) -> BoolIter<'_, B> {
+        BoolIter { rng: self }
+    }
+
+    /// Returns an iterator that yields values uniformly from `range` indefinitely.
+    ///
+    /// The range is validated once, as by [`Uniform::new`], and each item is the value
+    /// [`Rng::gen_range`] would return for the same generator state.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Rng::gen_range`] for an empty or open-ended range.
+    pub fn iter_range<T, R>(&mut self, range: R) -> core::result::Result<SampleIter<'_, B, Uniform<T>, T>, crate::AporiaError>
+    where
+        T: SampleUniform,
+        R: core::ops::RangeBounds<T>,
+    {
+        Ok(self.sample_iter(Uniform::new(range)?))
+    }
+
+    /// Returns an iterator that yields samples of `dist` indefinitely; the same as
+    /// [`Distribution::sample_iter`].
+    #[inline]
+    pub fn sample_iter<T, D: Distribution<T>>(&mut self, dist: D) -> SampleIter<'_, B, D, T> {
+        dist.sample_iter(self)
+    }
+}
+
+/// Collecting helpers for scripts and tests. Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+impl<B: RandomBackend> Rng<B> {
+    /// Returns `n` random `u64` values.
+    #[must_use]
+    pub fn vec_u64(&mut self, n: usize) -> alloc::vec::Vec<u64> {
+        self.iter_u64().take(n).collect()
+    }
+
+    /// Returns `n` random `f64` values in [0, 1).
+    #[must_use]
+    pub fn vec_f64(&mut self, n: usize) -> alloc::vec::Vec<f64> {
+        self.iter_f64().take(n).collect()
+    }
+
+    /// Returns `n` values drawn uniformly from `range`, as by [`Rng::iter_range`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Rng::gen_range`] for an empty or open-ended range.
+    pub fn vec_range<T, R>(&mut self, n: usize, range: R) -> core::result::Result<alloc::vec::Vec<T>, crate::AporiaError>
+    where
+        T: SampleUniform,
+        R: core::ops::RangeBounds<T>,
+    {
+        Ok(self.iter_range(range)?.take(n).collect())
+    }
+}
+
+/// Byte-stream adapters. Requires the `std` feature.
+#[cfg(feature = "std")]
+impl<B: RandomBackend> Rng<B> {
+    /// Writes `n` random bytes to `w`, in chunks produced by [`Rng::fill_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `w`; bytes written before it are not rewound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aporia::{Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(8));
+    /// let mut out = Vec::new();
+    /// rng.read_to_writer(&mut out, 10_000).unwrap();
+    /// assert_eq!(out.len(), 10_000);
+    /// ```
+    pub fn read_to_writer<W: std::io::Write + ?Sized>(&mut self, w: &mut W, n: u64) -> std::io::Result<()> {
+        let mut buf = [0u8; 4096];
+        let mut remaining = n;
+        while remaining > 0 {
+            let len = remaining.min(buf.len() as u64) as usize;
+            self.fill_bytes(&mut buf[..len]);
+            w.write_all(&buf[..len])?;
+            remaining -= len as u64;
+        }
+        Ok(())
+    }
+}
+
+/// Serves an endless stream of random bytes, so an `Rng` can be used wherever a reader is
+/// expected, e.g. `std::io::copy(&mut (&mut rng).take(n), &mut sink)`.
+///
+/// Every call fills the whole buffer through [`Rng::fill_bytes`] and never fails.
+#[cfg(feature = "std")]
+impl<B: RandomBackend> std::io::Read for Rng<B> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.fill_bytes(buf);
+        Ok(buf.len())
+    }
 }
 
 impl<'a, B: RandomBackend> IntoIterator for &'a mut Rng<B> {
@@ -275,11 +957,88 @@ mod tests {
         let backend = SplitMix64::new(123);
         let mut rng = Rng::new(backend);
         for _ in 0..1000 {
-            let x = rng.gen_range(10, 20).unwrap();
+            let x = rng.gen_range(10..20).unwrap();
             assert!((10..20).contains(&x));
         }
     }
 
+    #[test]
+    fn gen_range_inclusive_covers_full_domain() {
+        let mut rng = Rng::new(SplitMix64::new(8));
+        let mut plain = SplitMix64::new(8);
+        for _ in 0..100 {
+            assert_eq!(rng.gen_range_inclusive(0, u64::MAX).unwrap(), plain.next_u64());
+            let offset = plain.next_u32() as i32;
+            assert_eq!(rng.gen_range(i32::MIN..=i32::MAX).unwrap(), i32::MIN.wrapping_add(offset));
+        }
+        assert_eq!(rng.gen_range_inclusive(7u64, 7).unwrap(), 7);
+        assert!(rng.gen_range_inclusive(2i64, 1).is_err());
+    }
+
+    #[test]
+    fn wide_and_signed_outputs_use_backend_bits() {
+        let mut rng = Rng::new(SplitMix64::new(21));
+        let mut plain = SplitMix64::new(21);
+        let hi = u128::from(plain.next_u64());
+        assert_eq!(rng.next_u128(), (hi << 64) | u128::from(plain.next_u64()));
+        assert_eq!(rng.next_i64(), plain.next_u64() as i64);
+        assert_eq!(rng.next_i32(), plain.next_u32() as i32);
+        let hi = u128::from(plain.next_u64());
+        assert_eq!(rng.next_i128(), ((hi << 64) | u128::from(plain.next_u64())) as i128);
+    }
+
+    #[test]
+    fn fill_range_matches_repeated_gen_range() {
+        let mut rng = Rng::new(SplitMix64::new(40));
+        let mut plain = Rng::new(SplitMix64::new(40));
+        let mut values = [0i16; 100];
+        rng.fill_range(&mut values, -7..=7).unwrap();
+        for v in values {
+            assert_eq!(v, plain.gen_range(-7..=7).unwrap());
+        }
+
+        let mut untouched = [5u64; 4];
+        assert!(rng.fill_range(&mut untouched, 3..3).is_err());
+        assert_eq!(untouched, [5; 4]);
+    }
+
+    #[test]
+    fn gen_ratio_is_exact_and_validated() {
+        let mut rng = Rng::new(SplitMix64::new(31));
+        let hits = (0..70_000).filter(|_| rng.gen_ratio(3, 7).unwrap()).count();
+        assert!((29_000..31_000).contains(&hits), "hits = {hits}");
+        assert!(!rng.gen_ratio(0, 5).unwrap());
+        assert!(rng.gen_ratio(5, 5).unwrap());
+        assert!(rng.gen_ratio(1, 0).is_err());
+        assert!(rng.gen_ratio(6, 5).is_err());
+    }
+
+    #[test]
+    fn float_intervals_respect_endpoints() {
+        struct Fixed(u64);
+        impl RandomBackend for Fixed {
+            fn next_u64(&mut self) -> u64 {
+                self.0
+            }
+        }
+
+        let mut low = Rng::new(Fixed(0));
+        let mut high = Rng::new(Fixed(u64::MAX));
+        assert_eq!(low.next_f64_closed(), 0.0);
+        assert_eq!(high.next_f64_closed(), 1.0);
+        assert_eq!(low.next_f64_open_closed(), 1.0 / (1u64 << 53) as f64);
+        assert_eq!(high.next_f64_open_closed(), 1.0);
+        assert!(low.next_f64_open() > 0.0);
+        assert!(high.next_f64_open() < 1.0);
+
+        let mut min = Rng::new(Fixed(1 << 63));
+        assert_eq!(min.next_f64_signed(), -1.0);
+        assert_eq!(min.next_f32_signed(), -1.0);
+        let mut max = Rng::new(Fixed(i64::MAX as u64));
+        assert!(max.next_f64_signed() < 1.0);
+        assert!(max.next_f32_signed() < 1.0);
+    }
+
     #[test]
     fn gen_range_f64_bounds() {
         let backend = SplitMix64::new(123);
@@ -300,6 +1059,210 @@ mod tests {
         let _b = rng.next_bool();
     }
 
+    #[test]
+    fn seedable_backends_construct_generically() {
+        use crate::backend::{ChaCha8, Isaac64, Pcg64Dxsm, Xoshiro256StarStar, XorWow};
+
+        fn draw<B: SeedableBackend>(seed: u64) -> u64 {
+            Rng::<B>::from_seed_u64(seed).next_u64()
+        }
+        assert_eq!(draw::<Xoshiro256StarStar>(3), Xoshiro256StarStar::new(3).next_u64());
+        assert_eq!(draw::<XorWow>(3), XorWow::new(3).next_u64());
+        assert_eq!(draw::<Isaac64>(3), Isaac64::new(3).next_u64());
+        assert_eq!(draw::<ChaCha8>(3), ChaCha8::new(3).next_u64());
+        assert_eq!(draw::<Pcg64Dxsm>(3), Pcg64Dxsm::new(3, 0).next_u64());
+
+        // All-zero byte seeds fall back to a valid state instead of a stuck generator.
+        assert_ne!(Rng::<XorShift>::from_seed([0; 8]).next_u64(), 0);
+        assert_ne!(Rng::<Xoshiro256StarStar>::from_seed([0; 32]).next_u64(), 0);
+    }
+
+    #[test]
+    fn split_is_deterministic() {
+        let mut a = Rng::new(XorShift::new(9));
+        let mut b = Rng::new(XorShift::new(9));
+        let mut ca = a.split();
+        let mut cb = b.split();
+        assert_eq!(ca.next_u64(), cb.next_u64());
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_ne!(a.split().next_u64(), ca.next_u64());
+    }
+
+    #[test]
+    fn fork_draws_seed_from_parent() {
+        use crate::backend::ChaCha20;
+        let mut parent = Rng::<ChaCha20>::from_seed_u64(4);
+        let child = parent.fork();
+
+        let mut source = ChaCha20::new(4);
+        let mut seed = [0u8; 32];
+        for chunk in seed.chunks_mut(8) {
+            chunk.copy_from_slice(&mix64(source.next_u64()).to_le_bytes());
+        }
+        assert_eq!(child, Rng::from_seed(seed));
+        assert_eq!(parent.next_u64(), source.next_u64());
+
+        // Seeds longer than 32 bytes have no `Default` impl but fork all the same.
+        use crate::backend::{HmacDrbg, Xoshiro512StarStar};
+        let mut parent = Rng::<Xoshiro512StarStar>::from_seed_u64(4);
+        assert_ne!(parent.fork(), parent.fork());
+        let mut parent = Rng::<HmacDrbg>::from_seed_u64(4);
+        assert_ne!(parent.fork().next_u64(), parent.fork().next_u64());
+    }
+
+    #[test]
+    fn forked_single_word_backends_do_not_replay_the_parent() {
+        use crate::backend::LCG;
+
+        // Seeded with the raw next output, these children would start on the parent's
+        // next state and repeat its stream one step behind.
+        let mut parent = Rng::<XorShift>::from_seed_u64(9);
+        let mut child = parent.fork();
+        let ahead: [u64; 1000] = core::array::from_fn(|_| parent.next_u64());
+        assert!((0..1000).all(|_| !ahead.contains(&child.next_u64())));
+
+        let mut parent = Rng::<LCG>::from_seed_u64(9);
+        let mut child = parent.fork();
+        let ahead: [u64; 1000] = core::array::from_fn(|_| parent.next_u64());
+        assert!((0..1000).all(|_| !ahead.contains(&child.next_u64())));
+    }
+
+    #[test]
+    fn streams_match_with_stream() {
+        use crate::backend::PCG;
+        let mut streams = Rng::<PCG>::streams(9, 3);
+        assert_eq!(streams.size_hint(), (3, Some(3)));
+        for i in 0..3 {
+            let mut expected = PCG::new(9, 0).with_stream(i);
+            assert_eq!(streams.next().unwrap().next_u64(), expected.next_u64());
+        }
+        assert!(streams.next().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_dyn_backend_matches_concrete() {
+        use crate::backend::ChaCha8;
+        use alloc::boxed::Box;
+        let mut dynamic: Rng<Box<dyn RandomBackend>> = Rng::new(Box::new(ChaCha8::new(3)));
+        let mut concrete = Rng::new(ChaCha8::new(3));
+        // ChaCha overrides next_u32, which must be forwarded through the box.
+        assert_eq!(dynamic.next_u32(), concrete.next_u32());
+        assert_eq!(dynamic.next_u64(), concrete.next_u64());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_restores_state() {
+        use crate::backend::{AnyBackend, MT19937_64};
+        let mut rng = Rng::new(MT19937_64::new(5489));
+        for _ in 0..400 {
+            let _ = rng.next_u64();
+        }
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: Rng<MT19937_64> = serde_json::from_str(&json).unwrap();
+        for _ in 0..400 {
+            assert_eq!(restored.next_u64(), rng.next_u64());
+        }
+
+        let mut any = AnyBackend::from_name("fortuna", 3).unwrap();
+        let _ = any.next_u32();
+        let mut restored: AnyBackend = serde_json::from_str(&serde_json::to_string(&any).unwrap()).unwrap();
+        assert_eq!(restored.next_u64(), any.next_u64());
+    }
+
+    #[test]
+    fn backends_compare_by_state() {
+        use crate::backend::{AnyBackend, MT19937_64};
+        let mut a = Rng::new(MT19937_64::new(1));
+        let b = a.clone();
+        assert_eq!(a, b);
+        let _ = a.next_u64();
+        assert_ne!(a, b);
+
+        let mut s = SplitMix64::new(2);
+        let t = s.clone();
+        let _ = s.next_u64();
+        assert_ne!(s, t);
+        assert_eq!(t, SplitMix64::new(2));
+
+        let any = AnyBackend::from_name("cmwc", 3).unwrap();
+        assert_eq!(any.clone(), any);
+    }
+
+    #[test]
+    fn reseed_restarts_sequence() {
+        let mut rng = Rng::<SplitMix64>::from_seed_u64(5);
+        let first = rng.next_u64();
+        let _ = rng.next_u64();
+        rng.reseed_u64(5);
+        assert_eq!(rng.next_u64(), first);
+
+        let old = rng.replace_backend(SplitMix64::new(6));
+        let mut expected = SplitMix64::new(5);
+        let _ = expected.next_u64();
+        assert_eq!(old, expected);
+        assert_eq!(rng.next_u64(), SplitMix64::new(6).next_u64());
+    }
+
+    fn assert_snapshot_resumes<B: RandomBackend + crate::StateSnapshot>(mut backend: B) {
+        for _ in 0..3 {
+            let _ = backend.next_u32();
+        }
+        let saved = backend.to_state_bytes();
+        let mut restored = B::from_state_bytes(saved.as_ref()).unwrap();
+        for _ in 0..700 {
+            assert_eq!(restored.next_u64(), backend.next_u64());
+        }
+        assert!(B::from_state_bytes(&saved.as_ref()[1..]).is_err());
+    }
+
+    #[test]
+    fn state_snapshots_resume_sequence() {
+        use crate::backend::*;
+        assert_snapshot_resumes(LCG::new(1));
+        assert_snapshot_resumes(Pcg64Dxsm::new(2, 3));
+        assert_snapshot_resumes(MT19937_64::new(4));
+        assert_snapshot_resumes(MT19937::new(5));
+        assert_snapshot_resumes(Well19937a::new(6));
+        assert_snapshot_resumes(Cmwc::new(7));
+        assert_snapshot_resumes(Isaac64::new(8));
+        assert_snapshot_resumes(ChaCha12::new(9));
+        assert_snapshot_resumes(Philox::new(10));
+        assert_snapshot_resumes(HmacDrbg::new(11));
+        assert_snapshot_resumes(CtrDrbg::new(12));
+        assert_snapshot_resumes(Fortuna::new(13));
+        assert_snapshot_resumes(XorWow::new(14));
+        assert!(XorShift::from_state_bytes(&[0; 8]).is_err());
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn default_backends_are_seeded_from_os() {
+        use crate::backend::{ChaCha20, Xoshiro256StarStar};
+        let mut a = Rng::new(Xoshiro256StarStar::default());
+        let mut b = Rng::new(Xoshiro256StarStar::default());
+        assert_ne!(a.next_u64(), b.next_u64());
+        assert_ne!(ChaCha20::default().next_u64(), ChaCha20::default().next_u64());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize_wipes_crypto_state() {
+        use crate::backend::{ChaCha20, CtrDrbg, Fortuna, HmacDrbg};
+        use zeroize::Zeroize;
+
+        fn assert_wiped<B: RandomBackend + crate::StateSnapshot + Zeroize>(mut backend: B) {
+            let _ = backend.next_u64();
+            backend.zeroize();
+            assert!(backend.to_state_bytes().as_ref().iter().all(|&b| b == 0));
+        }
+        assert_wiped(ChaCha20::new(1));
+        assert_wiped(HmacDrbg::new(2));
+        assert_wiped(CtrDrbg::new(3));
+        assert_wiped(Fortuna::new(4));
+    }
+
     #[test]
     fn iter_helpers_and_into_iter() {
         let backend = XorShift::new(1);
@@ -324,4 +1287,70 @@ mod tests {
         }
         assert!(count > 0);
     }
+
+    #[test]
+    fn bounded_iterators_match_single_draws() {
+        use crate::distributions::Bernoulli;
+
+        let mut a = Rng::new(XorShift::new(3));
+        let mut b = Rng::new(XorShift::new(3));
+        for roll in a.iter_range(-3..=3).unwrap().take(50) {
+            assert_eq!(roll, b.gen_range(-3..=3).unwrap());
+        }
+        for x in a.iter_f32().take(50) {
+            assert_eq!(x, b.next_f32());
+        }
+        for coin in a.iter_bool().take(50) {
+            assert_eq!(coin, b.next_bool());
+        }
+        let coin = Bernoulli::new(0.5).unwrap();
+        for heads in a.sample_iter(coin).take(50) {
+            assert_eq!(heads, b.sample(coin));
+        }
+        assert!(a.iter_range(5u8..5).is_err());
+    }
+
+    #[test]
+    fn peeking_does_not_consume_draws() {
+        let mut rng = Rng::new(XorShift::new(9));
+        let snapshot = rng.clone();
+        let first = rng.peek_u64();
+        assert_eq!(rng.peek_u64(), first);
+        assert_eq!(rng.peek(|r| [r.next_u64(), r.next_u64()])[0], first);
+        assert_eq!(rng, snapshot);
+        assert_eq!(rng.next_u64(), first);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn byte_streams_match_fill_bytes() {
+        use std::io::Read;
+
+        let mut a = Rng::new(XorShift::new(6));
+        let mut b = Rng::new(XorShift::new(6));
+        let mut read = vec![0u8; 5000];
+        a.read_exact(&mut read[..4096]).unwrap();
+        a.read_exact(&mut read[4096..]).unwrap();
+        let mut written = Vec::new();
+        b.read_to_writer(&mut written, 5000).unwrap();
+        assert_eq!(read, written);
+
+        let mut expected = [0u8; 16];
+        Rng::new(XorShift::new(6)).fill_bytes(&mut expected);
+        assert_eq!(read[..16], expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_helpers_collect_the_iterators() {
+        let mut a = Rng::new(XorShift::new(4));
+        let mut b = Rng::new(XorShift::new(4));
+        assert_eq!(a.vec_u64(8), b.iter_u64().take(8).collect::<alloc::vec::Vec<_>>());
+        assert_eq!(a.vec_f64(8), b.iter_f64().take(8).collect::<alloc::vec::Vec<_>>());
+        let dice = a.vec_range(20, 1u8..=6).unwrap();
+        assert_eq!(dice.len(), 20);
+        assert!(dice.iter().all(|&d| b.gen_range(1u8..=6).unwrap() == d));
+        assert!(a.vec_range(3, 2.0..1.0).is_err());
+        assert!(a.vec_u64(0).is_empty());
+    }
 }
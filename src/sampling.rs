@@ -0,0 +1,204 @@
+//! Sampling utilities for design of experiments.
+//!
+//! # Latin hypercube sampling
+//!
+//! [`latin_hypercube`] produces `n` points in `[0, 1)^D` such that, in every dimension,
+//! each of the `n` equal-width strata `[k/n, (k+1)/n)` contains exactly one point. Within a
+//! stratum the position is uniform, and the strata are matched across dimensions by
+//! independent random permutations.
+//!
+//! The permutations are evaluated on the fly with Kensler's hash-based permutation, so the
+//! iterator needs no allocation and works in `no_std` builds. With the `alloc` feature,
+//! [`latin_hypercube_vec`] collects points of a runtime-chosen dimension into a `Vec`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use aporia::{Rng, backend::Xoshiro256StarStar, sampling::latin_hypercube};
+//!
+//! let mut rng = Rng::new(Xoshiro256StarStar::new(5));
+//! for [temperature, pressure] in latin_hypercube::<_, 2>(&mut rng, 10) {
+//!     let temperature = 20.0 + 80.0 * temperature;
+//!     let pressure = 1.0 + 4.0 * pressure;
+//!     // run an experiment at (temperature, pressure)
+//! }
+//! ```
+//!
+//! # References
+//!
+//! - [McKay, Beckman, Conover (1979), "A Comparison of Three Methods for Selecting Values of Input Variables in the Analysis of Output from a Computer Code"](https://doi.org/10.2307/1268522)
+//! - [Kensler (2013), "Correlated Multi-Jittered Sampling"](https://graphics.pixar.com/library/MultiJitteredSampling/)
+
+use crate::backend::RandomBackend;
+use crate::rng::Rng;
+
+/// The largest `f64` strictly below 1.0.
+const BELOW_ONE: f64 = 1.0 - f64::EPSILON / 2.0;
+
+/// Returns an iterator over `n_samples` Latin hypercube points in `[0, 1)^D`.
+///
+/// Each dimension draws its stratum permutation from `rng` up front, and each point draws
+/// `D` jitter values as it is produced.
+///
+/// # Panics
+///
+/// Panics if `n_samples` exceeds `u32::MAX`.
+pub fn latin_hypercube<B: RandomBackend, const D: usize>(
+    rng: &mut Rng<B>,
+    n_samples: usize,
+) -> LatinHypercube<'_, B, D> {
+    assert!(n_samples <= u32::MAX as usize, "too many Latin hypercube samples");
+    let seeds = core::array::from_fn(|_| rng.next_u32());
+    LatinHypercube {
+        rng,
+        seeds,
+        index: 0,
+        n_samples: n_samples as u32,
+    }
+}
+
+/// Collects `n_samples` Latin hypercube points with `n_dims` coordinates each.
+///
+/// This is the runtime-dimension counterpart of [`latin_hypercube`]; the outer `Vec` holds
+/// the points.
+///
+/// # Panics
+///
+/// Panics if `n_samples` exceeds `u32::MAX`.
+#[cfg(feature = "alloc")]
+pub fn latin_hypercube_vec<B: RandomBackend>(
+    rng: &mut Rng<B>,
+    n_samples: usize,
+    n_dims: usize,
+) -> alloc::vec::Vec<alloc::vec::Vec<f64>> {
+    assert!(n_samples <= u32::MAX as usize, "too many Latin hypercube samples");
+    let seeds: alloc::vec::Vec<u32> = (0..n_dims).map(|_| rng.next_u32()).collect();
+    (0..n_samples as u32)
+        .map(|i| {
+            seeds
+                .iter()
+                .map(|&seed| stratified(i, n_samples as u32, seed, rng.next_f64()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Iterator over Latin hypercube points, created by [`latin_hypercube`].
+pub struct LatinHypercube<'a, B: RandomBackend, const D: usize> {
+    rng: &'a mut Rng<B>,
+    seeds: [u32; D],
+    index: u32,
+    n_samples: u32,
+}
+
+impl<B: RandomBackend, const D: usize> Iterator for LatinHypercube<'_, B, D> {
+    type Item = [f64; D];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.n_samples {
+            return None;
+        }
+        let i = self.index;
+        self.index += 1;
+        Some(core::array::from_fn(|j| {
+            stratified(i, self.n_samples, self.seeds[j], self.rng.next_f64())
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.n_samples - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<B: RandomBackend, const D: usize> ExactSizeIterator for LatinHypercube<'_, B, D> {}
+
+/// Places sample `i` uniformly (by `jitter`) inside its permuted stratum.
+fn stratified(i: u32, n: u32, seed: u32, jitter: f64) -> f64 {
+    let stratum = permute(i, n, seed);
+    ((stratum as f64 + jitter) / n as f64).min(BELOW_ONE)
+}
+
+/// Kensler's hash-based permutation: maps `i` in `[0, len)` to a unique index in
+/// `[0, len)` selected by `seed`, using cycle walking over the next power of two.
+fn permute(mut i: u32, len: u32, seed: u32) -> u32 {
+    let mut w = len - 1;
+    w |= w >> 1;
+    w |= w >> 2;
+    w |= w >> 4;
+    w |= w >> 8;
+    w |= w >> 16;
+    loop {
+        i ^= seed;
+        i = i.wrapping_mul(0xe170_893d);
+        i ^= seed >> 16;
+        i ^= (i & w) >> 4;
+        i ^= seed >> 8;
+        i = i.wrapping_mul(0x0929_eb3f);
+        i ^= seed >> 23;
+        i ^= (i & w) >> 1;
+        i = i.wrapping_mul(1 | seed >> 27);
+        i = i.wrapping_mul(0x6935_fa69);
+        i ^= (i & w) >> 11;
+        i = i.wrapping_mul(0x74dc_b303);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0x9e50_1cc3);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0xc860_a3df);
+        i &= w;
+        i ^= i >> 5;
+        if i < len {
+            break;
+        }
+    }
+    ((i as u64 + seed as u64) % len as u64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn latin_hypercube_is_stratified() {
+        let mut rng = Rng::new(SplitMix64::new(8));
+        let n = 37;
+        let mut seen = [[false; 37]; 3];
+        let mut count = 0;
+        for point in latin_hypercube::<_, 3>(&mut rng, n) {
+            for (x, cells) in point.iter().zip(seen.iter_mut()) {
+                let k = (x * n as f64) as usize;
+                assert!(!cells[k]);
+                cells[k] = true;
+            }
+            count += 1;
+        }
+        assert_eq!(count, n);
+    }
+
+    #[test]
+    fn permute_is_a_bijection() {
+        for len in [1, 2, 5, 64, 100] {
+            let mut hit = [false; 100];
+            for i in 0..len {
+                hit[permute(i, len, 0xdead_beef) as usize] = true;
+            }
+            assert!(hit[..len as usize].iter().all(|&h| h));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn latin_hypercube_vec_shape() {
+        let mut rng = Rng::new(SplitMix64::new(8));
+        let points = latin_hypercube_vec(&mut rng, 16, 5);
+        assert_eq!(points.len(), 16);
+        assert!(points.iter().all(|p| p.len() == 5));
+        for j in 0..5 {
+            let mut strata: alloc::vec::Vec<usize> =
+                points.iter().map(|p| (p[j] * 16.0) as usize).collect();
+            strata.sort_unstable();
+            assert_eq!(strata, (0..16).collect::<alloc::vec::Vec<_>>());
+        }
+    }
+}
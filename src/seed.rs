@@ -0,0 +1,390 @@
+//! Seed derivation helpers.
+//!
+//! Reproducible runs are often keyed by a human-readable label such as `"experiment-42"`.
+//! [`seed_from_str`] and [`seed_from_bytes`] turn such a label into a well-mixed `u64`
+//! for [`SeedableBackend::from_seed_u64`](crate::SeedableBackend::from_seed_u64), and
+//! [`fill_seed_from_bytes`] expands it into a full byte seed for
+//! [`SeedableBackend::from_seed`](crate::SeedableBackend::from_seed).
+//!
+//! Composite keys such as `(user_id, date)` can be hashed with [`seed_from_hash`], or
+//! passed straight to [`Rng::seeded_from`](crate::Rng::seeded_from). [`SeedHasher`]
+//! writes integers in little-endian order and `usize`/`isize` as 64-bit values, so the
+//! seed does not depend on the platform. It does depend on the value's `Hash` impl, which
+//! the standard library does not promise to keep unchanged between Rust releases.
+//!
+//! The label is hashed with 64-bit FNV-1a and the result is passed through the SplitMix64
+//! output function, so similar labels give unrelated seeds. The derivation is fixed: the
+//! same label yields the same seed on every platform and in every release. It is not a
+//! cryptographic hash and must not be used to derive keys from secrets.
+//!
+//! For hierarchical simulations, [`SeedSequence`] absorbs several entropy inputs and
+//! spawns any number of well-separated child sequences, each of which can seed a
+//! generator or spawn children of its own.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use aporia::{Rng, SeedableBackend, backend::Xoshiro256StarStar, seed};
+//!
+//! let seed = seed::seed_from_str("experiment-42");
+//! let mut rng = Rng::new(Xoshiro256StarStar::from_seed_u64(seed));
+//! let random_number = rng.next_u64();
+//!
+//! // Or fill the backend's full 32-byte seed.
+//! let mut bytes = [0u8; 32];
+//! seed::fill_seed_from_bytes(b"experiment-42", &mut bytes);
+//! let mut rng = Rng::new(Xoshiro256StarStar::from_seed(bytes));
+//! ```
+//!
+//! # References
+//!
+//! - [Fowler, Noll, Vo, "FNV Hash"](http://www.isthe.com/chongo/tech/comp/fnv/)
+//! - [NumPy `SeedSequence` documentation](https://numpy.org/doc/stable/reference/random/bit_generators/generated/numpy.random.SeedSequence.html)
+//! - [Melissa O'Neill (2015), "Developing a seed_seq Alternative"](https://www.pcg-random.org/posts/developing-a-seed_seq-alternative.html)
+
+use core::hash::{Hash, Hasher};
+
+use crate::backend::{RandomBackend, SplitMix64};
+
+/// 64-bit FNV offset basis.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// 64-bit FNV prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Platform-independent [`Hasher`] that produces well-mixed seeds.
+///
+/// Bytes are absorbed with 64-bit FNV-1a and [`Hasher::finish`] applies the SplitMix64
+/// output function. Integer writes use little-endian byte order, and `usize`/`isize` are
+/// widened to 64 bits.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::hash::{Hash, Hasher};
+/// use aporia::seed::{SeedHasher, seed_from_hash};
+///
+/// let mut hasher = SeedHasher::new();
+/// (42u64, "eu-west").hash(&mut hasher);
+/// assert_eq!(hasher.finish(), seed_from_hash(&(42u64, "eu-west")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeedHasher {
+    state: u64,
+}
+
+impl SeedHasher {
+    /// Creates a hasher with no input absorbed.
+    pub const fn new() -> Self {
+        Self { state: FNV_OFFSET }
+    }
+}
+
+impl Default for SeedHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for SeedHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.state = (self.state ^ u64::from(b)).wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as i64 as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        SplitMix64::new(self.state).next_u64()
+    }
+}
+
+/// Hashes `bytes` into a well-mixed `u64` seed.
+pub fn seed_from_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = SeedHasher::new();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// Hashes `value` with [`SeedHasher`] into a well-mixed `u64` seed.
+pub fn seed_from_hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = SeedHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes the UTF-8 bytes of `label` into a well-mixed `u64` seed.
+pub fn seed_from_str(label: &str) -> u64 {
+    seed_from_bytes(label.as_bytes())
+}
+
+/// Fills `seed` with bytes derived from `bytes`, for seeds wider than 64 bits.
+///
+/// The output is a SplitMix64 stream started from [`seed_from_bytes`], so it carries at
+/// most 64 bits of information about `bytes`. It is meant for reproducibility, not for
+/// stretching entropy.
+pub fn fill_seed_from_bytes(bytes: &[u8], seed: &mut [u8]) {
+    SplitMix64::new(seed_from_bytes(bytes)).fill_bytes(seed);
+}
+
+/// Number of 32-bit words in the [`SeedSequence`] entropy pool.
+const POOL_SIZE: usize = 4;
+
+/// Initial hash constant used while absorbing entropy.
+const INIT_A: u32 = 0x43b0_d7e5;
+
+/// Multiplier applied to the hash constant while absorbing entropy.
+const MULT_A: u32 = 0x931e_8875;
+
+/// Initial hash constant used while generating output.
+const INIT_B: u32 = 0x8b51_f9dd;
+
+/// Multiplier applied to the hash constant while generating output.
+const MULT_B: u32 = 0x58f3_8ded;
+
+const MIX_MULT_L: u32 = 0xca01_f9dd;
+const MIX_MULT_R: u32 = 0x4973_f715;
+const XSHIFT: u32 = 16;
+
+/// Seed combiner that mixes entropy into a pool and spawns independent children.
+///
+/// This is the algorithm of NumPy's `SeedSequence` (O'Neill's `seed_seq_fe` with a
+/// 128-bit pool), and it produces the same words: `SeedSequence::new(&[a, b])` matches
+/// `numpy.random.SeedSequence([a, b])`, and the `i`-th call to [`SeedSequence::spawn`]
+/// matches the `i`-th child of NumPy's `spawn`. Every input bit affects every pool word,
+/// and each child appends its index to the parent's input, so children never share
+/// entropy with their parent or siblings.
+///
+/// The sequence itself is not a generator. Use [`SeedSequence::generate_state_u64`] or
+/// [`SeedSequence::fill_bytes`] to produce seed material for a backend.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, SeedableBackend, backend::Xoshiro256PlusPlus, seed::SeedSequence};
+///
+/// let mut root = SeedSequence::new(&[2024, 7]);
+/// let mut workers = [0u8; 32];
+/// for _ in 0..4 {
+///     let child = root.spawn();
+///     child.fill_bytes(&mut workers);
+///     let mut rng = Rng::new(Xoshiro256PlusPlus::from_seed(workers));
+///     let _ = rng.next_u64();
+/// }
+/// assert_eq!(root.children_spawned(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeedSequence {
+    pool: [u32; POOL_SIZE],
+    hash_const: u32,
+    children_spawned: u64,
+}
+
+impl SeedSequence {
+    /// Creates a sequence from entropy values.
+    ///
+    /// Each value contributes one 32-bit word if it is below 2<sup>32</sup> and two
+    /// little-endian words otherwise, like a list of Python integers passed to NumPy.
+    pub fn new(entropy: &[u64]) -> Self {
+        let mut words = entropy.iter().flat_map(|&v| int_words(v));
+        let mut hash_const = INIT_A;
+        let mut pool = [0u32; POOL_SIZE];
+        // Missing words are hashed as zeros, which matches NumPy's zero padding.
+        for word in pool.iter_mut() {
+            *word = hashmix(words.next().unwrap_or(0), &mut hash_const);
+        }
+        // Mix all words together so late bits can affect earlier ones.
+        for src in 0..POOL_SIZE {
+            for dst in 0..POOL_SIZE {
+                if src != dst {
+                    let h = hashmix(pool[src], &mut hash_const);
+                    pool[dst] = mix(pool[dst], h);
+                }
+            }
+        }
+        let mut seq = Self { pool, hash_const, children_spawned: 0 };
+        for word in words {
+            seq.absorb(word);
+        }
+        seq
+    }
+
+    /// Mixes one word of entropy beyond the first pool-sized block into every pool word.
+    fn absorb(&mut self, word: u32) {
+        for dst in 0..POOL_SIZE {
+            let h = hashmix(word, &mut self.hash_const);
+            self.pool[dst] = mix(self.pool[dst], h);
+        }
+    }
+
+    /// Returns the next child sequence.
+    ///
+    /// The child absorbs this sequence's input followed by its own index, so repeated calls
+    /// yield distinct, reproducible children.
+    pub fn spawn(&mut self) -> Self {
+        let mut child = Self {
+            pool: self.pool,
+            hash_const: self.hash_const,
+            children_spawned: 0,
+        };
+        for word in int_words(self.children_spawned) {
+            child.absorb(word);
+        }
+        self.children_spawned += 1;
+        child
+    }
+
+    /// Returns the number of children spawned so far.
+    pub fn children_spawned(&self) -> u64 {
+        self.children_spawned
+    }
+
+    /// Returns the endless stream of output words derived from the pool.
+    fn output_words(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut hash_const = INIT_B;
+        self.pool.iter().cycle().map(move |&src| {
+            let mut v = src ^ hash_const;
+            hash_const = hash_const.wrapping_mul(MULT_B);
+            v = v.wrapping_mul(hash_const);
+            v ^ (v >> XSHIFT)
+        })
+    }
+
+    /// Fills `out` with seed words, like NumPy's `generate_state(n, np.uint32)`.
+    pub fn generate_state_u32(&self, out: &mut [u32]) {
+        for (dst, word) in out.iter_mut().zip(self.output_words()) {
+            *dst = word;
+        }
+    }
+
+    /// Fills `out` with seed words, like NumPy's `generate_state(n, np.uint64)`.
+    ///
+    /// Each `u64` joins two consecutive 32-bit words, low word first.
+    pub fn generate_state_u64(&self, out: &mut [u64]) {
+        let mut words = self.output_words();
+        for dst in out.iter_mut() {
+            let lo = u64::from(words.next().unwrap_or(0));
+            let hi = u64::from(words.next().unwrap_or(0));
+            *dst = (hi << 32) | lo;
+        }
+    }
+
+    /// Fills `out` with seed bytes: the 32-bit output words in little-endian order.
+    pub fn fill_bytes(&self, out: &mut [u8]) {
+        for (chunk, word) in out.chunks_mut(4).zip(self.output_words()) {
+            chunk.copy_from_slice(&word.to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+/// Splits an integer into its minimal little-endian 32-bit words (zero is one word).
+fn int_words(v: u64) -> impl Iterator<Item = u32> {
+    let hi = (v >> 32) as u32;
+    core::iter::once(v as u32).chain((hi != 0).then_some(hi))
+}
+
+fn hashmix(value: u32, hash_const: &mut u32) -> u32 {
+    let mut v = value ^ *hash_const;
+    *hash_const = hash_const.wrapping_mul(MULT_A);
+    v = v.wrapping_mul(*hash_const);
+    v ^ (v >> XSHIFT)
+}
+
+fn mix(x: u32, y: u32) -> u32 {
+    let r = MIX_MULT_L.wrapping_mul(x).wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+    r ^ (r >> XSHIFT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_from_str_is_stable() {
+        // Pinned so that seeds derived from labels never change between releases.
+        assert_eq!(seed_from_str("experiment-42"), 8246748266967831871);
+        assert_eq!(seed_from_bytes(b""), 14087677454934409008);
+        assert_ne!(seed_from_str("experiment-42"), seed_from_str("experiment-43"));
+    }
+
+    #[test]
+    fn seed_from_hash_is_platform_independent() {
+        // Little-endian u32 followed by the str bytes and the 0xff terminator.
+        assert_eq!(seed_from_hash(&(7u32, "2024-05-01")), 14403473474061662062);
+        assert_eq!(seed_from_hash(&5usize), seed_from_hash(&5u64));
+    }
+
+    #[test]
+    fn seed_sequence_matches_numpy() {
+        // Reference data from NumPy's test_seed_sequence.py.
+        let ss = SeedSequence::new(&[3735928559, 195939070, 229505742, 305419896]);
+        let mut words = [0u32; 4];
+        ss.generate_state_u32(&mut words);
+        assert_eq!(words, [3914649087, 576849849, 3593928901, 2229911004]);
+
+        // Spawn keys (0,) and (1, 2) continue the root input, as in NumPy.
+        let mut root = SeedSequence::new(&[12345]);
+        let mut state = [0u64; 4];
+        root.generate_state_u64(&mut state);
+        assert_eq!(state[0], 13091511679009522556);
+        root.spawn().generate_state_u64(&mut state[..1]);
+        assert_eq!(state[0], 13729193726644583001);
+        let mut child = root.spawn();
+        let _ = (child.spawn(), child.spawn());
+        child.spawn().generate_state_u64(&mut state[..1]);
+        assert_eq!(state[0], 10984387000649362038);
+
+        let long = SeedSequence::new(&[1, 2, 3, 4, 5, 1 << 40]);
+        long.generate_state_u32(&mut words);
+        assert_eq!(words, [3475487598, 4037002046, 3239956225, 3268584874]);
+    }
+
+    #[test]
+    fn fill_seed_is_deterministic() {
+        let mut a = [0u8; 48];
+        let mut b = [0u8; 48];
+        fill_seed_from_bytes(b"run", &mut a);
+        fill_seed_from_bytes(b"run", &mut b);
+        assert_eq!(a, b);
+        assert!(a.iter().any(|&x| x != 0));
+    }
+}
@@ -0,0 +1,114 @@
+//! Iterator over random `k`-subsets of `0..n`.
+
+use alloc::vec::Vec;
+
+use super::gen_index;
+use crate::backend::RandomBackend;
+use crate::rng::Rng;
+use crate::AporiaError;
+
+/// Returns an endless iterator of uniformly random `k`-subsets of `0..n`.
+///
+/// Each item is a sorted `Vec` of `k` distinct indices, and every one of the `C(n, k)`
+/// subsets is equally likely. Draws are independent, so a subset can repeat.
+///
+/// Each draw uses Floyd's combination algorithm, keeping the chosen indices sorted as they
+/// are inserted, so it costs `k` bounded draws and no table of `n` entries. When `k` is
+/// more than half of `n` the `n - k` excluded indices are drawn instead.
+///
+/// # Errors
+///
+/// Returns [`AporiaError::InvalidParameter`] if `k > n`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, seq::combinations};
+///
+/// let mut rng = Rng::new(SplitMix64::new(19));
+/// let flags = ["-O", "-g", "-Wall", "-fPIC", "-flto", "-march=native"];
+/// for subset in combinations(&mut rng, flags.len(), 3).unwrap().take(5) {
+///     let args: Vec<&str> = subset.iter().map(|&i| flags[i]).collect();
+///     // compile with `args`
+/// }
+/// ```
+pub fn combinations<B: RandomBackend>(rng: &mut Rng<B>, n: usize, k: usize) -> Result<Combinations<'_, B>, AporiaError> {
+    if k > n {
+        return Err(AporiaError::InvalidParameter("subset size must not exceed the set size"));
+    }
+    Ok(Combinations { rng, n, k })
+}
+
+/// Iterator over random subsets, created by [`combinations`].
+#[derive(Debug)]
+pub struct Combinations<'a, B: RandomBackend> {
+    rng: &'a mut Rng<B>,
+    n: usize,
+    k: usize,
+}
+
+impl<B: RandomBackend> Iterator for Combinations<'_, B> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let (n, k) = (self.n, self.k);
+        if k <= n / 2 {
+            return Some(floyd_sorted(self.rng, n, k));
+        }
+        let excluded = floyd_sorted(self.rng, n, n - k);
+        let mut subset = Vec::with_capacity(k);
+        let mut skip = excluded.iter().peekable();
+        for i in 0..n {
+            if skip.next_if_eq(&&i).is_none() {
+                subset.push(i);
+            }
+        }
+        Some(subset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Floyd's algorithm, returning the chosen indices in increasing order.
+fn floyd_sorted<B: RandomBackend>(rng: &mut Rng<B>, n: usize, k: usize) -> Vec<usize> {
+    let mut chosen = Vec::with_capacity(k);
+    for j in n - k..n {
+        let t = gen_index(rng, 0, j + 1);
+        match chosen.binary_search(&t) {
+            // Everything chosen so far is below j, so j goes at the end.
+            Ok(_) => chosen.push(j),
+            Err(pos) => chosen.insert(pos, t),
+        }
+    }
+    chosen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn subsets_are_uniform_and_sorted() {
+        let mut rng = Rng::new(SplitMix64::new(33));
+        // (5, 2) draws the subset directly and (5, 4) draws its complement.
+        for (k, subsets) in [(2usize, 10u32), (4, 5)] {
+            let mut counts = [0u32; 32];
+            for subset in combinations(&mut rng, 5, k).unwrap().take(20_000) {
+                assert_eq!(subset.len(), k);
+                assert!(subset.windows(2).all(|w| w[0] < w[1]) && subset.iter().all(|&i| i < 5));
+                counts[subset.iter().map(|&i| 1 << i).sum::<usize>()] += 1;
+            }
+            let hits: Vec<u32> = counts.into_iter().filter(|&c| c > 0).collect();
+            assert_eq!(hits.len() as u32, subsets);
+            let expected = 20_000 / subsets;
+            assert!(hits.iter().all(|c| c.abs_diff(expected) < expected / 10), "{:?}", hits);
+        }
+
+        assert_eq!(combinations(&mut rng, 3, 0).unwrap().next(), Some(Vec::new()));
+        assert_eq!(combinations(&mut rng, 3, 3).unwrap().next(), Some(Vec::from([0, 1, 2])));
+        assert!(combinations(&mut rng, 3, 4).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+//! Random permutations without fixed points.
+
+use alloc::vec::Vec;
+
+use super::gen_index;
+use crate::backend::RandomBackend;
+use crate::rng::Rng;
+use crate::AporiaError;
+
+/// Returns a uniformly random derangement of `0..n`: a permutation in which no index maps
+/// to itself.
+///
+/// Entry `i` of the result is the index whose element moves to position `i`, so
+/// `result[i] != i` for every `i`. Useful for secret-santa style assignments, where
+/// person `i` gives to person `result[i]`.
+///
+/// A Fisher-Yates shuffle is restarted as soon as a position receives its own index. Every
+/// completed run is uniform over permutations and rejection only discards runs with a
+/// fixed point, so the result is uniform over derangements. About `e` runs are started on
+/// average, most of which abort early.
+///
+/// # Errors
+///
+/// Returns [`AporiaError::InvalidParameter`] if `n == 1`, which has no derangement.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, seq::derangement};
+///
+/// let mut rng = Rng::new(SplitMix64::new(18));
+/// let people = ["Ana", "Ben", "Chloe", "Dev"];
+/// let gives_to = derangement(&mut rng, people.len()).unwrap();
+/// for (giver, &receiver) in gives_to.iter().enumerate() {
+///     assert_ne!(giver, receiver);
+/// }
+/// ```
+pub fn derangement<B: RandomBackend>(rng: &mut Rng<B>, n: usize) -> Result<Vec<usize>, AporiaError> {
+    let mut perm = Vec::with_capacity(n);
+    let mut swaps = Vec::with_capacity(n);
+    derange_indices(rng, n, &mut perm, &mut swaps)?;
+    Ok(perm)
+}
+
+/// Writes a derangement of `0..n` to `perm`, and to `swaps` the Fisher-Yates swap targets
+/// that produce it: `swaps[k]` was swapped with position `n - 1 - k`.
+pub(super) fn derange_indices<B: RandomBackend>(
+    rng: &mut Rng<B>,
+    n: usize,
+    perm: &mut Vec<usize>,
+    swaps: &mut Vec<usize>,
+) -> Result<(), AporiaError> {
+    if n == 1 {
+        return Err(AporiaError::InvalidParameter("a single element has no derangement"));
+    }
+    'attempt: loop {
+        perm.clear();
+        perm.extend(0..n);
+        swaps.clear();
+        for i in (1..n).rev() {
+            let j = gen_index(rng, 0, i + 1);
+            perm.swap(i, j);
+            swaps.push(j);
+            // Position i is final once swapped, so a fixed point here rejects the run.
+            if perm[i] == i {
+                continue 'attempt;
+            }
+        }
+        if n == 0 || perm[0] != 0 {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+    use crate::seq::SliceRandom;
+
+    #[test]
+    fn derangements_are_uniform() {
+        let mut rng = Rng::new(SplitMix64::new(31));
+        // Each of the 9 derangements of 0..4 is identified by its first three entries.
+        let mut counts = [0u32; 64];
+        for _ in 0..36_000 {
+            let d = derangement(&mut rng, 4).unwrap();
+            assert!(d.iter().enumerate().all(|(i, &x)| i != x));
+            counts[d[0] * 16 + d[1] * 4 + d[2]] += 1;
+        }
+        let hits: Vec<u32> = counts.into_iter().filter(|&c| c > 0).collect();
+        assert_eq!(hits.len(), 9);
+        assert!(hits.iter().all(|c| c.abs_diff(4_000) < 400), "{:?}", hits);
+
+        assert!(derangement(&mut rng, 1).is_err());
+        assert!(derangement(&mut rng, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn derange_moves_every_element() {
+        let mut rng = Rng::new(SplitMix64::new(32));
+        for _ in 0..100 {
+            let mut v = ['a', 'b', 'c', 'd', 'e', 'f'];
+            v.derange(&mut rng).unwrap();
+            assert!(v.iter().zip("abcdef".chars()).all(|(&x, y)| x != y));
+            let mut sorted = v;
+            sorted.sort_unstable();
+            assert_eq!(sorted, ['a', 'b', 'c', 'd', 'e', 'f']);
+        }
+        assert!([1].derange(&mut rng).is_err());
+    }
+}
@@ -0,0 +1,307 @@
+//! Sampling distinct indices from `0..n`.
+//!
+//! [`sample`] returns `k` distinct indices chosen uniformly from `0..n`, in random order, as
+//! an [`IndexVec`]. It chooses the algorithm from `k` and `n` so that the work and memory
+//! stay close to `O(k)`:
+//!
+//! - Floyd's combination algorithm for small `k`: `O(k^2)` comparisons, no extra memory.
+//! - A partial Fisher-Yates shuffle of `0..n` when `k` is a large fraction of `n`, so the
+//!   `O(n)` table is at most a few times the size of the result.
+//! - Rejection sampling against an ordered set otherwise, drawing at most `4k/3` indices
+//!   on average.
+//!
+//! Indices are stored as `u32` whenever `n` fits, halving the memory on 64-bit targets.
+//! This module requires the `alloc` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, backend::SplitMix64, seq::index};
+//!
+//! let mut rng = Rng::new(SplitMix64::new(16));
+//! // 1000 distinct rows of a ten-billion-row table, without a ten-billion-entry buffer.
+//! let rows = index::sample(&mut rng, 10_000_000_000, 1000);
+//! assert_eq!(rows.len(), 1000);
+//! for row in rows.iter() {
+//!     assert!(row < 10_000_000_000);
+//! }
+//! ```
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use super::{SliceRandom, gen_index};
+use crate::SampleUniform;
+use crate::backend::RandomBackend;
+use crate::rng::Rng;
+
+/// Largest `k` for which Floyd's quadratic scan beats the other methods.
+const FLOYD_MAX: usize = 32;
+
+/// `n` up to this multiple of `k` is shuffled rather than sampled by rejection.
+const SHUFFLE_RATIO: usize = 4;
+
+/// Returns `amount` distinct indices from `0..length`, chosen uniformly and in random order.
+///
+/// # Panics
+///
+/// Panics if `amount > length`.
+pub fn sample<B: RandomBackend>(rng: &mut Rng<B>, length: usize, amount: usize) -> IndexVec {
+    assert!(amount <= length, "cannot sample more indices than the length");
+    if length <= u32::MAX as usize {
+        IndexVec(Repr::U32(sample_with(rng, length as u32, amount)))
+    } else {
+        IndexVec(Repr::USize(sample_with(rng, length, amount)))
+    }
+}
+
+fn sample_with<B: RandomBackend, T: Index>(rng: &mut Rng<B>, length: T, amount: usize) -> Vec<T> {
+    if amount <= FLOYD_MAX {
+        sample_floyd(rng, length, amount)
+    } else if length.as_usize() / SHUFFLE_RATIO <= amount {
+        sample_shuffle(rng, length, amount)
+    } else {
+        sample_rejection(rng, length, amount)
+    }
+}
+
+/// Floyd's combination algorithm, inserting each collision at the position of the value it
+/// replaces so that the output order is uniformly random as well.
+fn sample_floyd<B: RandomBackend, T: Index>(rng: &mut Rng<B>, length: T, amount: usize) -> Vec<T> {
+    let length = length.as_usize();
+    let mut indices = Vec::with_capacity(amount);
+    for j in length - amount..length {
+        let t = gen_index(rng, T::ZERO, T::from_usize(j + 1));
+        match indices.iter().position(|&i| i == t) {
+            Some(pos) => indices.insert(pos, T::from_usize(j)),
+            None => indices.push(t),
+        }
+    }
+    indices
+}
+
+/// Partial Fisher-Yates shuffle of the whole index range.
+fn sample_shuffle<B: RandomBackend, T: Index>(rng: &mut Rng<B>, length: T, amount: usize) -> Vec<T> {
+    let mut indices: Vec<T> = (0..length.as_usize()).map(T::from_usize).collect();
+    indices.partial_shuffle(rng, amount);
+    indices.truncate(amount);
+    indices
+}
+
+/// Draws indices until `amount` distinct ones have been seen, keeping them in draw order.
+fn sample_rejection<B: RandomBackend, T: Index>(rng: &mut Rng<B>, length: T, amount: usize) -> Vec<T> {
+    let mut seen = BTreeSet::new();
+    let mut indices = Vec::with_capacity(amount);
+    while indices.len() < amount {
+        let i = gen_index(rng, T::ZERO, length);
+        if seen.insert(i) {
+            indices.push(i);
+        }
+    }
+    indices
+}
+
+/// Index types an [`IndexVec`] can store.
+trait Index: SampleUniform + Copy + Ord {
+    const ZERO: Self;
+    fn from_usize(i: usize) -> Self;
+    fn as_usize(self) -> usize;
+}
+
+impl Index for u32 {
+    const ZERO: Self = 0;
+
+    #[inline]
+    fn from_usize(i: usize) -> Self {
+        i as u32
+    }
+
+    #[inline]
+    fn as_usize(self) -> usize {
+        self as usize
+    }
+}
+
+impl Index for usize {
+    const ZERO: Self = 0;
+
+    #[inline]
+    fn from_usize(i: usize) -> Self {
+        i
+    }
+
+    #[inline]
+    fn as_usize(self) -> usize {
+        self
+    }
+}
+
+/// Distinct indices returned by [`sample`], stored as `u32` when the range allows it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexVec(Repr);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Repr {
+    U32(Vec<u32>),
+    USize(Vec<usize>),
+}
+
+impl IndexVec {
+    /// Returns the number of indices.
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Repr::U32(v) => v.len(),
+            Repr::USize(v) => v.len(),
+        }
+    }
+
+    /// Returns `true` if there are no indices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `i`-th index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.len()`.
+    pub fn index(&self, i: usize) -> usize {
+        match &self.0 {
+            Repr::U32(v) => v[i] as usize,
+            Repr::USize(v) => v[i],
+        }
+    }
+
+    /// Returns an iterator over the indices.
+    pub fn iter(&self) -> IndexVecIter<'_> {
+        match &self.0 {
+            Repr::U32(v) => IndexVecIter::U32(v.iter()),
+            Repr::USize(v) => IndexVecIter::USize(v.iter()),
+        }
+    }
+
+    /// Returns the indices as a `Vec<usize>`.
+    pub fn into_vec(self) -> Vec<usize> {
+        match self.0 {
+            Repr::U32(v) => v.into_iter().map(|i| i as usize).collect(),
+            Repr::USize(v) => v,
+        }
+    }
+}
+
+impl IntoIterator for IndexVec {
+    type Item = usize;
+    type IntoIter = IndexVecIntoIter;
+
+    fn into_iter(self) -> IndexVecIntoIter {
+        match self.0 {
+            Repr::U32(v) => IndexVecIntoIter::U32(v.into_iter()),
+            Repr::USize(v) => IndexVecIntoIter::USize(v.into_iter()),
+        }
+    }
+}
+
+/// Borrowing iterator over an [`IndexVec`].
+#[derive(Clone, Debug)]
+pub enum IndexVecIter<'a> {
+    #[doc(hidden)]
+    U32(core::slice::Iter<'a, u32>),
+    #[doc(hidden)]
+    USize(core::slice::Iter<'a, usize>),
+}
+
+impl Iterator for IndexVecIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self {
+            IndexVecIter::U32(it) => it.next().map(|&i| i as usize),
+            IndexVecIter::USize(it) => it.next().copied(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IndexVecIter::U32(it) => it.size_hint(),
+            IndexVecIter::USize(it) => it.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for IndexVecIter<'_> {}
+
+/// Owning iterator over an [`IndexVec`].
+#[derive(Clone, Debug)]
+pub enum IndexVecIntoIter {
+    #[doc(hidden)]
+    U32(alloc::vec::IntoIter<u32>),
+    #[doc(hidden)]
+    USize(alloc::vec::IntoIter<usize>),
+}
+
+impl Iterator for IndexVecIntoIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self {
+            IndexVecIntoIter::U32(it) => it.next().map(|i| i as usize),
+            IndexVecIntoIter::USize(it) => it.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IndexVecIntoIter::U32(it) => it.size_hint(),
+            IndexVecIntoIter::USize(it) => it.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for IndexVecIntoIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    type Sampler = fn(&mut Rng<SplitMix64>, u32, usize) -> Vec<u32>;
+
+    #[test]
+    fn every_method_is_uniform() {
+        let mut rng = Rng::new(SplitMix64::new(23));
+        let methods: [Sampler; 3] = [sample_floyd, sample_shuffle, sample_rejection];
+        for method in methods {
+            let (length, amount) = (10, 3);
+            let mut first = [0u32; 10];
+            let mut seen = [0u32; 10];
+            for _ in 0..30_000 {
+                let v = method(&mut rng, length, amount);
+                assert_eq!(v.len(), amount);
+                for (k, &i) in v.iter().enumerate() {
+                    assert!(i < length && !v[..k].contains(&i));
+                    seen[i as usize] += 1;
+                }
+                first[v[0] as usize] += 1;
+            }
+            for i in 0..10 {
+                assert!(first[i].abs_diff(3_000) < 300, "{:?}", first);
+                assert!(seen[i].abs_diff(9_000) < 900, "{:?}", seen);
+            }
+        }
+    }
+
+    #[test]
+    fn sample_covers_edge_cases() {
+        let mut rng = Rng::new(SplitMix64::new(24));
+        assert!(sample(&mut rng, 0, 0).is_empty());
+        for (length, amount) in [(100, 100), (100, 40), (1000, 40), (5, 0)] {
+            let v = sample(&mut rng, length, amount);
+            let mut sorted = v.clone().into_vec();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), amount);
+            assert!(v.iter().all(|i| i < length));
+            assert_eq!(v.iter().collect::<Vec<_>>(), v.into_iter().collect::<Vec<_>>());
+        }
+    }
+}
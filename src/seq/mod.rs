@@ -0,0 +1,422 @@
+//! Randomized operations on slices and iterators.
+//!
+//! [`SliceRandom`] extends `[T]` (and so arrays and `Vec`s) with methods that take the
+//! generator as an argument. [`SliceRandom::shuffle`] permutes a slice in place with the
+//! Fisher-Yates algorithm, drawing each swap position with the unbiased [`Rng::gen_range`],
+//! so every permutation is equally likely. [`SliceRandom::partial_shuffle`] stops after the
+//! first `k` positions, which selects `k` random elements in `O(k)` draws.
+//!
+//! With the `alloc` feature, [`SliceRandom::choose_multiple`] picks `k` distinct elements
+//! without modifying the slice, and the [`index`] module picks `k` distinct indices from
+//! `0..n` in about `O(k)` time and memory, choosing between Floyd's algorithm, a partial
+//! shuffle and rejection sampling from `k` and `n`. [`SliceRandom::choose_weighted`] picks
+//! one element with probability proportional to a weight computed from it. [`derangement`]
+//! and [`SliceRandom::derange`] produce permutations that move every element, and
+//! [`combinations`] yields random `k`-subsets of `0..n`.
+//!
+//! [`IteratorRandom`] chooses from iterators that cannot be indexed, such as the lines of a
+//! file or a filtered stream, by reservoir sampling in a single pass. [`ReservoirSampler`]
+//! and [`WeightedReservoirSampler`] keep a reservoir across calls, for streams that arrive
+//! in batches.
+//!
+//! [`RandomPermutation`] shuffles `0..n` without materializing it, mapping each index to its
+//! shuffled position on demand.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aporia::{Rng, SliceRandom, backend::Xoshiro256PlusPlus};
+//!
+//! let mut rng = Rng::new(Xoshiro256PlusPlus::new(7));
+//! let mut deck: [u8; 52] = core::array::from_fn(|i| i as u8);
+//! deck.shuffle(&mut rng);
+//! ```
+//!
+//! # References
+//!
+//! - [Durstenfeld (1964), "Algorithm 235: Random permutation"](https://doi.org/10.1145/364520.364540)
+//! - [Vitter (1985), "Random sampling with a reservoir"](https://doi.org/10.1145/3147.3165)
+//! - [Efraimidis, Spirakis (2006), "Weighted random sampling with a reservoir"](https://doi.org/10.1016/j.ipl.2005.11.003)
+//! - [Bentley, Floyd (1987), "Programming pearls: a sample of brilliance"](https://doi.org/10.1145/30401.315746)
+
+#[cfg(feature = "alloc")]
+mod combinations;
+#[cfg(feature = "alloc")]
+mod derangement;
+#[cfg(feature = "alloc")]
+pub mod index;
+mod permutation;
+#[cfg(feature = "alloc")]
+mod reservoir;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::AporiaError;
+#[cfg(feature = "alloc")]
+use crate::distributions::WeightedIndex;
+
+#[cfg(feature = "alloc")]
+pub use self::combinations::{Combinations, combinations};
+#[cfg(feature = "alloc")]
+pub use self::derangement::derangement;
+pub use self::permutation::{PermutationIter, RandomPermutation};
+#[cfg(feature = "alloc")]
+pub use self::reservoir::ReservoirSampler;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use self::reservoir::WeightedReservoirSampler;
+
+use crate::SampleUniform;
+use crate::backend::RandomBackend;
+use crate::rng::Rng;
+
+/// Extension trait for randomizing slices.
+pub trait SliceRandom {
+    /// The element type of the slice.
+    type Item;
+
+    /// Shuffles the elements in place, making every permutation equally likely.
+    ///
+    /// Draws one bounded index per element except the last.
+    fn shuffle<B: RandomBackend>(&mut self, rng: &mut Rng<B>);
+
+    /// Moves `amount` uniformly chosen elements, in random order, to the front.
+    ///
+    /// Returns the shuffled prefix and the remaining elements, whose order is unspecified.
+    /// Only `amount` indices are drawn, so this is much cheaper than a full shuffle when
+    /// `amount` is small. An `amount` above the length shuffles the whole slice.
+    ///
+    /// ```rust
+    /// use aporia::{Rng, SliceRandom, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(4));
+    /// let mut ids: Vec<u32> = (0..10_000).collect();
+    /// let (winners, _rest) = ids.partial_shuffle(&mut rng, 3);
+    /// assert_eq!(winners.len(), 3);
+    /// ```
+    fn partial_shuffle<B: RandomBackend>(
+        &mut self,
+        rng: &mut Rng<B>,
+        amount: usize,
+    ) -> (&mut [Self::Item], &mut [Self::Item]);
+
+    /// Returns an iterator over `amount` distinct elements chosen uniformly, in random order.
+    ///
+    /// The slice is left unchanged. An `amount` above the length yields every element.
+    ///
+    /// ```rust
+    /// use aporia::{Rng, SliceRandom, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(6));
+    /// let pool = ["ash", "birch", "cedar", "elm", "fir", "oak"];
+    /// let planted: Vec<&str> = pool.choose_multiple(&mut rng, 3).copied().collect();
+    /// assert_eq!(planted.len(), 3);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn choose_multiple<B: RandomBackend>(&self, rng: &mut Rng<B>, amount: usize) -> ChooseMultiple<'_, Self::Item>;
+
+    /// Returns one element chosen with probability proportional to `weight(element)`.
+    ///
+    /// The weights are collected into a [`WeightedIndex`] on every call; build one directly
+    /// to draw repeatedly from the same table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::AllWeightsZero`] if every weight is zero, and
+    /// [`AporiaError::InvalidParameter`] if the slice is empty or a weight is negative, NaN
+    /// or infinite.
+    ///
+    /// ```rust
+    /// use aporia::{Rng, SliceRandom, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(12));
+    /// let loot = [("copper", 70.0), ("silver", 25.0), ("gold", 5.0)];
+    /// let (metal, _) = loot.choose_weighted(&mut rng, |&(_, w)| w).unwrap();
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn choose_weighted<B, F>(&self, rng: &mut Rng<B>, weight: F) -> Result<&Self::Item, AporiaError>
+    where
+        B: RandomBackend,
+        F: FnMut(&Self::Item) -> f64;
+
+    /// Permutes the elements so that none stays at its index, uniformly over all such
+    /// permutations; see [`derangement`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] for a slice of length 1, which is left
+    /// unchanged.
+    #[cfg(feature = "alloc")]
+    fn derange<B: RandomBackend>(&mut self, rng: &mut Rng<B>) -> Result<(), AporiaError>;
+}
+
+impl<T> SliceRandom for [T] {
+    type Item = T;
+
+    fn shuffle<B: RandomBackend>(&mut self, rng: &mut Rng<B>) {
+        self.partial_shuffle(rng, self.len());
+    }
+
+    fn partial_shuffle<B: RandomBackend>(&mut self, rng: &mut Rng<B>, amount: usize) -> (&mut [T], &mut [T]) {
+        let len = self.len();
+        let amount = amount.min(len);
+        // The last position of a full shuffle has nothing left to swap with.
+        for i in 0..amount.min(len.saturating_sub(1)) {
+            self.swap(i, gen_index(rng, i, len));
+        }
+        self.split_at_mut(amount)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn choose_multiple<B: RandomBackend>(&self, rng: &mut Rng<B>, amount: usize) -> ChooseMultiple<'_, T> {
+        let indices = index::sample(rng, self.len(), amount.min(self.len()));
+        ChooseMultiple { slice: self, indices: indices.into_iter() }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn choose_weighted<B, F>(&self, rng: &mut Rng<B>, weight: F) -> Result<&T, AporiaError>
+    where
+        B: RandomBackend,
+        F: FnMut(&T) -> f64,
+    {
+        let weights: Vec<f64> = self.iter().map(weight).collect();
+        let index = WeightedIndex::new(&weights)?;
+        Ok(&self[rng.sample(&index)])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn derange<B: RandomBackend>(&mut self, rng: &mut Rng<B>) -> Result<(), AporiaError> {
+        let (mut perm, mut swaps) = (Vec::new(), Vec::new());
+        derangement::derange_indices(rng, self.len(), &mut perm, &mut swaps)?;
+        // Replay the accepted run's swaps on the elements themselves.
+        for (k, &j) in swaps.iter().enumerate() {
+            self.swap(self.len() - 1 - k, j);
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over elements chosen by [`SliceRandom::choose_multiple`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ChooseMultiple<'a, T> {
+    slice: &'a [T],
+    indices: index::IndexVecIntoIter,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> Iterator for ChooseMultiple<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.indices.next().map(|i| &self.slice[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ExactSizeIterator for ChooseMultiple<'_, T> {}
+
+/// Returns `amount` distinct indices from `0..length`, chosen uniformly and in random order.
+///
+/// This is [`index::sample`] collected into a `Vec<usize>`.
+///
+/// # Panics
+///
+/// Panics if `amount > length`.
+#[cfg(feature = "alloc")]
+pub fn sample_indices<B: RandomBackend>(rng: &mut Rng<B>, length: usize, amount: usize) -> Vec<usize> {
+    index::sample(rng, length, amount).into_vec()
+}
+
+/// Extension trait for choosing from iterators by reservoir sampling.
+///
+/// Implemented for every [`Iterator`]. The iterator is consumed in one pass, drawing a
+/// bounded index per element; when [`Iterator::size_hint`] reports an exact length,
+/// [`IteratorRandom::choose`] instead draws a single index and skips to it.
+pub trait IteratorRandom: Iterator + Sized {
+    /// Returns one element chosen uniformly, or `None` if the iterator is empty.
+    ///
+    /// ```rust
+    /// use aporia::{IteratorRandom, Rng, backend::SplitMix64};
+    ///
+    /// let mut rng = Rng::new(SplitMix64::new(13));
+    /// let text = "alpha\nbeta\ngamma\n";
+    /// let line = text.lines().choose(&mut rng).unwrap();
+    /// ```
+    fn choose<B: RandomBackend>(mut self, rng: &mut Rng<B>) -> Option<Self::Item> {
+        let (lower, upper) = self.size_hint();
+        if upper == Some(lower) {
+            return if lower == 0 { None } else { self.nth(gen_index(rng, 0, lower)) };
+        }
+        let mut chosen = None;
+        for (seen, item) in self.enumerate() {
+            // Keep the `seen + 1`-th element with probability `1 / (seen + 1)`.
+            if gen_index(rng, 0, seen + 1) == 0 {
+                chosen = Some(item);
+            }
+        }
+        chosen
+    }
+
+    /// Returns `amount` distinct elements chosen uniformly, or every element if there are
+    /// fewer.
+    ///
+    /// Uses Vitter's Algorithm R. The set of elements is uniform but their order is not;
+    /// shuffle the result if the order matters.
+    #[cfg(feature = "alloc")]
+    fn choose_multiple<B: RandomBackend>(self, rng: &mut Rng<B>, amount: usize) -> Vec<Self::Item> {
+        let mut reservoir = Vec::with_capacity(amount.min(self.size_hint().0));
+        for (seen, item) in self.enumerate() {
+            if seen < amount {
+                reservoir.push(item);
+            } else {
+                let j = gen_index(rng, 0, seen + 1);
+                if j < amount {
+                    reservoir[j] = item;
+                }
+            }
+        }
+        reservoir
+    }
+}
+
+impl<I: Iterator> IteratorRandom for I {}
+
+/// Draws an index uniformly from `[low, high)`, which must be non-empty.
+#[inline]
+fn gen_index<B: RandomBackend, T: SampleUniform>(rng: &mut Rng<B>, low: T, high: T) -> T {
+    match rng.gen_range(low..high) {
+        Ok(i) => i,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn shuffle_is_a_uniform_permutation() {
+        let mut rng = Rng::new(SplitMix64::new(21));
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            let mut v = [0u8, 1, 2];
+            v.shuffle(&mut rng);
+            let mut sorted = v;
+            sorted.sort_unstable();
+            assert_eq!(sorted, [0, 1, 2]);
+            // Index the permutation by its first two entries.
+            counts[(v[0] * 2 + (v[1] > v[2]) as u8) as usize] += 1;
+        }
+        for c in counts {
+            assert!((9_500..10_500).contains(&c), "{}", c);
+        }
+
+        let mut empty: [u8; 0] = [];
+        empty.shuffle(&mut rng);
+        let mut one = [9];
+        one.shuffle(&mut rng);
+        assert_eq!(one, [9]);
+    }
+
+    #[test]
+    fn partial_shuffle_picks_uniform_prefixes() {
+        let mut rng = Rng::new(SplitMix64::new(22));
+        let mut counts = [0u32; 10];
+        for _ in 0..20_000 {
+            let mut v = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            let (head, tail) = v.partial_shuffle(&mut rng, 2);
+            assert_eq!((head.len(), tail.len()), (2, 8));
+            assert_ne!(head[0], head[1]);
+            counts[head[0] as usize] += 1;
+            counts[head[1] as usize] += 1;
+        }
+        for c in counts {
+            assert!((3_700..4_300).contains(&c), "{}", c);
+        }
+
+        // A full-length partial shuffle draws the same permutation as shuffle.
+        let (mut a, mut b) = ([1, 2, 3, 4, 5], [1, 2, 3, 4, 5]);
+        let mut other = rng.clone();
+        assert_eq!(a.partial_shuffle(&mut rng, 99).0.len(), 5);
+        b.shuffle(&mut other);
+        assert_eq!(a, b);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn choose_multiple_returns_distinct_elements() {
+        let mut rng = Rng::new(SplitMix64::new(23));
+        let pool: Vec<u32> = (0..50).collect();
+        let picks: Vec<u32> = pool.choose_multiple(&mut rng, 20).copied().collect();
+        let mut sorted = picks.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 20);
+
+        let pool = [1, 2, 3];
+        let mut all: Vec<i32> = pool.choose_multiple(&mut rng, 5).copied().collect();
+        all.sort_unstable();
+        assert_eq!(all, pool);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn choose_weighted_follows_weights() {
+        let mut rng = Rng::new(SplitMix64::new(24));
+        let table = [('a', 1.0), ('b', 0.0), ('c', 3.0)];
+        let mut counts = [0u32; 3];
+        for _ in 0..40_000 {
+            let &(item, _) = table.choose_weighted(&mut rng, |&(_, w)| w).unwrap();
+            counts[(item as u8 - b'a') as usize] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!((9_500..10_500).contains(&counts[0]), "{:?}", counts);
+
+        let zero = table.choose_weighted(&mut rng, |_| 0.0);
+        assert_eq!(zero, Err(AporiaError::AllWeightsZero));
+        let empty: [u8; 0] = [];
+        assert!(empty.choose_weighted(&mut rng, |_| 1.0).is_err());
+    }
+
+    #[test]
+    fn iterator_choose_is_uniform() {
+        let mut rng = Rng::new(SplitMix64::new(25));
+        let (mut exact, mut filtered) = ([0u32; 5], [0u32; 5]);
+        for _ in 0..25_000 {
+            exact[(0..5).choose(&mut rng).unwrap()] += 1;
+            let even = (0..10).filter(|i| i % 2 == 0).choose(&mut rng).unwrap();
+            filtered[even / 2] += 1;
+        }
+        for c in exact.iter().chain(&filtered) {
+            assert!((4_700..5_300).contains(c), "{:?} {:?}", exact, filtered);
+        }
+        assert_eq!((0..0).choose(&mut rng), None);
+        assert_eq!(core::iter::empty::<u8>().filter(|_| true).choose(&mut rng), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iterator_choose_multiple_is_uniform() {
+        let mut rng = Rng::new(SplitMix64::new(26));
+        let mut seen = [0u32; 8];
+        for _ in 0..20_000 {
+            let picks = (0..8).filter(|_| true).choose_multiple(&mut rng, 3);
+            assert_eq!(picks.len(), 3);
+            for (k, &i) in picks.iter().enumerate() {
+                assert!(!picks[..k].contains(&i));
+                seen[i] += 1;
+            }
+        }
+        for c in seen {
+            assert!((7_100..7_900).contains(&c), "{:?}", seen);
+        }
+        assert_eq!((0..2).choose_multiple(&mut rng, 5), [0, 1]);
+    }
+}
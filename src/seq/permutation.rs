@@ -0,0 +1,167 @@
+//! Allocation-free random permutation of `0..n`.
+
+use crate::backend::{RandomBackend, mix64};
+use crate::rng::Rng;
+
+/// Number of Feistel rounds. It must be even so that the output uses the same split of the
+/// domain as the input.
+const ROUNDS: usize = 8;
+
+/// Seeded random permutation of `0..len`, evaluated one index at a time.
+///
+/// [`nth(i)`](Self::nth) returns the position that `i` is shuffled to, in `O(1)` time and
+/// without any table, so permutations of billions of indices cost a few words of memory.
+/// The same keys always give the same permutation, and different indices never collide.
+///
+/// The permutation is a format-preserving cipher: an index is split into a pair
+/// `(l, r)` with `l < p` and `r < q`, where `p * q` is the smallest near-square product
+/// covering `len`, and passed through eight keyed Feistel rounds
+/// `(l, r) -> (r, (l + F(r)) mod p)` whose radices alternate between `p` and `q`. Results
+/// that land in `len..p * q` are encrypted again (cycle walking) until they fall inside
+/// the domain, which takes fewer than two passes on average.
+///
+/// This is a pseudorandom permutation for sampling and scheduling, not a cryptographic
+/// one, and it is not drawn exactly uniformly from all `len!` permutations.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, seq::RandomPermutation};
+///
+/// let mut rng = Rng::new(SplitMix64::new(17));
+/// let perm = RandomPermutation::new(&mut rng, 5_000_000_000);
+/// let first = perm.nth(0);
+/// assert!(first < 5_000_000_000);
+/// for shuffled in perm.iter().take(3) {
+///     // visit record `shuffled`
+/// }
+/// ```
+///
+/// # References
+///
+/// - [Black, Rogaway (2002), "Ciphers with Arbitrary Finite Domains"](https://doi.org/10.1007/3-540-45760-7_9)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RandomPermutation {
+    len: u64,
+    p: u64,
+    q: u64,
+    keys: [u64; ROUNDS],
+}
+
+impl RandomPermutation {
+    /// Creates a permutation of `0..len` with round keys drawn from `rng`.
+    pub fn new<B: RandomBackend>(rng: &mut Rng<B>, len: u64) -> Self {
+        // p = ceil(sqrt(len)) and q = ceil(len / p), so len <= p * q < len + p.
+        let p = if len <= 1 { 1 } else { (len - 1).isqrt() + 1 };
+        let q = len.div_ceil(p).max(1);
+        Self { len, p, q, keys: core::array::from_fn(|_| rng.next_u64()) }
+    }
+
+    /// Returns the number of indices permuted.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the permutation is of the empty range.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the image of `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= self.len()`.
+    pub fn nth(&self, index: u64) -> u64 {
+        assert!(index < self.len, "index out of range for the permutation");
+        let mut x = index as u128;
+        loop {
+            x = self.encrypt(x);
+            if x < self.len as u128 {
+                return x as u64;
+            }
+        }
+    }
+
+    /// Returns an iterator over `nth(0), nth(1), ..., nth(len - 1)`.
+    pub fn iter(&self) -> PermutationIter<'_> {
+        PermutationIter { perm: self, next: 0 }
+    }
+
+    /// One pass of the Feistel network over `[0, p * q)`.
+    fn encrypt(&self, x: u128) -> u128 {
+        let (mut m, mut n) = (self.p as u128, self.q as u128);
+        let (mut l, mut r) = (x / n, x % n);
+        for &key in &self.keys {
+            let f = mix64(mix64((r as u64) ^ key).wrapping_add(key)) as u128;
+            (l, r) = (r, (l + f % m) % m);
+            (m, n) = (n, m);
+        }
+        l * n + r
+    }
+}
+
+/// Iterator over the images of a [`RandomPermutation`], created by
+/// [`RandomPermutation::iter`].
+#[derive(Clone, Debug)]
+pub struct PermutationIter<'a> {
+    perm: &'a RandomPermutation,
+    next: u64,
+}
+
+impl Iterator for PermutationIter<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.next >= self.perm.len {
+            return None;
+        }
+        let image = self.perm.nth(self.next);
+        self.next += 1;
+        Some(image)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.perm.len - self.next;
+        match usize::try_from(remaining) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn permutation_is_a_bijection() {
+        let mut rng = Rng::new(SplitMix64::new(29));
+        for len in [1u64, 2, 3, 7, 10, 99, 1000, 1021] {
+            let perm = RandomPermutation::new(&mut rng, len);
+            let mut hit = [false; 1021];
+            for image in perm.iter() {
+                assert!(!hit[image as usize]);
+                hit[image as usize] = true;
+            }
+            assert!(hit[..len as usize].iter().all(|&h| h));
+        }
+        assert_eq!(RandomPermutation::new(&mut rng, 0).iter().next(), None);
+
+        let huge = RandomPermutation::new(&mut rng, u64::MAX);
+        assert!(huge.nth(u64::MAX - 1) < u64::MAX);
+    }
+
+    #[test]
+    fn images_are_spread_over_keys() {
+        let mut rng = Rng::new(SplitMix64::new(30));
+        let mut counts = [0u32; 10];
+        for _ in 0..20_000 {
+            counts[RandomPermutation::new(&mut rng, 10).nth(3) as usize] += 1;
+        }
+        for c in counts {
+            assert!((1_800..2_200).contains(&c), "{:?}", counts);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Reservoirs that sample from a stream across several calls.
+
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "libm"))]
+use alloc::collections::BinaryHeap;
+
+use super::gen_index;
+use crate::backend::RandomBackend;
+use crate::rng::Rng;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::AporiaError;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::distributions::math::{exp, ln};
+
+/// Uniform sample of up to `k` items from a stream of unknown length.
+///
+/// Items are offered one at a time with [`observe`](Self::observe), possibly across many
+/// batches, and after any number of them the reservoir holds a uniform sample of `k`
+/// distinct positions (or every item, if fewer were seen). This is Vitter's Algorithm R:
+/// one bounded draw per item once the reservoir is full.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, seq::ReservoirSampler};
+///
+/// let mut rng = Rng::new(SplitMix64::new(14));
+/// let mut sampler = ReservoirSampler::new(3);
+/// for batch in [0..100, 100..250] {
+///     for request_id in batch {
+///         sampler.observe(&mut rng, request_id);
+///     }
+/// }
+/// assert_eq!(sampler.seen(), 250);
+/// assert_eq!(sampler.into_samples().len(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReservoirSampler<T> {
+    amount: usize,
+    seen: u64,
+    reservoir: Vec<T>,
+}
+
+impl<T> ReservoirSampler<T> {
+    /// Creates an empty reservoir keeping up to `amount` items.
+    pub fn new(amount: usize) -> Self {
+        Self { amount, seen: 0, reservoir: Vec::new() }
+    }
+
+    /// Offers the next item of the stream.
+    pub fn observe<B: RandomBackend>(&mut self, rng: &mut Rng<B>, item: T) {
+        self.seen += 1;
+        if self.reservoir.len() < self.amount {
+            self.reservoir.push(item);
+        } else {
+            let j = gen_index(rng, 0, self.seen);
+            if j < self.amount as u64 {
+                self.reservoir[j as usize] = item;
+            }
+        }
+    }
+
+    /// Returns the number of items observed so far.
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// Returns the current sample. Its order is not random.
+    pub fn samples(&self) -> &[T] {
+        &self.reservoir
+    }
+
+    /// Returns the sample, consuming the reservoir. Its order is not random.
+    pub fn into_samples(self) -> Vec<T> {
+        self.reservoir
+    }
+}
+
+/// Weighted sample of up to `k` items from a stream, without replacement.
+///
+/// Each item is kept with the probability it would have under `k` successive weighted
+/// draws without replacement. This is the A-ExpJ algorithm of Efraimidis and Spirakis:
+/// every item gets the key `u^(1/w)` and the `k` largest keys are kept (A-Res), but once
+/// the reservoir is full an exponential jump decides how much weight to skip before the
+/// next replacement, so only `O(k log(n / k))` random draws are needed for `n` items.
+/// Keys are kept as logarithms to stay accurate for very small and very large weights.
+///
+/// Items with zero weight are never selected. Requires the `alloc` feature and either
+/// `std` or `libm`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Rng, backend::SplitMix64, seq::WeightedReservoirSampler};
+///
+/// let mut rng = Rng::new(SplitMix64::new(15));
+/// let mut sampler = WeightedReservoirSampler::new(2);
+/// for (host, load) in [("a", 1.0), ("b", 8.0), ("c", 0.0), ("d", 3.0)] {
+///     sampler.observe(&mut rng, host, 1.0 / (1.0 + load)).unwrap();
+/// }
+/// assert_eq!(sampler.into_samples().len(), 2);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Debug)]
+pub struct WeightedReservoirSampler<T> {
+    amount: usize,
+    /// Min-heap on the log-keys, so the entry to evict is on top.
+    heap: BinaryHeap<Keyed<T>>,
+    /// Weight still to pass over before the next replacement, once the reservoir is full.
+    skip: f64,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> WeightedReservoirSampler<T> {
+    /// Creates an empty reservoir keeping up to `amount` items.
+    pub fn new(amount: usize) -> Self {
+        Self { amount, heap: BinaryHeap::new(), skip: 0.0 }
+    }
+
+    /// Offers the next item of the stream with its weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AporiaError::InvalidParameter`] if `weight` is negative, NaN or infinite;
+    /// the item is not observed in that case.
+    pub fn observe<B: RandomBackend>(&mut self, rng: &mut Rng<B>, item: T, weight: f64) -> Result<(), AporiaError> {
+        if !(weight.is_finite() && weight >= 0.0) {
+            return Err(AporiaError::InvalidParameter("weight must be finite and non-negative"));
+        }
+        if self.amount == 0 || weight == 0.0 {
+            return Ok(());
+        }
+        if self.heap.len() < self.amount {
+            let key = ln(rng.next_f64_open()) / weight;
+            self.heap.push(Keyed { key, item });
+            if self.heap.len() == self.amount {
+                self.draw_skip(rng);
+            }
+            return Ok(());
+        }
+        self.skip -= weight;
+        if self.skip > 0.0 {
+            return Ok(());
+        }
+        // This item replaces the smallest key. Its own key is drawn conditioned on
+        // exceeding that threshold: u uniform in (t^w, 1) for the threshold t.
+        if let Some(mut min) = self.heap.peek_mut() {
+            let floor = exp(min.key * weight);
+            let u = floor + (1.0 - floor) * rng.next_f64_open();
+            *min = Keyed { key: ln(u) / weight, item };
+        }
+        self.draw_skip(rng);
+        Ok(())
+    }
+
+    /// Draws the weight to skip as `ln(r) / ln(t)` for the current threshold `t`.
+    fn draw_skip<B: RandomBackend>(&mut self, rng: &mut Rng<B>) {
+        if let Some(min) = self.heap.peek() {
+            self.skip = ln(rng.next_f64_open()) / min.key;
+        }
+    }
+
+    /// Returns the number of items currently held.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if no item with positive weight has been observed.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the sample, consuming the reservoir. Its order is not random.
+    pub fn into_samples(self) -> Vec<T> {
+        self.heap.into_iter().map(|entry| entry.item).collect()
+    }
+}
+
+/// Reservoir entry ordered so that the smallest log-key is the heap maximum.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Debug)]
+struct Keyed<T> {
+    key: f64,
+    item: T,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key.total_cmp(&other.key).is_eq()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Eq for Keyed<T> {}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        other.key.total_cmp(&self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn reservoir_is_uniform_across_batches() {
+        let mut rng = Rng::new(SplitMix64::new(27));
+        let mut counts = [0u32; 10];
+        for _ in 0..20_000 {
+            let mut sampler = ReservoirSampler::new(2);
+            for batch in [0..3, 3..4, 4..10] {
+                for i in batch {
+                    sampler.observe(&mut rng, i);
+                }
+            }
+            let picks = sampler.into_samples();
+            assert!(picks.len() == 2 && picks[0] != picks[1]);
+            for i in picks {
+                counts[i] += 1;
+            }
+        }
+        for c in counts {
+            assert!((3_700..4_300).contains(&c), "{:?}", counts);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn weighted_reservoir_follows_weights() {
+        let mut rng = Rng::new(SplitMix64::new(28));
+        // With one slot, item i is kept with probability w_i / sum(w) = (i + 1) / 210,
+        // and the long stream exercises the exponential jumps.
+        let mut counts = [0u32; 20];
+        for _ in 0..42_000 {
+            let mut sampler = WeightedReservoirSampler::new(1);
+            for i in 0..20 {
+                sampler.observe(&mut rng, i, (i + 1) as f64).unwrap();
+            }
+            counts[sampler.into_samples()[0]] += 1;
+        }
+        for (i, &c) in counts.iter().enumerate() {
+            let expected = 200.0 * (i + 1) as f64;
+            assert!((c as f64 - expected).abs() < 5.0 * expected.sqrt() + 10.0, "{:?}", counts);
+        }
+
+        let mut sampler = WeightedReservoirSampler::new(3);
+        for i in 0..50 {
+            sampler.observe(&mut rng, i, if i % 2 == 0 { 1.0 } else { 0.0 }).unwrap();
+        }
+        assert!(sampler.observe(&mut rng, 99, -1.0).is_err());
+        let picks = sampler.into_samples();
+        assert!(picks.len() == 3 && picks.iter().all(|i| i % 2 == 0));
+    }
+}
@@ -0,0 +1,179 @@
+//! Generic generation of values of a type.
+//!
+//! [`Random`] describes how to draw a value of a type from a backend, which lets generic
+//! code such as [`crate::Rng::random`] produce values of any supported type. Tuples, arrays
+//! and `Option` are built from their element types, so `(f64, f64)` or `[u8; 16]` work
+//! without extra code.
+
+use crate::backend::RandomBackend;
+use crate::SampleUniform;
+
+/// Types that can be drawn uniformly from a backend.
+///
+/// Integers are uniform over their whole domain, floats are uniform in `[0, 1)` and `bool`
+/// is `true` with probability one half. `char` is uniform over all Unicode scalar values.
+/// Tuples and arrays draw their elements in order, and `Option<T>` is `None` with
+/// probability one half, otherwise `Some` of a drawn `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{Random, backend::SplitMix64};
+///
+/// let mut backend = SplitMix64::new(1);
+/// let byte = u8::random(&mut backend);
+/// let x = f64::random(&mut backend);
+/// assert!((0.0..1.0).contains(&x));
+/// ```
+pub trait Random: Sized {
+    /// Draws a value from `backend`.
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self;
+}
+
+/// Implements [`Random`] for integers narrower than 64 bits by truncating `next_u32`.
+macro_rules! impl_random_from_u32 {
+    ($($t:ty),*) => {$(
+        impl Random for $t {
+            fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+                backend.next_u32() as $t
+            }
+        }
+    )*};
+}
+
+/// Implements [`Random`] for 64-bit integers by casting `next_u64`.
+macro_rules! impl_random_from_u64 {
+    ($($t:ty),*) => {$(
+        impl Random for $t {
+            fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+                backend.next_u64() as $t
+            }
+        }
+    )*};
+}
+
+impl_random_from_u32!(u8, u16, u32, i8, i16, i32);
+impl_random_from_u64!(u64, i64, usize, isize);
+
+impl Random for u128 {
+    /// Uses [`RandomBackend::next_u128`], which by default joins two draws.
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        backend.next_u128()
+    }
+}
+
+impl Random for i128 {
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        backend.next_i128()
+    }
+}
+
+impl Random for f64 {
+    /// Uniform in `[0, 1)`, like [`RandomBackend::next_f64`].
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        backend.next_f64()
+    }
+}
+
+impl Random for f32 {
+    /// Uniform in `[0, 1)` from the top 24 bits of a draw, like [`crate::Rng::next_f32`].
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        let val = (backend.next_u64() >> 40) as u32;
+        (val as f32) * (1.0 / ((1u32 << 24) as f32))
+    }
+}
+
+impl Random for bool {
+    /// Uses the same bit as [`crate::Rng::next_bool`].
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        (backend.next_u64() & 1) != 0
+    }
+}
+
+impl Random for char {
+    /// Uniform over the Unicode scalar values, skipping the surrogate range.
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        match char::sample_uniform_inclusive(backend, '\0', char::MAX) {
+            Ok(c) => c,
+            Err(_) => unreachable!("the full char range is never empty"),
+        }
+    }
+}
+
+impl<T: Random> Random for Option<T> {
+    /// Decides on `Some` with one draw, then draws the value.
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        if bool::random(backend) { Some(T::random(backend)) } else { None }
+    }
+}
+
+impl<T: Random, const N: usize> Random for [T; N] {
+    fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+        core::array::from_fn(|_| T::random(backend))
+    }
+}
+
+/// Implements [`Random`] for tuples by drawing each element from left to right.
+macro_rules! impl_random_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: Random),*> Random for ($($t,)*) {
+            #[allow(clippy::unused_unit, unused_variables)]
+            fn random<B: RandomBackend + ?Sized>(backend: &mut B) -> Self {
+                ($($t::random(backend),)*)
+            }
+        }
+    };
+}
+
+impl_random_tuple!();
+impl_random_tuple!(A);
+impl_random_tuple!(A, B1);
+impl_random_tuple!(A, B1, C);
+impl_random_tuple!(A, B1, C, D);
+impl_random_tuple!(A, B1, C, D, E);
+impl_random_tuple!(A, B1, C, D, E, F);
+impl_random_tuple!(A, B1, C, D, E, F, G);
+impl_random_tuple!(A, B1, C, D, E, F, G, H);
+impl_random_tuple!(A, B1, C, D, E, F, G, H, I);
+impl_random_tuple!(A, B1, C, D, E, F, G, H, I, J);
+impl_random_tuple!(A, B1, C, D, E, F, G, H, I, J, K);
+impl_random_tuple!(A, B1, C, D, E, F, G, H, I, J, K, L);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn random_matches_rng_methods() {
+        let mut backend = SplitMix64::new(9);
+        let mut rng = Rng::new(SplitMix64::new(9));
+        assert_eq!(u64::random(&mut backend), rng.next_u64());
+        assert_eq!(f64::random(&mut backend), rng.next_f64());
+        assert_eq!(f32::random(&mut backend), rng.next_f32());
+        assert_eq!(bool::random(&mut backend), rng.next_bool());
+        assert_eq!(u32::random(&mut backend), rng.next_u32());
+    }
+
+    #[test]
+    fn compound_types_draw_elements_in_order() {
+        let mut rng = Rng::new(SplitMix64::new(3));
+        let (a, b): (u64, f64) = rng.random();
+        let mut plain = Rng::new(SplitMix64::new(3));
+        assert_eq!(a, plain.next_u64());
+        assert_eq!(b, plain.next_f64());
+
+        let bytes: [u8; 4] = rng.random();
+        for byte in bytes {
+            assert_eq!(byte, plain.next_u32() as u8);
+        }
+
+        for _ in 0..1000 {
+            let c: char = rng.random();
+            assert!(!(0xD800..0xE000).contains(&(c as u32)));
+        }
+        let somes = (0..1000).filter(|_| rng.random::<Option<u8>>().is_some()).count();
+        assert!((400..600).contains(&somes));
+    }
+}
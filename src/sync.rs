@@ -0,0 +1,155 @@
+//! Mutex-protected generator shared between threads.
+//!
+//! [`SyncRng`] wraps an [`Rng`] in a `std::sync::Mutex` and exposes the `Rng` methods on
+//! `&self`, so one seeded generator of any size can be shared by several threads, for
+//! example in a multi-threaded test that must stay reproducible as a whole. Every call
+//! takes the lock once; [`SyncRng::with`] runs a closure under a single lock for batches.
+//!
+//! For single-word backends, [`AtomicRng`](crate::backend::AtomicRng) avoids the lock.
+//!
+//! This module requires the `std` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use aporia::{SyncRng, backend::MT19937_64};
+//!
+//! let shared = Arc::new(SyncRng::new(MT19937_64::new(5489)));
+//! let worker = {
+//!     let shared = Arc::clone(&shared);
+//!     std::thread::spawn(move || shared.gen_range(0..10).unwrap())
+//! };
+//! let here = shared.next_f64();
+//! let there = worker.join().unwrap();
+//! ```
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::backend::RandomBackend;
+use crate::Rng;
+
+/// Thread-safe wrapper that serializes access to an [`Rng`] with a mutex.
+///
+/// A panic while the lock is held does not poison the generator: its state is always
+/// valid between draws, so later calls keep working.
+pub struct SyncRng<B: RandomBackend> {
+    rng: Mutex<Rng<B>>,
+}
+
+impl<B: RandomBackend> SyncRng<B> {
+    /// Creates a shared generator around `backend`.
+    pub fn new(backend: B) -> Self {
+        Self::from_rng(Rng::new(backend))
+    }
+
+    /// Creates a shared generator from an existing [`Rng`].
+    pub fn from_rng(rng: Rng<B>) -> Self {
+        Self { rng: Mutex::new(rng) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Rng<B>> {
+        self.rng.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Runs `f` with exclusive access to the generator, holding the lock once.
+    pub fn with<T>(&self, f: impl FnOnce(&mut Rng<B>) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    /// Returns the wrapped generator.
+    pub fn into_inner(self) -> Rng<B> {
+        self.rng.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Generates the next 64-bit unsigned integer.
+    pub fn next_u64(&self) -> u64 {
+        self.lock().next_u64()
+    }
+
+    /// Generates the next 32-bit unsigned integer.
+    pub fn next_u32(&self) -> u32 {
+        self.lock().next_u32()
+    }
+
+    /// Generates the next floating-point number in the range [0, 1).
+    pub fn next_f64(&self) -> f64 {
+        self.lock().next_f64()
+    }
+
+    /// Generates the next 32-bit floating point number in [0, 1).
+    pub fn next_f32(&self) -> f32 {
+        self.lock().next_f32()
+    }
+
+    /// Generates a random boolean with p=0.5.
+    pub fn next_bool(&self) -> bool {
+        self.lock().next_bool()
+    }
+
+    /// Generates a random number in `range`; see [`Rng::gen_range`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-range [`crate::AporiaError`] if `range` is empty.
+    pub fn gen_range<T, R>(&self, range: R) -> core::result::Result<T, crate::AporiaError>
+    where
+        T: crate::SampleUniform,
+        R: core::ops::RangeBounds<T>,
+    {
+        self.lock().gen_range(range)
+    }
+
+    /// Generates a random number in `[min, max]`; see [`Rng::gen_range_inclusive`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-range [`crate::AporiaError`] if `min > max`.
+    pub fn gen_range_inclusive<T: crate::SampleUniform>(&self, min: T, max: T) -> core::result::Result<T, crate::AporiaError> {
+        self.lock().gen_range_inclusive(min, max)
+    }
+
+    /// Generates a random floating-point number in `[min, max)`; see [`Rng::gen_range_f64`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::AporiaError::InvalidRangeF64`] if `min >= max`.
+    pub fn gen_range_f64(&self, min: f64, max: f64) -> core::result::Result<f64, crate::AporiaError> {
+        self.lock().gen_range_f64(min, max)
+    }
+
+    /// Fills `buf` with random bytes under a single lock.
+    pub fn fill_bytes(&self, buf: &mut [u8]) {
+        self.lock().fill_bytes(buf)
+    }
+}
+
+impl<B: RandomBackend + core::fmt::Debug> core::fmt::Debug for SyncRng<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SyncRng").field("rng", &*self.lock()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Xoshiro256StarStar;
+
+    #[test]
+    fn shared_draws_cover_the_sequence() {
+        let shared = SyncRng::new(Xoshiro256StarStar::new(11));
+        let mut all: Vec<u64> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| s.spawn(|| (0..250).map(|_| shared.next_u64()).collect::<Vec<_>>()))
+                .collect();
+            handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+        });
+
+        let mut plain = Rng::new(Xoshiro256StarStar::new(11));
+        let mut expected: Vec<u64> = (0..1000).map(|_| plain.next_u64()).collect();
+        all.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(all, expected);
+        assert_eq!(shared.into_inner(), plain);
+    }
+}
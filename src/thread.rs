@@ -0,0 +1,107 @@
+//! Lazily seeded per-thread generator and convenience free functions.
+//!
+//! [`thread_rng`] returns a handle to a generator owned by the current thread. It is a
+//! [`ChaCha12`] seeded from the operating system on first use, so it needs no setup and is
+//! suitable for keys and tokens as well as everyday sampling. [`random`] and
+//! [`random_range`] draw a single value from it for the "I just need a number" case.
+//!
+//! Sequences from the thread generator are not reproducible. Construct an [`Rng`] with an
+//! explicit seed when results must be repeatable.
+//!
+//! This module requires the `std` and `getrandom` features.
+//!
+//! # Example
+//!
+//! ```rust
+//! let roll = aporia::random_range(1..=6);
+//! assert!((1..=6).contains(&roll));
+//!
+//! let coin: bool = aporia::random();
+//! let mut rng = aporia::thread_rng();
+//! let x = rng.next_f64();
+//! ```
+
+use core::cell::RefCell;
+use core::ops::RangeBounds;
+
+use crate::backend::{ChaCha12, RandomBackend};
+use crate::{Random, Rng, SampleUniform};
+
+std::thread_local! {
+    static THREAD_RNG: RefCell<ChaCha12> = RefCell::new(ChaCha12::default());
+}
+
+/// Handle to the current thread's generator, created by [`thread_rng`].
+///
+/// The handle holds no state; every call goes to the generator of the thread it is used on.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadRng;
+
+impl RandomBackend for ThreadRng {
+    fn next_u64(&mut self) -> u64 {
+        THREAD_RNG.with(|rng| rng.borrow_mut().next_u64())
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        THREAD_RNG.with(|rng| rng.borrow_mut().next_u32())
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        THREAD_RNG.with(|rng| rng.borrow_mut().fill_bytes(buf))
+    }
+}
+
+/// Returns an [`Rng`] backed by the current thread's generator.
+///
+/// # Panics
+///
+/// The first call on each thread panics if the OS random source fails.
+pub fn thread_rng() -> Rng<ThreadRng> {
+    Rng::new(ThreadRng)
+}
+
+/// Returns a random value of type `T` from the thread generator.
+///
+/// See [`Random`] for the distribution of each type.
+pub fn random<T: Random>() -> T {
+    T::random(&mut ThreadRng)
+}
+
+/// Returns a uniformly distributed value in `range` from the thread generator.
+///
+/// Accepts the same ranges as [`Rng::gen_range`], e.g. `0..10`, `1..=6` or `-1.0..1.0`.
+///
+/// # Panics
+///
+/// Panics if `range` is empty.
+pub fn random_range<T: SampleUniform, R: RangeBounds<T>>(range: R) -> T {
+    match thread_rng().gen_range(range) {
+        Ok(v) => v,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn free_functions_use_thread_generator() {
+        for _ in 0..100 {
+            assert!((10..20).contains(&random_range(10..20)));
+            assert!((-1.0..1.0).contains(&random_range(-1.0..1.0)));
+        }
+        let a: u64 = random();
+        let b: u64 = random();
+        assert_ne!(a, b);
+        let x: f64 = random();
+        assert!((0.0..1.0).contains(&x));
+    }
+
+    #[test]
+    fn threads_get_distinct_generators() {
+        let here = thread_rng().next_u64();
+        let there = std::thread::spawn(|| thread_rng().next_u64()).join().unwrap();
+        assert_ne!(here, there);
+    }
+}
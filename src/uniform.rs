@@ -0,0 +1,478 @@
+//! Uniform sampling from a range for every primitive numeric type.
+//!
+//! [`SampleUniform`] is what makes [`crate::Rng::gen_range`] generic. Each implementation
+//! maps the range onto an unsigned offset of the same width and draws it without modulo
+//! bias. Types of 32 bits or fewer use a single `next_u32` per draw, 64-bit types use
+//! `next_u64` and 128-bit types combine two `next_u64` draws. Floats scale a value in
+//! `[0, 1)` onto the range.
+//!
+//! # Integer Method
+//!
+//! Up to 64 bits, offsets are drawn with Lemire's widening multiply: for a span `n`, a
+//! draw `x` of width `w` gives the product `x * n` in twice the width, whose high half is
+//! in `[0, n)`. Products whose low half falls below `2^w mod n` are rejected, which makes
+//! every result equally likely. The remainder is only computed when the low half is below
+//! `n`, so the common case costs one multiply and no division. 128-bit types, which have
+//! no wider multiply, use zone rejection: draws at or above the largest multiple of `n`
+//! are rejected and the rest reduced with `%`.
+//!
+//! # References
+//!
+//! - [Lemire (2019), "Fast Random Integer Generation in an Interval"](https://arxiv.org/abs/1805.10941)
+//!
+//! `char` ranges are sampled over the Unicode scalar values they contain, skipping the
+//! surrogate code points `U+D800..=U+DFFF`.
+//!
+//! Inclusive ranges of integers may cover the whole domain, e.g. `0..=u64::MAX`, in which
+//! case a plain draw of the full width is returned.
+
+use core::ops::{Bound, RangeBounds};
+
+use crate::AporiaError;
+use crate::backend::RandomBackend;
+use crate::standard::Random;
+
+/// Types that can be sampled uniformly from a range.
+///
+/// # Examples
+///
+/// ```rust
+/// use aporia::{SampleUniform, backend::SplitMix64};
+///
+/// let mut backend = SplitMix64::new(1);
+/// let die = i8::sample_uniform_inclusive(&mut backend, 1, 6).unwrap();
+/// assert!((1..=6).contains(&die));
+/// ```
+pub trait SampleUniform: Copy + PartialOrd {
+    /// Draws a value uniformly from `[low, high)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-range [`AporiaError`] if `low >= high` (or either bound is NaN).
+    fn sample_uniform<B: RandomBackend + ?Sized>(backend: &mut B, low: Self, high: Self) -> Result<Self, AporiaError>;
+
+    /// Draws a value uniformly from `[low, high]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-range [`AporiaError`] if `low > high` (or either bound is NaN).
+    fn sample_uniform_inclusive<B: RandomBackend + ?Sized>(
+        backend: &mut B,
+        low: Self,
+        high: Self,
+    ) -> Result<Self, AporiaError>;
+
+    /// Parameters of one range, precomputed for repeated sampling by
+    /// [`Uniform`](crate::distributions::Uniform).
+    type Sampler: Copy + core::fmt::Debug + PartialEq;
+
+    /// Validates the range `[low, high)`, or `[low, high]` if `inclusive`, and precomputes
+    /// its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`SampleUniform::sample_uniform`] and
+    /// [`SampleUniform::sample_uniform_inclusive`].
+    fn new_sampler(low: Self, high: Self, inclusive: bool) -> Result<Self::Sampler, AporiaError>;
+
+    /// Draws a value using precomputed parameters.
+    ///
+    /// Produces the same value as sampling the original range directly from the same
+    /// backend state.
+    fn sample_with<B: RandomBackend + ?Sized>(sampler: &Self::Sampler, backend: &mut B) -> Self;
+}
+
+/// Splits `low..high` or `low..=high`, the two range shapes with a finite lower bound that
+/// is part of the range, into `(low, high, inclusive)`.
+///
+/// Other bounds (open-ended ranges such as `..10`, or an excluded start) are rejected
+/// with [`AporiaError::InvalidRange`].
+pub(crate) fn range_bounds<T, R>(range: &R) -> Result<(T, T, bool), AporiaError>
+where
+    T: SampleUniform,
+    R: RangeBounds<T>,
+{
+    match (range.start_bound(), range.end_bound()) {
+        (Bound::Included(&low), Bound::Excluded(&high)) => Ok((low, high, false)),
+        (Bound::Included(&low), Bound::Included(&high)) => Ok((low, high, true)),
+        _ => Err(AporiaError::InvalidRange { ty: core::any::type_name::<T>() }),
+    }
+}
+
+/// Draws a value from `range`; see [`range_bounds`] for the accepted shapes.
+pub(crate) fn sample_range<T, B, R>(backend: &mut B, range: R) -> Result<T, AporiaError>
+where
+    T: SampleUniform,
+    B: RandomBackend + ?Sized,
+    R: RangeBounds<T>,
+{
+    match range_bounds(&range)? {
+        (low, high, false) => T::sample_uniform(backend, low, high),
+        (low, high, true) => T::sample_uniform_inclusive(backend, low, high),
+    }
+}
+
+/// Precomputed parameters for an integer range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntSampler<T, W> {
+    low: T,
+    /// Number of values in the range, or zero if it covers the whole draw width.
+    span: W,
+    /// Rejection bound for the bounded draw: `2^w mod span` for Lemire's method, or the
+    /// zone limit for 128-bit types.
+    threshold: W,
+}
+
+/// Precomputed parameters for a floating-point range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatSampler<T> {
+    low: T,
+    scale: T,
+}
+
+/// Implements bounded draws with Lemire's method; see the [module documentation](self).
+///
+/// `$name` computes the threshold only when a draw might need rejecting, `$with` takes a
+/// threshold precomputed by `$threshold`. Both accept exactly the same draws.
+macro_rules! lemire_below {
+    ($(#[$doc:meta])* $name:ident, $with:ident, $threshold:ident, $word:ty, $wide:ty, $next:ident) => {
+        $(#[$doc])*
+        fn $name<B: RandomBackend + ?Sized>(backend: &mut B, n: $word) -> $word {
+            let mut m = <$wide>::from(backend.$next()) * <$wide>::from(n);
+            if (m as $word) < n {
+                let threshold = $threshold(n);
+                while (m as $word) < threshold {
+                    m = <$wide>::from(backend.$next()) * <$wide>::from(n);
+                }
+            }
+            (m >> <$word>::BITS) as $word
+        }
+
+        /// Bounded draw with a threshold from the matching `threshold_*` function.
+        fn $with<B: RandomBackend + ?Sized>(backend: &mut B, n: $word, threshold: $word) -> $word {
+            loop {
+                let m = <$wide>::from(backend.$next()) * <$wide>::from(n);
+                if (m as $word) >= threshold {
+                    return (m >> <$word>::BITS) as $word;
+                }
+            }
+        }
+
+        /// Returns `2^w mod n`, the number of low halves that would bias the result.
+        fn $threshold(n: $word) -> $word {
+            n.wrapping_neg() % n
+        }
+    };
+}
+
+lemire_below!(
+    /// Returns a uniform value in `[0, n)` from `next_u32` draws.
+    below_u32, below_u32_with, threshold_u32, u32, u64, next_u32
+);
+lemire_below!(
+    /// Returns a uniform value in `[0, n)` from `next_u64` draws.
+    below_u64, below_u64_with, threshold_u64, u64, u128, next_u64
+);
+
+/// Returns a uniform value in `[0, n)` using zone rejection.
+fn below_u128<B: RandomBackend + ?Sized>(backend: &mut B, n: u128) -> u128 {
+    below_u128_with(backend, n, threshold_u128(n))
+}
+
+/// Draws until a value falls below `zone`, then reduces it modulo `n`.
+fn below_u128_with<B: RandomBackend + ?Sized>(backend: &mut B, n: u128, zone: u128) -> u128 {
+    loop {
+        let v = backend.next_u128();
+        if v < zone {
+            return v % n;
+        }
+    }
+}
+
+/// Returns the largest multiple of `n` that fits in a `u128`, the zone limit.
+fn threshold_u128(n: u128) -> u128 {
+    u128::MAX - (u128::MAX % n)
+}
+
+/// Implements [`SampleUniform`] for an integer type.
+///
+/// `$unsigned` is the unsigned type of the same width, `$word` the draw width, and
+/// `$below`, `$with` and `$threshold` the matching bounded draws. `$err` builds the error
+/// for an empty range.
+macro_rules! impl_sample_uniform_int {
+    ($($t:ty => $unsigned:ty, $word:ty, $below:ident, $with:ident, $threshold:ident, $err:expr;)*) => {$(
+        impl SampleUniform for $t {
+            type Sampler = IntSampler<$t, $word>;
+
+            fn sample_uniform<B: RandomBackend + ?Sized>(
+                backend: &mut B,
+                low: Self,
+                high: Self,
+            ) -> Result<Self, AporiaError> {
+                if low >= high {
+                    return Err($err(low, high));
+                }
+                let span = high.wrapping_sub(low) as $unsigned as $word;
+                Ok(low.wrapping_add($below(backend, span) as $unsigned as $t))
+            }
+
+            fn sample_uniform_inclusive<B: RandomBackend + ?Sized>(
+                backend: &mut B,
+                low: Self,
+                high: Self,
+            ) -> Result<Self, AporiaError> {
+                if low > high {
+                    return Err($err(low, high));
+                }
+                let span = high.wrapping_sub(low) as $unsigned as $word;
+                let offset = match span.checked_add(1) {
+                    Some(n) => $below(backend, n),
+                    // The range covers every value of the draw width.
+                    None => <$word as Random>::random(backend),
+                };
+                Ok(low.wrapping_add(offset as $unsigned as $t))
+            }
+
+            fn new_sampler(low: Self, high: Self, inclusive: bool) -> Result<Self::Sampler, AporiaError> {
+                if low > high || (low == high && !inclusive) {
+                    return Err($err(low, high));
+                }
+                let span = (high.wrapping_sub(low) as $unsigned as $word).wrapping_add(inclusive as $word);
+                let threshold = if span == 0 { 0 } else { $threshold(span) };
+                Ok(IntSampler { low, span, threshold })
+            }
+
+            fn sample_with<B: RandomBackend + ?Sized>(sampler: &Self::Sampler, backend: &mut B) -> Self {
+                let offset = if sampler.span == 0 {
+                    <$word as Random>::random(backend)
+                } else {
+                    $with(backend, sampler.span, sampler.threshold)
+                };
+                sampler.low.wrapping_add(offset as $unsigned as $t)
+            }
+        }
+    )*};
+}
+
+fn u64_error<T: Into<u64>>(min: T, max: T) -> AporiaError {
+    AporiaError::InvalidRangeU64 { min: min.into(), max: max.into() }
+}
+
+fn usize_error(min: usize, max: usize) -> AporiaError {
+    AporiaError::InvalidRangeU64 { min: min as u64, max: max as u64 }
+}
+
+fn typed_error<T>(_: T, _: T) -> AporiaError {
+    AporiaError::InvalidRange { ty: core::any::type_name::<T>() }
+}
+
+impl_sample_uniform_int! {
+    u8 => u8, u32, below_u32, below_u32_with, threshold_u32, u64_error;
+    u16 => u16, u32, below_u32, below_u32_with, threshold_u32, u64_error;
+    u32 => u32, u32, below_u32, below_u32_with, threshold_u32, u64_error;
+    u64 => u64, u64, below_u64, below_u64_with, threshold_u64, u64_error;
+    usize => usize, u64, below_u64, below_u64_with, threshold_u64, usize_error;
+    u128 => u128, u128, below_u128, below_u128_with, threshold_u128, typed_error;
+    i8 => u8, u32, below_u32, below_u32_with, threshold_u32, typed_error;
+    i16 => u16, u32, below_u32, below_u32_with, threshold_u32, typed_error;
+    i32 => u32, u32, below_u32, below_u32_with, threshold_u32, typed_error;
+    i64 => u64, u64, below_u64, below_u64_with, threshold_u64, typed_error;
+    isize => usize, u64, below_u64, below_u64_with, threshold_u64, typed_error;
+    i128 => u128, u128, below_u128, below_u128_with, threshold_u128, typed_error;
+}
+
+impl SampleUniform for f64 {
+    type Sampler = FloatSampler<f64>;
+
+    /// Computes `low + u * (high - low)` for `u` in `[0, 1)`.
+    fn sample_uniform<B: RandomBackend + ?Sized>(backend: &mut B, low: Self, high: Self) -> Result<Self, AporiaError> {
+        if low >= high || low.is_nan() || high.is_nan() {
+            return Err(AporiaError::InvalidRangeF64 { min: low, max: high });
+        }
+        Ok(low + backend.next_f64() * (high - low))
+    }
+
+    /// Uses the same formula as the half-open case, so `high` itself is never returned;
+    /// `low == high` is accepted and returns `low`.
+    fn sample_uniform_inclusive<B: RandomBackend + ?Sized>(
+        backend: &mut B,
+        low: Self,
+        high: Self,
+    ) -> Result<Self, AporiaError> {
+        if low > high || low.is_nan() || high.is_nan() {
+            return Err(AporiaError::InvalidRangeF64 { min: low, max: high });
+        }
+        Ok(low + backend.next_f64() * (high - low))
+    }
+
+    fn new_sampler(low: Self, high: Self, inclusive: bool) -> Result<Self::Sampler, AporiaError> {
+        if low > high || (low == high && !inclusive) || low.is_nan() || high.is_nan() {
+            return Err(AporiaError::InvalidRangeF64 { min: low, max: high });
+        }
+        Ok(FloatSampler { low, scale: high - low })
+    }
+
+    fn sample_with<B: RandomBackend + ?Sized>(sampler: &Self::Sampler, backend: &mut B) -> Self {
+        sampler.low + backend.next_f64() * sampler.scale
+    }
+}
+
+impl SampleUniform for f32 {
+    type Sampler = FloatSampler<f32>;
+
+    /// Computes `low + u * (high - low)` for `u` in `[0, 1)` with 24 bits of precision.
+    fn sample_uniform<B: RandomBackend + ?Sized>(backend: &mut B, low: Self, high: Self) -> Result<Self, AporiaError> {
+        if low >= high || low.is_nan() || high.is_nan() {
+            return Err(AporiaError::InvalidRangeF64 { min: low.into(), max: high.into() });
+        }
+        Ok(low + f32::random(backend) * (high - low))
+    }
+
+    /// Like the `f64` version, `high` itself is never returned.
+    fn sample_uniform_inclusive<B: RandomBackend + ?Sized>(
+        backend: &mut B,
+        low: Self,
+        high: Self,
+    ) -> Result<Self, AporiaError> {
+        if low > high || low.is_nan() || high.is_nan() {
+            return Err(AporiaError::InvalidRangeF64 { min: low.into(), max: high.into() });
+        }
+        Ok(low + f32::random(backend) * (high - low))
+    }
+
+    fn new_sampler(low: Self, high: Self, inclusive: bool) -> Result<Self::Sampler, AporiaError> {
+        if low > high || (low == high && !inclusive) || low.is_nan() || high.is_nan() {
+            return Err(AporiaError::InvalidRangeF64 { min: low.into(), max: high.into() });
+        }
+        Ok(FloatSampler { low, scale: high - low })
+    }
+
+    fn sample_with<B: RandomBackend + ?Sized>(sampler: &Self::Sampler, backend: &mut B) -> Self {
+        sampler.low + f32::random(backend) * sampler.scale
+    }
+}
+
+/// First surrogate code point and the number of surrogates, which `char` cannot hold.
+const SURROGATE_START: u32 = 0xD800;
+const SURROGATE_COUNT: u32 = 0x800;
+
+/// Maps a `char` to its index among the Unicode scalar values.
+fn char_to_index(c: char) -> u32 {
+    let c = u32::from(c);
+    if c >= SURROGATE_START { c - SURROGATE_COUNT } else { c }
+}
+
+/// Inverse of [`char_to_index`].
+fn char_from_index(i: u32) -> char {
+    let c = if i >= SURROGATE_START { i + SURROGATE_COUNT } else { i };
+    char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+impl SampleUniform for char {
+    type Sampler = IntSampler<u32, u32>;
+
+    /// Uniform over the scalar values in `[low, high)`.
+    fn sample_uniform<B: RandomBackend + ?Sized>(backend: &mut B, low: Self, high: Self) -> Result<Self, AporiaError> {
+        u32::sample_uniform(backend, char_to_index(low), char_to_index(high))
+            .map(char_from_index)
+            .map_err(|_| AporiaError::InvalidRange { ty: "char" })
+    }
+
+    /// Uniform over the scalar values in `[low, high]`.
+    fn sample_uniform_inclusive<B: RandomBackend + ?Sized>(
+        backend: &mut B,
+        low: Self,
+        high: Self,
+    ) -> Result<Self, AporiaError> {
+        u32::sample_uniform_inclusive(backend, char_to_index(low), char_to_index(high))
+            .map(char_from_index)
+            .map_err(|_| AporiaError::InvalidRange { ty: "char" })
+    }
+
+    fn new_sampler(low: Self, high: Self, inclusive: bool) -> Result<Self::Sampler, AporiaError> {
+        u32::new_sampler(char_to_index(low), char_to_index(high), inclusive)
+            .map_err(|_| AporiaError::InvalidRange { ty: "char" })
+    }
+
+    fn sample_with<B: RandomBackend + ?Sized>(sampler: &Self::Sampler, backend: &mut B) -> Self {
+        char_from_index(u32::sample_with(sampler, backend))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+    use crate::backend::SplitMix64;
+
+    #[test]
+    fn bounded_draws_are_unbiased() {
+        let mut backend = SplitMix64::new(23);
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            counts[below_u64(&mut backend, 6) as usize] += 1;
+            counts[below_u32(&mut backend, 6) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (19_400..20_600).contains(&c)), "{counts:?}");
+
+        // Spans just above 2^63 reject almost half of all draws, and the survivors must
+        // split evenly between the lower and upper halves of the span.
+        let n = (1u64 << 63) + 1;
+        let upper = (0..10_000).filter(|_| below_u64(&mut backend, n) >= n / 2).count();
+        assert!((4_800..5_200).contains(&upper), "upper = {upper}");
+    }
+
+    #[test]
+    fn every_width_stays_in_range() {
+        let mut rng = Rng::new(SplitMix64::new(17));
+        let mut seen = [false; 256];
+        for _ in 0..10_000 {
+            let v = rng.gen_range(i8::MIN..i8::MAX).unwrap();
+            assert!(v < i8::MAX);
+            seen[(v as u8) as usize] = true;
+            assert!((-3..5).contains(&rng.gen_range(-3i64..5).unwrap()));
+            assert!((250..=255).contains(&rng.gen_range(250u8..=255).unwrap()));
+            let wide = rng.gen_range(i128::MIN..i128::MAX).unwrap();
+            assert!(wide < i128::MAX);
+            assert!((1u128 << 100..1u128 << 101).contains(&rng.gen_range(1u128 << 100..1 << 101).unwrap()));
+            assert!((-1.0..1.0).contains(&rng.gen_range(-1.0f32..1.0).unwrap()));
+        }
+        assert_eq!(seen.iter().filter(|&&s| s).count(), 255);
+
+        let mut full = [false; 256];
+        for _ in 0..10_000 {
+            full[rng.gen_range(0u8..=u8::MAX).unwrap() as usize] = true;
+        }
+        assert!(full.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn char_ranges_skip_surrogates() {
+        let mut rng = Rng::new(SplitMix64::new(5));
+        for _ in 0..1000 {
+            assert!(rng.gen_range('a'..='z').unwrap().is_ascii_lowercase());
+            let c = rng.gen_range('\u{D7FF}'..='\u{E000}').unwrap();
+            assert!(c == '\u{D7FF}' || c == '\u{E000}');
+        }
+        assert_eq!(rng.gen_range('x'..'x'), Err(AporiaError::InvalidRange { ty: "char" }));
+    }
+
+    #[test]
+    fn empty_ranges_are_rejected() {
+        let mut backend = SplitMix64::new(1);
+        assert_eq!(
+            u16::sample_uniform(&mut backend, 5, 5),
+            Err(AporiaError::InvalidRangeU64 { min: 5, max: 5 })
+        );
+        assert_eq!(
+            i32::sample_uniform(&mut backend, 2, -2),
+            Err(AporiaError::InvalidRange { ty: "i32" })
+        );
+        assert!(f64::sample_uniform(&mut backend, f64::NAN, 1.0).is_err());
+        assert_eq!(u8::sample_uniform_inclusive(&mut backend, 5, 5), Ok(5));
+        assert_eq!(
+            sample_range(&mut backend, ..10u32),
+            Err(AporiaError::InvalidRange { ty: "u32" })
+        );
+    }
+}